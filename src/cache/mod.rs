@@ -0,0 +1,120 @@
+// SPDX-FileCopyrightText: Copyright (C) Nicolas Lamirault <nicolas.lamirault@gmail.com>
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use crate::client::{Issue, Team};
+
+const MAX_CACHED_ISSUES: usize = 500;
+
+/// Serializes the updates of the cache file, which run in the background.
+static UPDATE: Mutex<()> = Mutex::new(());
+
+/// Local cache of recently seen teams and issues, used to provide shell
+/// completions without querying the Linear API on every keystroke.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Cache {
+    pub teams: Vec<CachedTeam>,
+    pub issues: Vec<CachedIssue>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedTeam {
+    pub id: String,
+    pub key: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedIssue {
+    pub identifier: String,
+    pub title: String,
+}
+
+impl Cache {
+    pub fn path() -> Result<PathBuf> {
+        let cache_dir = dirs::cache_dir()
+            .context("Could not find cache directory")?
+            .join("ltui");
+        Ok(cache_dir.join("completions.json"))
+    }
+
+    /// Load the cache, falling back to an empty one if it is missing or unreadable.
+    pub fn load() -> Self {
+        Self::path()
+            .ok()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let path = Self::path()?;
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).context("Could not create cache directory")?;
+        }
+
+        let content = serde_json::to_string(self).context("Could not serialize cache")?;
+        std::fs::write(&path, content).context("Could not write cache file")
+    }
+
+    pub fn update_teams(&mut self, teams: Vec<CachedTeam>) {
+        self.teams = teams;
+    }
+
+    /// Record issues as recently seen, keeping the most recent ones first.
+    pub fn record_issues(&mut self, mut issues: Vec<CachedIssue>) {
+        let seen: Vec<String> = issues.iter().map(|i| i.identifier.clone()).collect();
+        issues.extend(
+            self.issues
+                .drain(..)
+                .filter(|cached| !seen.contains(&cached.identifier)),
+        );
+        issues.truncate(MAX_CACHED_ISSUES);
+
+        self.issues = issues;
+    }
+}
+
+/// Update the completion cache with freshly fetched data. Failures are ignored
+/// since the cache is only a convenience for shell completions.
+///
+/// Within the async runtime the file is written on a blocking thread, so callers don't wait on it.
+pub fn remember(teams: Option<&[Team]>, issues: &[Issue]) {
+    let teams: Option<Vec<CachedTeam>> = teams.map(|teams| {
+        teams
+            .iter()
+            .map(|team| CachedTeam {
+                id: team.id.clone(),
+                key: team.key.clone(),
+                name: team.name.clone(),
+            })
+            .collect()
+    });
+    let issues: Vec<CachedIssue> = issues
+        .iter()
+        .map(|issue| CachedIssue {
+            identifier: issue.identifier.clone(),
+            title: issue.title.clone(),
+        })
+        .collect();
+
+    let update = move || {
+        let _lock = UPDATE.lock();
+        let mut cache = Cache::load();
+        if let Some(teams) = teams {
+            cache.update_teams(teams);
+        }
+        cache.record_issues(issues);
+        let _ = cache.save();
+    };
+    match tokio::runtime::Handle::try_current() {
+        Ok(runtime) => {
+            runtime.spawn_blocking(update);
+        }
+        Err(_) => update(),
+    }
+}
@@ -0,0 +1,73 @@
+// SPDX-FileCopyrightText: Copyright (C) Nicolas Lamirault <nicolas.lamirault@gmail.com>
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{Context, Result};
+use clap::ValueEnum;
+use clap_complete::engine::CompletionCandidate;
+use clap_complete::env::Shells;
+use std::ffi::OsStr;
+
+use crate::cache::Cache;
+
+/// Environment variable used by the shell scripts to request completions.
+pub const COMPLETE_VAR: &str = "COMPLETE";
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+}
+
+impl Shell {
+    fn name(&self) -> &'static str {
+        match self {
+            Shell::Bash => "bash",
+            Shell::Zsh => "zsh",
+            Shell::Fish => "fish",
+        }
+    }
+}
+
+/// Print the completion script for the given shell. The script calls back
+/// into ltui, so completions for team keys and issue identifiers are served
+/// from the local cache.
+pub fn print_script(shell: Shell) -> Result<()> {
+    let shells = Shells::builtins();
+    let completer = shells
+        .completer(shell.name())
+        .context("Unsupported shell")?;
+
+    let mut stdout = std::io::stdout();
+    completer
+        .write_registration(COMPLETE_VAR, "ltui", "ltui", "ltui", &mut stdout)
+        .context("Could not write completion script")
+}
+
+pub fn complete_team_keys(current: &OsStr) -> Vec<CompletionCandidate> {
+    let Some(current) = current.to_str() else {
+        return Vec::new();
+    };
+    let current = current.to_uppercase();
+
+    Cache::load()
+        .teams
+        .into_iter()
+        .filter(|team| team.key.to_uppercase().starts_with(&current))
+        .map(|team| CompletionCandidate::new(team.key).help(Some(team.name.into())))
+        .collect()
+}
+
+pub fn complete_issue_identifiers(current: &OsStr) -> Vec<CompletionCandidate> {
+    let Some(current) = current.to_str() else {
+        return Vec::new();
+    };
+    let current = current.to_uppercase();
+
+    Cache::load()
+        .issues
+        .into_iter()
+        .filter(|issue| issue.identifier.starts_with(&current))
+        .map(|issue| CompletionCandidate::new(issue.identifier).help(Some(issue.title.into())))
+        .collect()
+}
@@ -0,0 +1,296 @@
+// SPDX-FileCopyrightText: Copyright (C) Nicolas Lamirault <nicolas.lamirault@gmail.com>
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{Context, Result};
+use clap::Subcommand;
+use std::io::Write;
+use std::path::Path;
+
+use crate::client::LinearClient;
+use crate::config::{insert, lookup, validate, Config, Diagnostic, Severity};
+use crate::utils::editor;
+
+#[derive(Subcommand)]
+pub enum ConfigCommand {
+    /// Create the configuration file interactively
+    Init {
+        /// Overwrite an existing configuration file without asking
+        #[arg(long)]
+        force: bool,
+    },
+    /// Print a configuration value (e.g. theme.primary_color)
+    Get {
+        /// Dotted key
+        key: String,
+    },
+    /// Set a configuration value (e.g. refresh_interval 60)
+    Set {
+        /// Dotted key
+        key: String,
+        /// New value
+        value: String,
+    },
+    /// Open the configuration file in $EDITOR and validate it on save
+    Edit,
+    /// Check the configuration file for syntax errors, type errors and unknown keys
+    Validate,
+    /// Print the configuration file path
+    Path,
+}
+
+pub async fn run(
+    config_path: Option<&str>,
+    api_key: Option<&str>,
+    command: &ConfigCommand,
+) -> Result<()> {
+    match command {
+        ConfigCommand::Init { force } => init(config_path, api_key, *force).await,
+        ConfigCommand::Get { key } => get(config_path, key),
+        ConfigCommand::Set { key, value } => set(config_path, key, value),
+        ConfigCommand::Edit => edit(config_path),
+        ConfigCommand::Validate => validate_file(config_path),
+        ConfigCommand::Path => {
+            println!("{}", Config::path(config_path)?.display());
+            Ok(())
+        }
+    }
+}
+
+async fn init(config_path: Option<&str>, api_key: Option<&str>, force: bool) -> Result<()> {
+    let path = Config::path(config_path)?;
+
+    let mut config = if path.exists() {
+        if !force
+            && !confirm(
+                &format!("{} already exists. Overwrite?", path.display()),
+                false,
+            )?
+        {
+            return Ok(());
+        }
+        Config::parse(&std::fs::read_to_string(&path)?).unwrap_or_default()
+    } else {
+        Config::default()
+    };
+
+    let entered = if api_key.is_some() {
+        prompt("Linear Personal API Key (leave empty to use LINEAR_API_KEY / --apikey)")?
+    } else {
+        prompt("Linear Personal API Key")?
+    };
+
+    let token = if entered.is_empty() {
+        api_key
+            .map(str::to_string)
+            .or_else(|| config.api_key.clone())
+            .context("A Linear Personal API Key is required")?
+    } else {
+        config.api_key = Some(entered.clone());
+        entered
+    };
+
+    let client = LinearClient::new(token);
+    let teams = client
+        .get_teams()
+        .await
+        .context("Could not fetch teams, please check the API key")?;
+
+    println!("\nTeams:");
+    for (i, team) in teams.iter().enumerate() {
+        println!("  {}) {} ({})", i + 1, team.name, team.key);
+    }
+
+    let choice = prompt("Default team number (leave empty for none)")?;
+    if !choice.is_empty() {
+        let team = choice
+            .parse::<usize>()
+            .ok()
+            .and_then(|n| n.checked_sub(1))
+            .and_then(|i| teams.get(i))
+            .with_context(|| format!("Invalid team number '{}'", choice))?;
+        config.default_team_id = Some(team.id.clone());
+    }
+
+    let interval = prompt(&format!(
+        "Refresh interval in seconds [{}]",
+        config.refresh_interval
+    ))?;
+    if !interval.is_empty() {
+        config.refresh_interval = interval
+            .parse()
+            .with_context(|| format!("Invalid refresh interval '{}'", interval))?;
+    }
+
+    config.save(config_path)?;
+    println!("\nConfiguration written to {}", path.display());
+    Ok(())
+}
+
+fn get(config_path: Option<&str>, key: &str) -> Result<()> {
+    let config = read_config(config_path)?;
+    let value = toml::Value::try_from(&config).context("Could not serialize config")?;
+
+    match lookup(&value, key) {
+        Some(toml::Value::String(s)) => println!("{}", s),
+        Some(toml::Value::Table(table)) => print!("{}", toml::to_string_pretty(table)?),
+        Some(value) => println!("{}", value),
+        None if is_known_key(key) => {}
+        None => return Err(anyhow::anyhow!("Unknown configuration key '{}'", key)),
+    }
+
+    Ok(())
+}
+
+fn set(config_path: Option<&str>, key: &str, raw_value: &str) -> Result<()> {
+    let defaults = toml::Value::try_from(Config::default())?;
+    let value = parse_value(raw_value, lookup(&defaults, key))?;
+    Config::set_value(config_path, key, value)
+}
+
+fn edit(config_path: Option<&str>) -> Result<()> {
+    let path = Config::path(config_path)?;
+    let original = if path.exists() {
+        std::fs::read_to_string(&path).context("Could not read config file")?
+    } else {
+        toml::to_string_pretty(&Config::default()).context("Could not serialize config")?
+    };
+
+    let mut content = original.clone();
+    loop {
+        content = editor::edit_text(&content, "toml")?;
+
+        let diagnostics = validate(&content);
+        print_diagnostics(&path, &diagnostics);
+
+        if !diagnostics.iter().any(|d| d.severity == Severity::Error) {
+            break;
+        }
+
+        if !confirm("Re-open the editor to fix the errors?", true)? {
+            println!("Changes discarded");
+            return Ok(());
+        }
+    }
+
+    if path.exists() && content == original {
+        println!("No changes");
+        return Ok(());
+    }
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).context("Could not create config directory")?;
+    }
+    std::fs::write(&path, content).context("Could not write config file")?;
+    println!("Saved {}", path.display());
+    Ok(())
+}
+
+fn validate_file(config_path: Option<&str>) -> Result<()> {
+    let path = Config::path(config_path)?;
+    if !path.exists() {
+        return Err(anyhow::anyhow!(
+            "{} does not exist, run `ltui config init` to create it",
+            path.display()
+        ));
+    }
+
+    let content = std::fs::read_to_string(&path).context("Could not read config file")?;
+    let diagnostics = validate(&content);
+    print_diagnostics(&path, &diagnostics);
+
+    let errors = diagnostics
+        .iter()
+        .filter(|d| d.severity == Severity::Error)
+        .count();
+    if errors > 0 {
+        return Err(anyhow::anyhow!("{} error(s) in {}", errors, path.display()));
+    }
+
+    println!("{} is valid", path.display());
+    Ok(())
+}
+
+fn print_diagnostics(path: &Path, diagnostics: &[Diagnostic]) {
+    for diagnostic in diagnostics {
+        eprintln!("{}:{}", path.display(), diagnostic);
+    }
+}
+
+/// Configuration from the file only, without the LINEAR_API_KEY override.
+fn read_config(config_path: Option<&str>) -> Result<Config> {
+    let path = Config::path(config_path)?;
+    if !path.exists() {
+        return Ok(Config::default());
+    }
+
+    let content = std::fs::read_to_string(&path).context("Could not read config file")?;
+    Config::parse(&content).with_context(|| format!("Could not parse {}", path.display()))
+}
+
+/// Unset optional keys are not serialized, so check the key against the schema instead.
+fn is_known_key(key: &str) -> bool {
+    let mut table = toml::Table::new();
+    if insert(&mut table, key, toml::Value::String(String::new())).is_err() {
+        return false;
+    }
+
+    toml::to_string(&table)
+        .map(|content| {
+            !validate(&content)
+                .iter()
+                .any(|d| d.message.starts_with("unknown key"))
+        })
+        .unwrap_or(false)
+}
+
+/// Parse a command line value using the type of the current value for the key.
+fn parse_value(raw: &str, current: Option<&toml::Value>) -> Result<toml::Value> {
+    let value = match current {
+        Some(toml::Value::Integer(_)) => toml::Value::Integer(
+            raw.parse()
+                .with_context(|| format!("'{}' is not an integer", raw))?,
+        ),
+        Some(toml::Value::Float(_)) => toml::Value::Float(
+            raw.parse()
+                .with_context(|| format!("'{}' is not a number", raw))?,
+        ),
+        Some(toml::Value::Boolean(_)) => toml::Value::Boolean(
+            raw.parse()
+                .with_context(|| format!("'{}' is not a boolean", raw))?,
+        ),
+        Some(toml::Value::Array(_)) | Some(toml::Value::Table(_)) => {
+            let mut table: toml::Table = toml::from_str(&format!("value = {}", raw))
+                .with_context(|| format!("'{}' is not a valid TOML value", raw))?;
+            table.remove("value").context("Missing value")?
+        }
+        _ => toml::Value::String(raw.to_string()),
+    };
+
+    Ok(value)
+}
+
+fn prompt(question: &str) -> Result<String> {
+    print!("{}: ", question);
+    std::io::stdout().flush()?;
+
+    let mut answer = String::new();
+    let read = std::io::stdin()
+        .read_line(&mut answer)
+        .context("Could not read answer")?;
+    if read == 0 {
+        return Err(anyhow::anyhow!("No answer, standard input is closed"));
+    }
+
+    Ok(answer.trim().to_string())
+}
+
+fn confirm(question: &str, default: bool) -> Result<bool> {
+    let hint = if default { "Y/n" } else { "y/N" };
+    let answer = prompt(&format!("{} [{}]", question, hint))?;
+
+    Ok(match answer.to_lowercase().as_str() {
+        "" => default,
+        "y" | "yes" => true,
+        _ => false,
+    })
+}
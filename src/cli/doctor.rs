@@ -0,0 +1,393 @@
+// SPDX-FileCopyrightText: Copyright (C) Nicolas Lamirault <nicolas.lamirault@gmail.com>
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::Result;
+use chrono::Utc;
+use serde::Serialize;
+use std::io::IsTerminal;
+
+use crate::cli::OutputFormat;
+use crate::client::LinearClient;
+use crate::config::Config;
+
+/// Smallest terminal size the TUI layout renders correctly in.
+const MIN_TERMINAL_SIZE: (u16, u16) = (100, 30);
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckStatus {
+    Pass,
+    Warn,
+    Fail,
+    Skip,
+}
+
+impl CheckStatus {
+    fn label(&self) -> &'static str {
+        match self {
+            CheckStatus::Pass => "PASS",
+            CheckStatus::Warn => "WARN",
+            CheckStatus::Fail => "FAIL",
+            CheckStatus::Skip => "SKIP",
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct CheckResult {
+    pub name: &'static str,
+    pub status: CheckStatus,
+    pub message: String,
+}
+
+impl CheckResult {
+    fn new(name: &'static str, status: CheckStatus, message: impl Into<String>) -> Self {
+        Self {
+            name,
+            status,
+            message: message.into(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct Summary {
+    pub passed: usize,
+    pub warnings: usize,
+    pub failed: usize,
+    pub skipped: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Report {
+    pub version: &'static str,
+    pub checks: Vec<CheckResult>,
+    pub summary: Summary,
+}
+
+impl Report {
+    fn new(checks: Vec<CheckResult>) -> Self {
+        let count = |status| checks.iter().filter(|c| c.status == status).count();
+        let summary = Summary {
+            passed: count(CheckStatus::Pass),
+            warnings: count(CheckStatus::Warn),
+            failed: count(CheckStatus::Fail),
+            skipped: count(CheckStatus::Skip),
+        };
+
+        Self {
+            version: env!("CARGO_PKG_VERSION"),
+            checks,
+            summary,
+        }
+    }
+}
+
+/// Run all diagnostics and print the report. Returns an error if any check failed.
+pub async fn run(
+    config_path: Option<&str>,
+    api_key: Option<&str>,
+    output: OutputFormat,
+) -> Result<()> {
+    let mut checks = Vec::new();
+
+    let config = check_config(config_path, &mut checks);
+    let token = check_key_source(api_key, config.as_ref(), &mut checks);
+
+    match token {
+        Some(token) => check_api(LinearClient::new(token), &mut checks).await,
+        None => {
+            for name in API_CHECKS {
+                checks.push(CheckResult::new(
+                    name,
+                    CheckStatus::Skip,
+                    "No API key available",
+                ));
+            }
+        }
+    }
+
+    checks.push(check_terminal());
+
+    let report = Report::new(checks);
+
+    match output {
+        OutputFormat::Text => print_text(&report),
+        OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&report)?),
+    }
+
+    if report.summary.failed > 0 {
+        return Err(anyhow::anyhow!("{} check(s) failed", report.summary.failed));
+    }
+
+    Ok(())
+}
+
+const API_CHECKS: [&str; 6] = [
+    "authentication",
+    "team access",
+    "issues",
+    "projects",
+    "write permission",
+    "clock skew",
+];
+
+fn check_config(config_path: Option<&str>, checks: &mut Vec<CheckResult>) -> Option<Config> {
+    let path = match Config::path(config_path) {
+        Ok(path) => path,
+        Err(e) => {
+            checks.push(CheckResult::new(
+                "configuration",
+                CheckStatus::Fail,
+                format!("{:#}", e),
+            ));
+            return None;
+        }
+    };
+
+    if !path.exists() {
+        checks.push(CheckResult::new(
+            "configuration",
+            CheckStatus::Warn,
+            format!("{} does not exist, using defaults", path.display()),
+        ));
+        return Some(Config::default());
+    }
+
+    match Config::load(config_path) {
+        Ok(config) => {
+            checks.push(CheckResult::new(
+                "configuration",
+                CheckStatus::Pass,
+                format!("Loaded {}", path.display()),
+            ));
+            Some(config)
+        }
+        Err(e) => {
+            checks.push(CheckResult::new(
+                "configuration",
+                CheckStatus::Fail,
+                format!("{}: {:#}", path.display(), e),
+            ));
+            None
+        }
+    }
+}
+
+fn check_key_source(
+    api_key: Option<&str>,
+    config: Option<&Config>,
+    checks: &mut Vec<CheckResult>,
+) -> Option<String> {
+    let from_env = std::env::var("LINEAR_API_KEY").ok();
+
+    let (token, source) = if let Some(key) = api_key {
+        let source = if from_env.as_deref() == Some(key) {
+            "LINEAR_API_KEY environment variable"
+        } else {
+            "--apikey command line argument"
+        };
+        (key.to_string(), source)
+    } else if let Some(key) = config.and_then(|c| c.api_key.clone()) {
+        (key, "configuration file")
+    } else {
+        checks.push(CheckResult::new(
+            "api key",
+            CheckStatus::Fail,
+            "No API key found. Set LINEAR_API_KEY, pass --apikey or set api_key in the config file",
+        ));
+        return None;
+    };
+
+    if token.starts_with("lin_api_") {
+        checks.push(CheckResult::new(
+            "api key",
+            CheckStatus::Pass,
+            format!("Using key from {}", source),
+        ));
+    } else {
+        checks.push(CheckResult::new(
+            "api key",
+            CheckStatus::Warn,
+            format!(
+                "Key from {} does not look like a Personal API Key (lin_api_...)",
+                source
+            ),
+        ));
+    }
+
+    Some(token)
+}
+
+async fn check_api(client: LinearClient, checks: &mut Vec<CheckResult>) {
+    match client.get_viewer().await {
+        Ok(user) => {
+            let email = user.email.map(|e| format!(" <{}>", e)).unwrap_or_default();
+            checks.push(CheckResult::new(
+                "authentication",
+                CheckStatus::Pass,
+                format!("Authenticated as {}{}", user.display_name, email),
+            ));
+        }
+        Err(e) => {
+            checks.push(CheckResult::new(
+                "authentication",
+                CheckStatus::Fail,
+                format!("{:#}", e),
+            ));
+            for name in &API_CHECKS[1..] {
+                checks.push(CheckResult::new(
+                    name,
+                    CheckStatus::Skip,
+                    "Authentication failed",
+                ));
+            }
+            return;
+        }
+    }
+
+    checks.push(match client.get_teams().await {
+        Ok(teams) if teams.is_empty() => CheckResult::new(
+            "team access",
+            CheckStatus::Warn,
+            "No teams are accessible with this key",
+        ),
+        Ok(teams) => {
+            let keys: Vec<&str> = teams.iter().map(|t| t.key.as_str()).collect();
+            CheckResult::new(
+                "team access",
+                CheckStatus::Pass,
+                format!("{} team(s): {}", teams.len(), keys.join(", ")),
+            )
+        }
+        Err(e) => CheckResult::new("team access", CheckStatus::Fail, format!("{:#}", e)),
+    });
+
+    checks.push(match client.get_issues(None, Some(5)).await {
+        Ok(issues) => CheckResult::new(
+            "issues",
+            CheckStatus::Pass,
+            format!("Fetched {} issue(s)", issues.nodes.len()),
+        ),
+        Err(e) => CheckResult::new("issues", CheckStatus::Fail, format!("{:#}", e)),
+    });
+
+    checks.push(match client.get_projects(None).await {
+        Ok(projects) => CheckResult::new(
+            "projects",
+            CheckStatus::Pass,
+            format!("Fetched {} project(s)", projects.len()),
+        ),
+        Err(e) => CheckResult::new("projects", CheckStatus::Fail, format!("{:#}", e)),
+    });
+
+    checks.push(match client.probe_write_access().await {
+        Ok(true) => CheckResult::new(
+            "write permission",
+            CheckStatus::Pass,
+            "Key is allowed to create and update issues",
+        ),
+        Ok(false) => CheckResult::new(
+            "write permission",
+            CheckStatus::Warn,
+            "Key is read-only, creating or editing issues will fail",
+        ),
+        Err(e) => CheckResult::new(
+            "write permission",
+            CheckStatus::Warn,
+            format!("Could not determine write access: {:#}", e),
+        ),
+    });
+
+    checks.push(match client.server_time().await {
+        Ok(server_time) => {
+            let skew = Utc::now().signed_duration_since(server_time).num_seconds();
+            let status = match skew.abs() {
+                0..=30 => CheckStatus::Pass,
+                31..=300 => CheckStatus::Warn,
+                _ => CheckStatus::Fail,
+            };
+            CheckResult::new(
+                "clock skew",
+                status,
+                format!("Local clock is {}s off the Linear servers", skew),
+            )
+        }
+        Err(e) => CheckResult::new("clock skew", CheckStatus::Warn, format!("{:#}", e)),
+    });
+}
+
+fn check_terminal() -> CheckResult {
+    if !std::io::stdout().is_terminal() {
+        return CheckResult::new(
+            "terminal",
+            CheckStatus::Warn,
+            "Standard output is not a terminal, the TUI cannot start",
+        );
+    }
+
+    let term = std::env::var("TERM").unwrap_or_default();
+    let truecolor = matches!(
+        std::env::var("COLORTERM").as_deref(),
+        Ok("truecolor") | Ok("24bit")
+    );
+    let colors = if truecolor {
+        "truecolor"
+    } else if term.contains("256color") {
+        "256 colors"
+    } else {
+        "basic colors"
+    };
+
+    match crossterm::terminal::size() {
+        Ok((width, height)) => {
+            let (min_width, min_height) = MIN_TERMINAL_SIZE;
+            let status =
+                if term.is_empty() || term == "dumb" || width < min_width || height < min_height {
+                    CheckStatus::Warn
+                } else {
+                    CheckStatus::Pass
+                };
+            CheckResult::new(
+                "terminal",
+                status,
+                format!(
+                    "TERM={}, {}, {}x{} (recommended at least {}x{})",
+                    term, colors, width, height, min_width, min_height
+                ),
+            )
+        }
+        Err(e) => CheckResult::new(
+            "terminal",
+            CheckStatus::Warn,
+            format!("Could not read terminal size: {}", e),
+        ),
+    }
+}
+
+fn print_text(report: &Report) {
+    println!("ltui {} diagnostics\n", report.version);
+
+    let name_width = report
+        .checks
+        .iter()
+        .map(|c| c.name.len())
+        .max()
+        .unwrap_or(0);
+
+    for check in &report.checks {
+        println!(
+            "  {}  {:<width$}  {}",
+            check.status.label(),
+            check.name,
+            check.message,
+            width = name_width
+        );
+    }
+
+    let summary = &report.summary;
+    println!(
+        "\n{} passed, {} warning(s), {} failed, {} skipped",
+        summary.passed, summary.warnings, summary.failed, summary.skipped
+    );
+}
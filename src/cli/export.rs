@@ -0,0 +1,125 @@
+// SPDX-FileCopyrightText: Copyright (C) Nicolas Lamirault <nicolas.lamirault@gmail.com>
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::Result;
+use clap::{Args, Subcommand, ValueEnum};
+use std::path::PathBuf;
+
+use crate::cli::find_team;
+use crate::client::LinearClient;
+use crate::config::Config;
+use crate::export::{self, ExportFormat};
+use crate::query::Query;
+use crate::utils::{Column, IssueColumn, ProjectColumn};
+
+#[derive(Subcommand)]
+pub enum ExportCommand {
+    /// Export the issues of a team (or of all teams)
+    Issues {
+        #[command(flatten)]
+        options: ExportOptions,
+
+        /// Columns to include, comma separated (defaults to export.issue_columns)
+        #[arg(long, value_enum, value_delimiter = ',')]
+        columns: Vec<IssueColumn>,
+
+        /// Maximum number of issues to export
+        #[arg(long, default_value_t = 50)]
+        limit: i32,
+
+        /// Only export the issues matching a query
+        /// (e.g. 'state:started assignee:me priority>=high label:bug updated:<7d')
+        #[arg(short, long, value_parser = Query::parse)]
+        query: Option<Query>,
+    },
+    /// Export projects
+    Projects {
+        #[command(flatten)]
+        options: ExportOptions,
+
+        /// Columns to include, comma separated (defaults to export.project_columns)
+        #[arg(long, value_enum, value_delimiter = ',')]
+        columns: Vec<ProjectColumn>,
+    },
+}
+
+#[derive(Args)]
+pub struct ExportOptions {
+    /// Output format (defaults to the file extension, or markdown)
+    #[arg(short, long, value_enum)]
+    format: Option<ExportFormat>,
+
+    /// Output file (defaults to standard output)
+    #[arg(long)]
+    file: Option<PathBuf>,
+}
+
+pub async fn run(
+    client: &LinearClient,
+    config: &Config,
+    team: Option<&str>,
+    command: &ExportCommand,
+) -> Result<()> {
+    match command {
+        ExportCommand::Issues {
+            options,
+            columns,
+            limit,
+            query,
+        } => {
+            let team = match team.or(config.default_team_id.as_deref()) {
+                Some(key) => Some(find_team(client, key).await?),
+                None => None,
+            };
+            let team_id = team.as_ref().map(|t| t.id.as_str());
+            let issues = match query {
+                Some(query) => {
+                    client
+                        .search_issues(query.filter(team_id), Some(*limit))
+                        .await?
+                        .nodes
+                }
+                None => client.get_issues(team_id, Some(*limit)).await?.nodes,
+            };
+
+            let columns = if columns.is_empty() {
+                &config.export.issue_columns
+            } else {
+                columns
+            };
+            output(&issues.iter().collect::<Vec<_>>(), columns, options)
+        }
+        ExportCommand::Projects { options, columns } => {
+            let projects = client.get_projects(None).await?;
+
+            let columns = if columns.is_empty() {
+                &config.export.project_columns
+            } else {
+                columns
+            };
+            output(&projects.iter().collect::<Vec<_>>(), columns, options)
+        }
+    }
+}
+
+fn output<T, C>(items: &[&T], columns: &[C], options: &ExportOptions) -> Result<()>
+where
+    C: Column<T> + ValueEnum,
+{
+    match &options.file {
+        Some(path) => {
+            let format = export::write(path, items, columns, options.format)?;
+            eprintln!(
+                "Exported {} rows to {} ({:?})",
+                items.len(),
+                path.display(),
+                format
+            );
+        }
+        None => {
+            let format = options.format.unwrap_or(ExportFormat::Markdown);
+            print!("{}", export::render(items, columns, format)?);
+        }
+    }
+    Ok(())
+}
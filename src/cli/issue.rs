@@ -0,0 +1,298 @@
+// SPDX-FileCopyrightText: Copyright (C) Nicolas Lamirault <nicolas.lamirault@gmail.com>
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{Context, Result};
+use clap::Subcommand;
+use clap_complete::engine::ArgValueCompleter;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use unicode_width::UnicodeWidthStr;
+
+use crate::cache;
+use crate::cli::completions::complete_issue_identifiers;
+use crate::cli::find_team;
+use crate::client::{IssueCreateInput, LinearClient};
+use crate::config::Config;
+use crate::import::{self, ImportFormat, ImportRow, Resolver};
+use crate::utils::{format_duration_since, format_priority, priority_name, truncate_string};
+
+#[derive(Subcommand)]
+pub enum IssueCommand {
+    /// Show the details of an issue
+    View {
+        /// Issue identifier (e.g. ENG-123)
+        #[arg(add = ArgValueCompleter::new(complete_issue_identifiers))]
+        identifier: String,
+    },
+    /// Create issues in bulk from a CSV file or a JSON array
+    ///
+    /// Recognized columns: title, description, priority, assignee (email),
+    /// labels (comma or semicolon separated), state and external_id. Rows with
+    /// an external_id already imported into the team are skipped.
+    Import {
+        /// CSV or JSON file
+        file: PathBuf,
+
+        /// File format (defaults to the file extension)
+        #[arg(short, long, value_enum)]
+        format: Option<ImportFormat>,
+
+        /// Show what would be created without creating anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+pub async fn run(
+    client: &LinearClient,
+    config: &Config,
+    team: Option<&str>,
+    command: &IssueCommand,
+) -> Result<()> {
+    match command {
+        IssueCommand::View { identifier } => view(client, identifier).await,
+        IssueCommand::Import {
+            file,
+            format,
+            dry_run,
+        } => {
+            let team = team
+                .or(config.default_team_id.as_deref())
+                .context("No team given, use --team or set default_team_id")?;
+            import(client, team, file, *format, *dry_run).await
+        }
+    }
+}
+
+async fn view(client: &LinearClient, identifier: &str) -> Result<()> {
+    let issue = client
+        .get_issue(identifier)
+        .await
+        .with_context(|| format!("Could not fetch issue {}", identifier))?;
+
+    cache::remember(None, std::slice::from_ref(&issue));
+
+    let assignee = issue
+        .assignee
+        .as_ref()
+        .map(|a| a.display_name.as_str())
+        .unwrap_or("Unassigned");
+
+    println!("{} - {}", issue.identifier, issue.title);
+    println!("State:    {}", issue.state.name);
+    println!("Priority: {}", format_priority(issue.priority.unwrap_or(0)));
+    println!("Assignee: {}", assignee);
+    println!("Creator:  {}", issue.creator.display_name);
+    println!("Team:     {} ({})", issue.team.name, issue.team.key);
+    println!("Updated:  {}", format_duration_since(&issue.updated_at));
+    println!("URL:      {}", issue.url);
+    if let Some(parent) = &issue.parent {
+        println!("Parent:   {} - {}", parent.identifier, parent.title);
+    }
+    if !issue.attachments.is_empty() {
+        println!();
+        println!("Attachments:");
+        for attachment in &issue.attachments {
+            println!(
+                "  [{}] {} - {}",
+                attachment.source(),
+                attachment.title,
+                attachment.url
+            );
+        }
+    }
+    if let Some((done, total)) = issue.sub_issue_completion() {
+        println!();
+        println!("Sub-issues ({}/{} done):", done, total);
+        for child in &issue.children {
+            println!(
+                "  {} - {} [{}]",
+                child.identifier, child.title, child.state.name
+            );
+        }
+    }
+
+    if let Some(description) = issue.description.as_deref().filter(|d| !d.is_empty()) {
+        println!();
+        println!("{}", description);
+    }
+
+    Ok(())
+}
+
+/// What to do with a row of the import file.
+enum Action {
+    Create(IssueCreateInput),
+    Skip(String),
+    Fail(String),
+}
+
+async fn import(
+    client: &LinearClient,
+    team: &str,
+    path: &Path,
+    format: Option<ImportFormat>,
+    dry_run: bool,
+) -> Result<()> {
+    let file = import::read(path, format)?;
+    if !file.ignored_columns.is_empty() {
+        eprintln!("Ignoring columns: {}", file.ignored_columns.join(", "));
+    }
+
+    let team = find_team(client, team).await?;
+    let resolver = Resolver::load(client, &team.id).await?;
+
+    let mut external_ids: HashMap<String, usize> = HashMap::new();
+    let mut plan = Vec::new();
+    for (number, row) in file.rows {
+        let action = match &row {
+            Ok(row) => plan_row(client, &team.id, &resolver, row, number, &mut external_ids).await,
+            Err(e) => Action::Fail(format!("{:#}", e)),
+        };
+        plan.push((number, row.ok(), action));
+    }
+
+    if dry_run {
+        print_plan(&plan);
+    }
+
+    let (mut created, mut skipped, mut failed) = (0, 0, 0);
+    for (number, row, action) in plan {
+        match action {
+            Action::Fail(message) => {
+                eprintln!("row {}: {}", number, message);
+                failed += 1;
+            }
+            Action::Skip(identifier) => {
+                if !dry_run {
+                    println!(
+                        "row {}: skipped, already imported as {}",
+                        number, identifier
+                    );
+                }
+                skipped += 1;
+            }
+            Action::Create(_) if dry_run => created += 1,
+            Action::Create(input) => match client.create_issue(&input).await {
+                Ok(issue) => {
+                    println!(
+                        "row {}: created {} {}",
+                        number, issue.identifier, issue.title
+                    );
+                    cache::remember(None, std::slice::from_ref(&issue));
+                    created += 1;
+
+                    let external_id = row.and_then(|row| row.external_id);
+                    if let Some(external_id) = external_id {
+                        let url = import::external_url(&external_id);
+                        if let Err(e) = client
+                            .create_attachment(&issue.id, "Import", &url, &external_id)
+                            .await
+                        {
+                            eprintln!(
+                                "row {}: could not record external id '{}', a re-run would create it again: {:#}",
+                                number, external_id, e
+                            );
+                        }
+                    }
+                }
+                Err(e) => {
+                    eprintln!("row {}: {:#}", number, e);
+                    failed += 1;
+                }
+            },
+        }
+    }
+
+    let verb = if dry_run { "to create" } else { "created" };
+    println!(
+        "\n{} {}, {} skipped, {} failed",
+        created, verb, skipped, failed
+    );
+
+    if failed > 0 {
+        return Err(anyhow::anyhow!("{} row(s) failed", failed));
+    }
+    Ok(())
+}
+
+async fn plan_row(
+    client: &LinearClient,
+    team_id: &str,
+    resolver: &Resolver,
+    row: &ImportRow,
+    number: usize,
+    external_ids: &mut HashMap<String, usize>,
+) -> Action {
+    if let Some(external_id) = &row.external_id {
+        if let Some(previous) = external_ids.insert(external_id.clone(), number) {
+            return Action::Fail(format!(
+                "Duplicate external id '{}' (also on row {})",
+                external_id, previous
+            ));
+        }
+
+        match import::find_imported(client, team_id, external_id).await {
+            Ok(Some(issue)) => return Action::Skip(issue.identifier),
+            Ok(None) => {}
+            Err(e) => return Action::Fail(format!("Could not check external id: {:#}", e)),
+        }
+    }
+
+    match resolver.resolve(row) {
+        Ok(input) => Action::Create(input),
+        Err(e) => Action::Fail(format!("{:#}", e)),
+    }
+}
+
+fn print_plan(plan: &[(usize, Option<ImportRow>, Action)]) {
+    let headers = [
+        "ROW", "ACTION", "TITLE", "PRIORITY", "ASSIGNEE", "LABELS", "STATE",
+    ];
+
+    let rows: Vec<[String; 7]> = plan
+        .iter()
+        .map(|(number, row, action)| {
+            let action = match action {
+                Action::Create(_) => "create".to_string(),
+                Action::Skip(identifier) => format!("skip ({})", identifier),
+                Action::Fail(_) => "error".to_string(),
+            };
+            let row = row.clone().unwrap_or_default();
+            [
+                number.to_string(),
+                action,
+                truncate_string(&row.title, 40),
+                row.priority.map(priority_name).unwrap_or("").to_string(),
+                row.assignee.unwrap_or_default(),
+                row.labels.join(", "),
+                row.state.unwrap_or_default(),
+            ]
+        })
+        .collect();
+
+    let widths: Vec<usize> = (0..headers.len())
+        .map(|i| {
+            rows.iter()
+                .map(|r| r[i].width())
+                .chain([headers[i].len()])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let print_line = |cells: Vec<&str>| {
+        let line: Vec<String> = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{}{}", cell, " ".repeat(width - cell.width())))
+            .collect();
+        println!("{}", line.join("  ").trim_end());
+    };
+
+    print_line(headers.to_vec());
+    for row in &rows {
+        print_line(row.iter().map(String::as_str).collect());
+    }
+    println!();
+}
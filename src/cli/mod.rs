@@ -1,11 +1,25 @@
 // SPDX-FileCopyrightText: Copyright (C) Nicolas Lamirault <nicolas.lamirault@gmail.com>
 // SPDX-License-Identifier: Apache-2.0
 
-use anyhow::Result;
-use clap::Parser;
+pub mod completions;
+pub mod config;
+pub mod doctor;
+pub mod export;
+pub mod issue;
+pub mod report;
+
+use anyhow::{Context, Result};
+use clap::{Parser, Subcommand, ValueEnum};
+use clap_complete::engine::ArgValueCompleter;
 
 use crate::app::App;
+use crate::client::{LinearClient, Team};
 use crate::config::Config;
+use completions::{complete_team_keys, Shell};
+use config::ConfigCommand;
+use export::ExportCommand;
+use issue::IssueCommand;
+use report::ReportCommand;
 
 #[derive(Parser)]
 #[command(name = "ltui")]
@@ -13,32 +27,109 @@ use crate::config::Config;
 #[command(version)]
 pub struct Cli {
     /// Linear Personal API Key (can also be set via LINEAR_API_KEY environment variable)
-    #[arg(short, long, env = "LINEAR_API_KEY")]
+    #[arg(short, long, env = "LINEAR_API_KEY", global = true)]
     pub apikey: Option<String>,
 
     /// Configuration file path
-    #[arg(short, long)]
+    #[arg(short, long, global = true)]
     pub config: Option<String>,
 
     /// Enable debug logging
-    #[arg(short, long)]
+    #[arg(short, long, global = true)]
     pub debug: bool,
 
-    /// Test mode - don't start TUI, just test API calls
-    #[arg(long)]
-    pub test: bool,
+    /// Team key (e.g. ENG) to open on startup or to run commands against
+    #[arg(short, long, global = true, add = ArgValueCompleter::new(complete_team_keys))]
+    pub team: Option<String>,
+
+    #[command(subcommand)]
+    pub command: Option<Commands>,
+}
+
+#[derive(Debug, Clone, Copy, Default, ValueEnum)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+#[derive(Subcommand)]
+pub enum Commands {
+    /// Print the shell completion script
+    Completions {
+        #[arg(value_enum)]
+        shell: Shell,
+    },
+    /// Manage the configuration file
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommand,
+    },
+    /// Diagnose configuration, authentication and API access problems
+    Doctor {
+        /// Output format
+        #[arg(short, long, value_enum, default_value_t)]
+        output: OutputFormat,
+    },
+    /// Export issues or projects to Markdown, CSV or JSON
+    Export {
+        #[command(subcommand)]
+        command: ExportCommand,
+    },
+    /// Work with issues
+    Issue {
+        #[command(subcommand)]
+        command: IssueCommand,
+    },
+    /// Generate activity reports
+    Report {
+        #[command(subcommand)]
+        command: ReportCommand,
+    },
 }
 
 impl Cli {
     pub async fn run(&self) -> Result<()> {
-        let config = Config::load(self.config.as_deref())?;
+        match &self.command {
+            Some(Commands::Completions { shell }) => completions::print_script(*shell),
+            Some(Commands::Config { command }) => {
+                config::run(self.config.as_deref(), self.apikey.as_deref(), command).await
+            }
+            Some(Commands::Doctor { output }) => {
+                doctor::run(self.config.as_deref(), self.apikey.as_deref(), *output).await
+            }
+            Some(Commands::Export { command }) => {
+                let app = self.app().await?;
+                export::run(app.client(), app.config(), self.team.as_deref(), command).await
+            }
+            Some(Commands::Issue { command }) => {
+                let app = self.app().await?;
+                issue::run(app.client(), app.config(), self.team.as_deref(), command).await
+            }
+            Some(Commands::Report { command }) => {
+                let app = self.app().await?;
+                report::run(app.client(), self.team.as_deref(), command).await
+            }
+            None => {
+                let app = self.app().await?;
+                app.run(self.config.as_deref(), self.team.as_deref()).await
+            }
+        }
+    }
 
-        let app = App::new(config, self.apikey.clone()).await?;
+    async fn app(&self) -> Result<App> {
+        let config = Config::load(self.config.as_deref())?;
 
-        if self.test {
-            app.test_mode().await
-        } else {
-            app.run().await
-        }
+        App::new(config, self.apikey.clone()).await
     }
 }
+
+/// Find a team by key (e.g. ENG) or id.
+pub async fn find_team(client: &LinearClient, key: &str) -> Result<Team> {
+    client
+        .get_teams()
+        .await?
+        .into_iter()
+        .find(|t| t.id == key || t.key.eq_ignore_ascii_case(key))
+        .with_context(|| format!("Unknown team '{}'", key))
+}
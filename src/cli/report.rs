@@ -0,0 +1,49 @@
+// SPDX-FileCopyrightText: Copyright (C) Nicolas Lamirault <nicolas.lamirault@gmail.com>
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+use clap::Subcommand;
+
+use crate::cli::find_team;
+use crate::client::LinearClient;
+use crate::report::{self, ReportFormat};
+use crate::utils::parse_duration;
+
+#[derive(Subcommand)]
+pub enum ReportCommand {
+    /// What you completed, moved, created or commented on, and what is in progress
+    Standup {
+        /// Start of the window, relative to now (e.g. 12h, 1d, 3d, 1w)
+        #[arg(long, default_value = "1d")]
+        since: String,
+
+        /// Output format
+        #[arg(short, long, value_enum, default_value_t)]
+        format: ReportFormat,
+    },
+}
+
+pub async fn run(client: &LinearClient, team: Option<&str>, command: &ReportCommand) -> Result<()> {
+    match command {
+        ReportCommand::Standup { since, format } => {
+            let duration = parse_duration(since).with_context(|| {
+                format!("Invalid duration '{}', use e.g. 30m, 12h, 1d or 2w", since)
+            })?;
+
+            let team = match team {
+                Some(key) => Some(find_team(client, key).await?),
+                None => None,
+            };
+
+            let standup = report::standup(
+                client,
+                Utc::now() - duration,
+                team.as_ref().map(|t| t.id.as_str()),
+            )
+            .await?;
+            print!("{}", report::render(&standup, *format));
+            Ok(())
+        }
+    }
+}
@@ -2,11 +2,334 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
 use reqwest::Client;
 use serde_json::json;
+use std::collections::HashMap;
+use std::path::Path;
 
 use super::types::*;
 
+/// Fields of an issue shown in lists, shared by all issue queries and mutations.
+/// The description and the lists of sub-issues, relations and attachments are only fetched for
+/// one issue at a time, by `get_issue`, to keep the cost of queries returning many issues low.
+const ISSUE_FRAGMENT: &str = r#"
+    fragment IssueFields on Issue {
+        id
+        title
+        identifier
+        priority
+        url
+        createdAt
+        updatedAt
+        completedAt
+        state {
+            id
+            name
+            color
+            type
+        }
+        assignee {
+            id
+            name
+            email
+            displayName
+            avatarUrl
+        }
+        creator {
+            id
+            name
+            email
+            displayName
+            avatarUrl
+        }
+        team {
+            id
+            name
+            key
+            description
+        }
+        estimate
+        dueDate
+        labels(first: 20) {
+            nodes {
+                id
+                name
+                color
+            }
+        }
+        project {
+            id
+            name
+        }
+        projectMilestone {
+            id
+            name
+        }
+        cycle {
+            id
+            number
+            name
+        }
+        parent {
+            id
+            identifier
+            title
+        }
+    }
+"#;
+
+/// Fields of an issue shown in its details, on top of `IssueFields`. The lists continue in
+/// `get_issue` when they have more nodes than the first page.
+const ISSUE_DETAIL_FRAGMENT: &str = r#"
+    fragment IssueDetails on Issue {
+        description
+        children(first: 100) {
+            nodes {
+                ...ChildFields
+            }
+            pageInfo {
+                hasNextPage
+                endCursor
+            }
+        }
+        relations(first: 100) {
+            nodes {
+                ...RelationFields
+            }
+            pageInfo {
+                hasNextPage
+                endCursor
+            }
+        }
+        inverseRelations(first: 100) {
+            nodes {
+                ...InverseRelationFields
+            }
+            pageInfo {
+                hasNextPage
+                endCursor
+            }
+        }
+        attachments(first: 100) {
+            nodes {
+                ...AttachmentFields
+            }
+            pageInfo {
+                hasNextPage
+                endCursor
+            }
+        }
+    }
+"#;
+
+/// Relations of an issue, for the dependency graph of a project's issues. Capped, a relation
+/// between two issues of the project is only missed when both have more than 25 relations.
+const ISSUE_RELATIONS_FRAGMENT: &str = r#"
+    fragment IssueRelations on Issue {
+        relations(first: 25) {
+            nodes {
+                ...RelationFields
+            }
+        }
+        inverseRelations(first: 25) {
+            nodes {
+                ...InverseRelationFields
+            }
+        }
+    }
+"#;
+
+const CHILD_FRAGMENT: &str = r#"
+    fragment ChildFields on Issue {
+        id
+        identifier
+        title
+        state {
+            id
+            name
+            color
+            type
+        }
+    }
+"#;
+
+const RELATION_FRAGMENT: &str = r#"
+    fragment RelationFields on IssueRelation {
+        id
+        type
+        relatedIssue {
+            id
+            identifier
+            title
+            state {
+                id
+                name
+                color
+                type
+            }
+        }
+    }
+"#;
+
+const INVERSE_RELATION_FRAGMENT: &str = r#"
+    fragment InverseRelationFields on IssueRelation {
+        id
+        type
+        issue {
+            id
+            identifier
+            title
+            state {
+                id
+                name
+                color
+                type
+            }
+        }
+    }
+"#;
+
+const ATTACHMENT_FRAGMENT: &str = r#"
+    fragment AttachmentFields on Attachment {
+        id
+        title
+        subtitle
+        url
+        sourceType
+        createdAt
+    }
+"#;
+
+/// Lists of `IssueDetails`: the field, the fragment of its nodes and the fragment's definition.
+const ISSUE_LISTS: [(&str, &str, &str); 4] = [
+    ("children", "ChildFields", CHILD_FRAGMENT),
+    ("relations", "RelationFields", RELATION_FRAGMENT),
+    (
+        "inverseRelations",
+        "InverseRelationFields",
+        INVERSE_RELATION_FRAGMENT,
+    ),
+    ("attachments", "AttachmentFields", ATTACHMENT_FRAGMENT),
+];
+
+/// Fields fetched for the entries of an issue's history.
+const HISTORY_FRAGMENT: &str = r#"
+    fragment HistoryFields on IssueHistory {
+        id
+        createdAt
+        actor {
+            id
+            name
+            email
+            displayName
+            avatarUrl
+        }
+        fromState {
+            id
+            name
+            color
+            type
+        }
+        toState {
+            id
+            name
+            color
+            type
+        }
+        fromPriority
+        toPriority
+        fromAssignee {
+            id
+            name
+            email
+            displayName
+            avatarUrl
+        }
+        toAssignee {
+            id
+            name
+            email
+            displayName
+            avatarUrl
+        }
+        fromTitle
+        toTitle
+        fromEstimate
+        toEstimate
+        fromDueDate
+        toDueDate
+        fromProject {
+            id
+            name
+        }
+        toProject {
+            id
+            name
+        }
+        fromCycle {
+            id
+            number
+            name
+        }
+        toCycle {
+            id
+            number
+            name
+        }
+        addedLabels {
+            id
+            name
+            color
+        }
+        removedLabels {
+            id
+            name
+            color
+        }
+        updatedDescription
+    }
+"#;
+
+/// Fields fetched for the comments of an issue.
+const COMMENT_FRAGMENT: &str = r#"
+    fragment CommentFields on Comment {
+        id
+        body
+        createdAt
+        user {
+            id
+            name
+            email
+            displayName
+            avatarUrl
+        }
+    }
+"#;
+
+/// A page of a connection, with where the next one starts.
+#[derive(serde::Deserialize)]
+struct Page<T> {
+    nodes: Vec<T>,
+    #[serde(rename = "pageInfo")]
+    page_info: Cursor,
+}
+
+#[derive(serde::Deserialize)]
+struct Cursor {
+    #[serde(rename = "hasNextPage")]
+    has_next_page: bool,
+    #[serde(rename = "endCursor")]
+    end_cursor: Option<String>,
+}
+
+impl Cursor {
+    /// Where the next page starts, if there is one.
+    fn next(&self) -> Option<&str> {
+        self.end_cursor.as_deref().filter(|_| self.has_next_page)
+    }
+}
+
 #[derive(Clone)]
 pub struct LinearClient {
     client: Client,
@@ -24,6 +347,30 @@ impl LinearClient {
     }
 
     async fn execute_query<T>(&self, query: &str, variables: Option<serde_json::Value>) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let graphql_response: GraphQLResponse<T> = self.send_query(query, variables).await?;
+
+        if let Some(errors) = graphql_response.errors {
+            let error_messages: Vec<String> = errors.iter().map(|e| e.message.clone()).collect();
+            return Err(anyhow::anyhow!(
+                "GraphQL errors: {}",
+                error_messages.join(", ")
+            ));
+        }
+
+        graphql_response
+            .data
+            .context("GraphQL response contained no data")
+    }
+
+    /// Send a query and parse the response, leaving its GraphQL errors to the caller.
+    async fn send_query<T>(
+        &self,
+        query: &str,
+        variables: Option<serde_json::Value>,
+    ) -> Result<GraphQLResponse<T>>
     where
         T: serde::de::DeserializeOwned,
     {
@@ -54,20 +401,37 @@ impl LinearClient {
             .await
             .context("Failed to get response text")?;
 
-        let graphql_response: GraphQLResponse<T> =
-            serde_json::from_str(&response_text).context("Failed to parse GraphQL response")?;
+        serde_json::from_str(&response_text).context("Failed to parse GraphQL response")
+    }
 
-        if let Some(errors) = graphql_response.errors {
-            let error_messages: Vec<String> = errors.iter().map(|e| e.message.clone()).collect();
-            return Err(anyhow::anyhow!(
-                "GraphQL errors: {}",
-                error_messages.join(", ")
-            ));
-        }
+    /// Fetch every page of the connection found at `path` in the response. The query takes an
+    /// `$after: String` variable and selects `nodes` and `pageInfo { hasNextPage endCursor }`.
+    async fn fetch_all<T>(
+        &self,
+        query: &str,
+        mut variables: serde_json::Value,
+        path: &[&str],
+    ) -> Result<Vec<T>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let mut nodes = Vec::new();
+        loop {
+            let mut response: serde_json::Value =
+                self.execute_query(query, Some(variables.clone())).await?;
+            let connection = path
+                .iter()
+                .try_fold(&mut response, |value, key| value.get_mut(*key))
+                .with_context(|| format!("GraphQL response has no {}", path.join(".")))?;
+            let page: Page<T> = serde_json::from_value(connection.take())
+                .context("Failed to parse GraphQL response")?;
+            nodes.extend(page.nodes);
 
-        graphql_response
-            .data
-            .context("GraphQL response contained no data")
+            match page.page_info.next() {
+                Some(cursor) => variables["after"] = json!(cursor),
+                None => return Ok(nodes),
+            }
+        }
     }
 
     pub async fn get_viewer(&self) -> Result<User> {
@@ -92,6 +456,59 @@ impl LinearClient {
         Ok(response.viewer)
     }
 
+    /// Current time according to the Linear API servers, read from the `Date` response header.
+    pub async fn server_time(&self) -> Result<DateTime<Utc>> {
+        let response = self
+            .client
+            .get(&self.base_url)
+            .send()
+            .await
+            .context("Failed to reach Linear API")?;
+
+        let date = response
+            .headers()
+            .get(reqwest::header::DATE)
+            .context("Response has no Date header")?
+            .to_str()
+            .context("Invalid Date header")?;
+
+        let date = DateTime::parse_from_rfc2822(date).context("Could not parse Date header")?;
+        Ok(date.with_timezone(&Utc))
+    }
+
+    /// Check whether the API key is allowed to run mutations, without modifying any data.
+    ///
+    /// The probe updates an issue that cannot exist: a key with write access gets a
+    /// "not found" error, a read-only key gets a "forbidden" one. Errors are told apart by
+    /// their `extensions`, any other outcome cannot be interpreted and is returned as an error.
+    pub async fn probe_write_access(&self) -> Result<bool> {
+        let query = r#"
+            mutation {
+                issueUpdate(id: "00000000-0000-0000-0000-000000000000", input: {}) {
+                    success
+                }
+            }
+        "#;
+
+        let response: GraphQLResponse<serde_json::Value> = self.send_query(query, None).await?;
+        let errors = response.errors.unwrap_or_default();
+        let kinds: Vec<String> = errors.iter().filter_map(GraphQLError::kind).collect();
+
+        if errors.is_empty() {
+            Ok(true)
+        } else if kinds.iter().any(|k| k == "forbidden") {
+            Ok(false)
+        } else if kinds
+            .iter()
+            .any(|k| k == "entitynotfound" || k == "notfound")
+        {
+            Ok(true)
+        } else {
+            let messages: Vec<&str> = errors.iter().map(|e| e.message.as_str()).collect();
+            Err(anyhow::anyhow!("Unexpected error: {}", messages.join(", ")))
+        }
+    }
+
     pub async fn get_teams(&self) -> Result<Vec<Team>> {
         let query = r#"
             query {
@@ -120,148 +537,145 @@ impl LinearClient {
         Ok(response.teams.nodes)
     }
 
-    pub async fn get_issues(
-        &self,
-        team_id: Option<&str>,
-        first: Option<i32>,
-    ) -> Result<IssuesConnection> {
-        let (query, variables) = if let Some(team_id) = team_id {
-            let query = r#"
-                query($teamId: ID, $first: Int) {
-                    issues(filter: { team: { id: { eq: $teamId } } }, first: $first, orderBy: updatedAt) {
-                        nodes {
-                            id
-                            title
-                            description
-                            identifier
-                            priority
-                            url
-                            createdAt
-                            updatedAt
-                            state {
+    /// Member count and open issues by state type of every team, by team id.
+    pub async fn get_team_stats(&self) -> Result<HashMap<String, TeamStats>> {
+        let query = r#"
+            query {
+                teams {
+                    nodes {
+                        id
+                        members(first: 250) {
+                            nodes {
                                 id
-                                name
-                                color
-                                type
                             }
-                            assignee {
-                                id
-                                name
-                                email
-                                displayName
-                                avatarUrl
+                            pageInfo {
+                                hasNextPage
+                                endCursor
                             }
-                            creator {
-                                id
-                                name
-                                email
-                                displayName
-                                avatarUrl
+                        }
+                        issues(first: 250, filter: { state: { type: { nin: ["completed", "canceled"] } } }) {
+                            nodes {
+                                state {
+                                    type
+                                }
                             }
-                            team {
-                                id
-                                name
-                                key
-                                description
+                            pageInfo {
+                                hasNextPage
+                                endCursor
                             }
                         }
+                    }
+                }
+            }
+        "#;
+
+        // The following pages of a team, when the first one is full
+        let members_query = r#"
+            query($teamId: String!, $after: String) {
+                team(id: $teamId) {
+                    members(first: 250, after: $after) {
+                        nodes {
+                            id
+                        }
                         pageInfo {
                             hasNextPage
-                            hasPreviousPage
-                            startCursor
                             endCursor
                         }
                     }
                 }
-            "#;
-
-            let variables = json!({
-                "teamId": team_id,
-                "first": first.unwrap_or(50)
-            });
-
-            (query, Some(variables))
-        } else {
-            let query = r#"
-                query($first: Int) {
-                    issues(first: $first, orderBy: updatedAt) {
+            }
+        "#;
+        let issues_query = r#"
+            query($teamId: String!, $after: String) {
+                team(id: $teamId) {
+                    issues(first: 250, after: $after, filter: { state: { type: { nin: ["completed", "canceled"] } } }) {
                         nodes {
-                            id
-                            title
-                            description
-                            identifier
-                            priority
-                            url
-                            createdAt
-                            updatedAt
                             state {
-                                id
-                                name
-                                color
                                 type
                             }
-                            assignee {
-                                id
-                                name
-                                email
-                                displayName
-                                avatarUrl
-                            }
-                            creator {
-                                id
-                                name
-                                email
-                                displayName
-                                avatarUrl
-                            }
-                            team {
-                                id
-                                name
-                                key
-                                description
-                            }
                         }
                         pageInfo {
                             hasNextPage
-                            hasPreviousPage
-                            startCursor
                             endCursor
                         }
                     }
                 }
-            "#;
+            }
+        "#;
 
-            let variables = json!({
-                "first": first.unwrap_or(50)
-            });
+        #[derive(serde::Deserialize)]
+        struct TeamsResponse {
+            teams: Connection<TeamNode>,
+        }
 
-            (query, Some(variables))
-        };
+        #[derive(serde::Deserialize)]
+        struct Connection<T> {
+            nodes: Vec<T>,
+        }
 
         #[derive(serde::Deserialize)]
-        struct IssuesResponse {
-            issues: IssuesConnection,
+        struct TeamNode {
+            id: String,
+            members: Page<serde_json::Value>,
+            issues: Page<OpenIssue>,
         }
 
-        let response: IssuesResponse = self.execute_query(query, variables).await?;
-        Ok(response.issues)
+        #[derive(serde::Deserialize)]
+        struct OpenIssue {
+            state: OpenIssueState,
+        }
+
+        #[derive(serde::Deserialize)]
+        struct OpenIssueState {
+            #[serde(rename = "type")]
+            state_type: String,
+        }
+
+        let response: TeamsResponse = self.execute_query(query, None).await?;
+        let mut stats = HashMap::new();
+        for mut team in response.teams.nodes {
+            if let Some(cursor) = team.members.page_info.next() {
+                let variables = json!({ "teamId": team.id, "after": cursor });
+                let more: Vec<serde_json::Value> = self
+                    .fetch_all(members_query, variables, &["team", "members"])
+                    .await?;
+                team.members.nodes.extend(more);
+            }
+            if let Some(cursor) = team.issues.page_info.next() {
+                let variables = json!({ "teamId": team.id, "after": cursor });
+                let more: Vec<OpenIssue> = self
+                    .fetch_all(issues_query, variables, &["team", "issues"])
+                    .await?;
+                team.issues.nodes.extend(more);
+            }
+
+            let mut team_stats = TeamStats {
+                members: team.members.nodes.len(),
+                ..Default::default()
+            };
+            for issue in team.issues.nodes {
+                *team_stats.open.entry(issue.state.state_type).or_default() += 1;
+            }
+            stats.insert(team.id, team_stats);
+        }
+        Ok(stats)
     }
 
-    pub async fn get_projects(&self, _team_id: Option<&str>) -> Result<Vec<Project>> {
-        // For now, get all projects - team filtering can be added later
+    /// A team with its members, workflow states, labels, active cycle and settings.
+    pub async fn get_team(&self, id: &str) -> Result<TeamDetails> {
         let query = r#"
-            query {
-                projects {
-                    nodes {
-                        id
-                        name
-                        description
-                        status {
-                            name
-                            color
-                            type
-                        }
-                        lead {
+            query($id: String!) {
+                team(id: $id) {
+                    id
+                    name
+                    key
+                    description
+                    cyclesEnabled
+                    cycleDuration
+                    triageEnabled
+                    requirePriorityToLeaveTriage
+                    members(first: 250) {
+                        nodes {
                             id
                             name
                             email
@@ -269,68 +683,335 @@ impl LinearClient {
                             avatarUrl
                         }
                     }
-                }
-            }
-        "#;
-
-        #[derive(serde::Deserialize)]
-        struct ProjectsResponse {
-            projects: ProjectsConnection,
-        }
-
-        #[derive(serde::Deserialize)]
-        struct ProjectsConnection {
-            nodes: Vec<Project>,
+                    states(first: 100) {
+                        nodes {
+                            id
+                            name
+                            color
+                            type
+                            position
+                        }
+                    }
+                    labels(first: 250) {
+                        nodes {
+                            id
+                            name
+                            color
+                        }
+                    }
+                    activeCycle {
+                        id
+                        number
+                        name
+                        startsAt
+                        endsAt
+                        completedAt
+                        progress
+                        isActive
+                        isFuture
+                        issueCountHistory
+                        completedIssueCountHistory
+                    }
+                }
+            }
+        "#;
+
+        let variables = json!({
+            "id": id
+        });
+
+        #[derive(serde::Deserialize)]
+        struct TeamResponse {
+            team: TeamDetails,
         }
 
-        let response: ProjectsResponse = self.execute_query(query, None).await?;
-        Ok(response.projects.nodes)
+        let response: TeamResponse = self.execute_query(query, Some(variables)).await?;
+        Ok(response.team)
+    }
+
+    /// Members of a team and its issues that are not completed or canceled.
+    pub async fn get_team_workload(&self, team_id: &str) -> Result<TeamWorkload> {
+        let members_query = r#"
+            query($id: String!, $after: String) {
+                team(id: $id) {
+                    members(first: 250, after: $after) {
+                        nodes {
+                            id
+                            name
+                            email
+                            displayName
+                            avatarUrl
+                        }
+                        pageInfo {
+                            hasNextPage
+                            endCursor
+                        }
+                    }
+                }
+            }
+        "#;
+        let issues_query = r#"
+            query($id: String!, $after: String) {
+                team(id: $id) {
+                    issues(first: 100, after: $after, filter: { state: { type: { nin: ["completed", "canceled"] } } }) {
+                        nodes {
+                            ...IssueFields
+                        }
+                        pageInfo {
+                            hasNextPage
+                            endCursor
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let variables = json!({
+            "id": team_id
+        });
+
+        let issues_query = format!("{}{}", issues_query, ISSUE_FRAGMENT);
+        Ok(TeamWorkload {
+            members: self
+                .fetch_all(members_query, variables.clone(), &["team", "members"])
+                .await?,
+            issues: self
+                .fetch_all(&issues_query, variables, &["team", "issues"])
+                .await?,
+        })
     }
 
-    pub async fn create_issue(
+    pub async fn get_issues(
         &self,
-        team_id: &str,
-        title: &str,
-        description: Option<&str>,
-    ) -> Result<Issue> {
+        team_id: Option<&str>,
+        first: Option<i32>,
+    ) -> Result<IssuesConnection> {
+        let (query, variables) = if let Some(team_id) = team_id {
+            let query = r#"
+                query($teamId: ID, $first: Int) {
+                    issues(filter: { team: { id: { eq: $teamId } } }, first: $first, orderBy: updatedAt) {
+                        nodes {
+                            ...IssueFields
+                        }
+                        pageInfo {
+                            hasNextPage
+                            hasPreviousPage
+                            startCursor
+                            endCursor
+                        }
+                    }
+                }
+            "#;
+
+            let variables = json!({
+                "teamId": team_id,
+                "first": first.unwrap_or(50)
+            });
+
+            (query, Some(variables))
+        } else {
+            let query = r#"
+                query($first: Int) {
+                    issues(first: $first, orderBy: updatedAt) {
+                        nodes {
+                            ...IssueFields
+                        }
+                        pageInfo {
+                            hasNextPage
+                            hasPreviousPage
+                            startCursor
+                            endCursor
+                        }
+                    }
+                }
+            "#;
+
+            let variables = json!({
+                "first": first.unwrap_or(50)
+            });
+
+            (query, Some(variables))
+        };
+
+        #[derive(serde::Deserialize)]
+        struct IssuesResponse {
+            issues: IssuesConnection,
+        }
+
+        let query = format!("{}{}", query, ISSUE_FRAGMENT);
+        let response: IssuesResponse = self.execute_query(&query, variables).await?;
+        Ok(response.issues)
+    }
+
+    /// An issue with its details: the description and every sub-issue, relation and attachment.
+    pub async fn get_issue(&self, id: &str) -> Result<Issue> {
         let query = r#"
-            mutation($input: IssueCreateInput!) {
-                issueCreate(input: $input) {
-                    success
-                    issue {
+            query($id: String!) {
+                issue(id: $id) {
+                    ...IssueFields
+                    ...IssueDetails
+                }
+            }
+        "#;
+
+        let variables = json!({
+            "id": id
+        });
+
+        let mut query = format!("{}{}{}", query, ISSUE_FRAGMENT, ISSUE_DETAIL_FRAGMENT);
+        for (_, _, fragment) in ISSUE_LISTS {
+            query.push_str(fragment);
+        }
+        let mut response: serde_json::Value = self.execute_query(&query, Some(variables)).await?;
+        let mut issue = response["issue"].take();
+        let id = issue["id"].as_str().context("Issue not found")?.to_string();
+
+        // The lists continue where their first page stopped
+        for (field, name, fragment) in ISSUE_LISTS {
+            let page: Page<serde_json::Value> = serde_json::from_value(issue[field].take())
+                .context("Failed to parse GraphQL response")?;
+            let mut nodes = page.nodes;
+            if let Some(cursor) = page.page_info.next() {
+                let query = format!(
+                    r#"
+                    query($id: String!, $after: String) {{
+                        issue(id: $id) {{
+                            {}(first: 100, after: $after) {{
+                                nodes {{
+                                    ...{}
+                                }}
+                                pageInfo {{
+                                    hasNextPage
+                                    endCursor
+                                }}
+                            }}
+                        }}
+                    }}
+                    {}"#,
+                    field, name, fragment
+                );
+                let variables = json!({ "id": id, "after": cursor });
+                nodes.extend(
+                    self.fetch_all::<serde_json::Value>(&query, variables, &["issue", field])
+                        .await?,
+                );
+            }
+            issue[field] = json!({ "nodes": nodes });
+        }
+
+        serde_json::from_value(issue).context("Failed to parse GraphQL response")
+    }
+
+    pub async fn get_projects(&self, _team_id: Option<&str>) -> Result<Vec<Project>> {
+        // For now, get all projects - team filtering can be added later
+        let query = r#"
+            query {
+                projects {
+                    nodes {
                         id
-                        title
+                        name
                         description
-                        identifier
-                        priority
-                        url
-                        createdAt
-                        updatedAt
-                        state {
-                            id
+                        status {
                             name
                             color
                             type
                         }
-                        assignee {
+                        lead {
                             id
                             name
                             email
                             displayName
                             avatarUrl
                         }
-                        creator {
+                    }
+                }
+            }
+        "#;
+
+        #[derive(serde::Deserialize)]
+        struct ProjectsResponse {
+            projects: ProjectsConnection,
+        }
+
+        #[derive(serde::Deserialize)]
+        struct ProjectsConnection {
+            nodes: Vec<Project>,
+        }
+
+        let response: ProjectsResponse = self.execute_query(query, None).await?;
+        Ok(response.projects.nodes)
+    }
+
+    /// A project with its members, milestones, updates and issues.
+    pub async fn get_project(&self, id: &str) -> Result<ProjectDetails> {
+        let query = r#"
+            query($id: String!) {
+                project(id: $id) {
+                    id
+                    name
+                    description
+                    status {
+                        name
+                        color
+                        type
+                    }
+                    lead {
+                        id
+                        name
+                        email
+                        displayName
+                        avatarUrl
+                    }
+                    startDate
+                    targetDate
+                    progress
+                    members {
+                        nodes {
                             id
                             name
                             email
                             displayName
                             avatarUrl
                         }
-                        team {
+                    }
+                    projectMilestones {
+                        nodes {
                             id
                             name
-                            key
-                            description
+                            targetDate
+                            sortOrder
+                        }
+                    }
+                    projectUpdates(first: 10) {
+                        nodes {
+                            id
+                            body
+                            health
+                            createdAt
+                            user {
+                                id
+                                name
+                                email
+                                displayName
+                                avatarUrl
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+        // The milestones count their issues among these, by the issues' milestone
+        let issues_query = r#"
+            query($id: String!, $after: String) {
+                project(id: $id) {
+                    issues(first: 50, after: $after) {
+                        nodes {
+                            ...IssueFields
+                            ...IssueRelations
+                        }
+                        pageInfo {
+                            hasNextPage
+                            endCursor
                         }
                     }
                 }
@@ -338,34 +1019,1060 @@ impl LinearClient {
         "#;
 
         let variables = json!({
-            "input": {
-                "teamId": team_id,
-                "title": title,
-                "description": description
-            }
+            "id": id
         });
 
         #[derive(serde::Deserialize)]
-        struct CreateIssueResponse {
-            #[serde(rename = "issueCreate")]
-            issue_create: IssueCreatePayload,
+        struct ProjectResponse {
+            project: ProjectDetails,
         }
 
+        let response: ProjectResponse = self.execute_query(query, Some(variables.clone())).await?;
+        let mut project = response.project;
+        project
+            .milestones
+            .sort_by(|a, b| a.sort_order.total_cmp(&b.sort_order));
+
+        let issues_query = format!(
+            "{}{}{}{}{}",
+            issues_query,
+            ISSUE_FRAGMENT,
+            ISSUE_RELATIONS_FRAGMENT,
+            RELATION_FRAGMENT,
+            INVERSE_RELATION_FRAGMENT
+        );
+        project.issues = self
+            .fetch_all(&issues_query, variables, &["project", "issues"])
+            .await?;
+        Ok(project)
+    }
+
+    /// Issues matching a Linear `IssueFilter`, most recently updated first.
+    pub async fn search_issues(
+        &self,
+        filter: serde_json::Value,
+        first: Option<i32>,
+    ) -> Result<IssuesConnection> {
+        let query = r#"
+            query($filter: IssueFilter, $first: Int) {
+                issues(filter: $filter, first: $first, orderBy: updatedAt) {
+                    nodes {
+                        ...IssueFields
+                    }
+                    pageInfo {
+                        hasNextPage
+                        hasPreviousPage
+                        startCursor
+                        endCursor
+                    }
+                }
+            }
+        "#;
+
+        let variables = json!({
+            "filter": filter,
+            "first": first.unwrap_or(50)
+        });
+
         #[derive(serde::Deserialize)]
-        struct IssueCreatePayload {
-            success: bool,
-            issue: Option<Issue>,
+        struct IssuesResponse {
+            issues: IssuesConnection,
         }
 
-        let response: CreateIssueResponse = self.execute_query(query, Some(variables)).await?;
+        let query = format!("{}{}", query, ISSUE_FRAGMENT);
+        let response: IssuesResponse = self.execute_query(&query, Some(variables)).await?;
+        Ok(response.issues)
+    }
 
-        if !response.issue_create.success {
-            return Err(anyhow::anyhow!("Failed to create issue"));
+    /// Custom views saved in the workspace.
+    pub async fn get_custom_views(&self) -> Result<Vec<CustomView>> {
+        let query = r#"
+            query {
+                customViews(first: 100) {
+                    nodes {
+                        id
+                        name
+                        description
+                    }
+                }
+            }
+        "#;
+
+        #[derive(serde::Deserialize)]
+        struct CustomViewsResponse {
+            #[serde(rename = "customViews")]
+            custom_views: CustomViewsConnection,
         }
 
-        response
-            .issue_create
-            .issue
-            .context("Issue creation succeeded but no issue data returned")
+        #[derive(serde::Deserialize)]
+        struct CustomViewsConnection {
+            nodes: Vec<CustomView>,
+        }
+
+        let response: CustomViewsResponse = self.execute_query(query, None).await?;
+        Ok(response.custom_views.nodes)
+    }
+
+    /// Issues of a custom view, filtered the way the view is on Linear.
+    pub async fn get_custom_view_issues(
+        &self,
+        view_id: &str,
+        first: Option<i32>,
+    ) -> Result<IssuesConnection> {
+        let query = r#"
+            query($id: String!, $first: Int) {
+                customView(id: $id) {
+                    issues(first: $first, orderBy: updatedAt) {
+                        nodes {
+                            ...IssueFields
+                        }
+                        pageInfo {
+                            hasNextPage
+                            hasPreviousPage
+                            startCursor
+                            endCursor
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let variables = json!({
+            "id": view_id,
+            "first": first.unwrap_or(50)
+        });
+
+        #[derive(serde::Deserialize)]
+        struct CustomViewResponse {
+            #[serde(rename = "customView")]
+            custom_view: CustomViewIssues,
+        }
+
+        #[derive(serde::Deserialize)]
+        struct CustomViewIssues {
+            issues: IssuesConnection,
+        }
+
+        let query = format!("{}{}", query, ISSUE_FRAGMENT);
+        let response: CustomViewResponse = self.execute_query(&query, Some(variables)).await?;
+        Ok(response.custom_view.issues)
+    }
+
+    /// Issues matching a Linear `IssueFilter`, with the history entries and the comments
+    /// since the given time. The issues are fetched by pages of 25 with the first entries of
+    /// their history and comments, which continue for the issues with more.
+    pub async fn get_issue_activity(
+        &self,
+        filter: serde_json::Value,
+        since: DateTime<Utc>,
+        first: Option<i32>,
+    ) -> Result<Vec<IssueActivity>> {
+        let query = r#"
+            query($filter: IssueFilter, $since: DateTimeOrDuration, $first: Int, $after: String) {
+                issues(filter: $filter, first: $first, after: $after, orderBy: updatedAt) {
+                    nodes {
+                        ...IssueFields
+                        history(first: 10, orderBy: createdAt) {
+                            nodes {
+                                ...HistoryFields
+                            }
+                            pageInfo {
+                                hasNextPage
+                                endCursor
+                            }
+                        }
+                        comments(first: 10, filter: { createdAt: { gte: $since } }) {
+                            nodes {
+                                ...CommentFields
+                            }
+                            pageInfo {
+                                hasNextPage
+                                endCursor
+                            }
+                        }
+                    }
+                    pageInfo {
+                        hasNextPage
+                        endCursor
+                    }
+                }
+            }
+        "#;
+        let comments_query = r#"
+            query($id: String!, $since: DateTimeOrDuration, $after: String) {
+                issue(id: $id) {
+                    comments(first: 50, after: $after, filter: { createdAt: { gte: $since } }) {
+                        nodes {
+                            ...CommentFields
+                        }
+                        pageInfo {
+                            hasNextPage
+                            endCursor
+                        }
+                    }
+                }
+            }
+        "#;
+
+        #[derive(serde::Deserialize)]
+        struct ActivityNode {
+            #[serde(flatten)]
+            issue: Issue,
+            history: Page<IssueHistory>,
+            comments: Page<Comment>,
+        }
+
+        let query = format!(
+            "{}{}{}{}",
+            query, ISSUE_FRAGMENT, HISTORY_FRAGMENT, COMMENT_FRAGMENT
+        );
+        let comments_query = format!("{}{}", comments_query, COMMENT_FRAGMENT);
+        let limit = first.unwrap_or(100).max(0) as usize;
+        let mut activities = Vec::new();
+        let mut after: Option<String> = None;
+        while activities.len() < limit {
+            let variables = json!({
+                "filter": filter,
+                "since": since,
+                "first": (limit - activities.len()).min(25),
+                "after": after
+            });
+            let mut response: serde_json::Value =
+                self.execute_query(&query, Some(variables)).await?;
+            let page: Page<ActivityNode> = serde_json::from_value(response["issues"].take())
+                .context("Failed to parse GraphQL response")?;
+
+            for node in page.nodes {
+                // Newest first, the entries before `since` are not needed
+                let mut history = node.history.nodes;
+                let more_history = history.last().is_some_and(|h| h.created_at >= since);
+                if let Some(cursor) = node.history.page_info.next().filter(|_| more_history) {
+                    history.extend(self.history_since(&node.issue.id, cursor, since).await?);
+                }
+                let mut comments = node.comments.nodes;
+                if let Some(cursor) = node.comments.page_info.next() {
+                    let variables = json!({ "id": node.issue.id, "since": since, "after": cursor });
+                    let more: Vec<Comment> = self
+                        .fetch_all(&comments_query, variables, &["issue", "comments"])
+                        .await?;
+                    comments.extend(more);
+                }
+                history.retain(|h| h.created_at >= since);
+                activities.push(IssueActivity {
+                    issue: node.issue,
+                    history,
+                    comments,
+                });
+            }
+
+            match page.page_info.next() {
+                Some(cursor) => after = Some(cursor.to_string()),
+                None => break,
+            }
+        }
+        Ok(activities)
+    }
+
+    /// History entries of an issue after `cursor`, newest first, stopping at the first page
+    /// reaching entries older than `since`.
+    async fn history_since(
+        &self,
+        id: &str,
+        cursor: &str,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<IssueHistory>> {
+        let query = r#"
+            query($id: String!, $after: String) {
+                issue(id: $id) {
+                    history(first: 50, after: $after, orderBy: createdAt) {
+                        nodes {
+                            ...HistoryFields
+                        }
+                        pageInfo {
+                            hasNextPage
+                            endCursor
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let query = format!("{}{}", query, HISTORY_FRAGMENT);
+        let mut history = Vec::new();
+        let mut cursor = cursor.to_string();
+        loop {
+            let variables = json!({ "id": id, "after": cursor });
+            let mut response: serde_json::Value =
+                self.execute_query(&query, Some(variables)).await?;
+            let page: Page<IssueHistory> =
+                serde_json::from_value(response["issue"]["history"].take())
+                    .context("Failed to parse GraphQL response")?;
+            let done = page.nodes.last().is_none_or(|h| h.created_at < since);
+            history.extend(page.nodes);
+            match page.page_info.next() {
+                Some(next) if !done => cursor = next.to_string(),
+                _ => return Ok(history),
+            }
+        }
+    }
+
+    /// An issue with its whole history and its comments, oldest first.
+    pub async fn get_issue_history(&self, id: &str) -> Result<IssueActivity> {
+        let query = r#"
+            query($id: String!) {
+                issue(id: $id) {
+                    ...IssueFields
+                    history(first: 250) {
+                        nodes {
+                            ...HistoryFields
+                        }
+                        pageInfo {
+                            hasNextPage
+                            endCursor
+                        }
+                    }
+                    comments(first: 250) {
+                        nodes {
+                            ...CommentFields
+                        }
+                        pageInfo {
+                            hasNextPage
+                            endCursor
+                        }
+                    }
+                }
+            }
+        "#;
+
+        // The following pages, for long-lived issues
+        let history_query = r#"
+            query($id: String!, $after: String) {
+                issue(id: $id) {
+                    history(first: 250, after: $after) {
+                        nodes {
+                            ...HistoryFields
+                        }
+                        pageInfo {
+                            hasNextPage
+                            endCursor
+                        }
+                    }
+                }
+            }
+        "#;
+        let comments_query = r#"
+            query($id: String!, $after: String) {
+                issue(id: $id) {
+                    comments(first: 250, after: $after) {
+                        nodes {
+                            ...CommentFields
+                        }
+                        pageInfo {
+                            hasNextPage
+                            endCursor
+                        }
+                    }
+                }
+            }
+        "#;
+        let variables = json!({ "id": id });
+
+        #[derive(serde::Deserialize)]
+        struct HistoryResponse {
+            issue: HistoryNode,
+        }
+
+        #[derive(serde::Deserialize)]
+        struct HistoryNode {
+            #[serde(flatten)]
+            issue: Issue,
+            history: Page<IssueHistory>,
+            comments: Page<Comment>,
+        }
+
+        let full_query = format!(
+            "{}{}{}{}",
+            query, ISSUE_FRAGMENT, HISTORY_FRAGMENT, COMMENT_FRAGMENT
+        );
+        let response: HistoryResponse = self.execute_query(&full_query, Some(variables)).await?;
+        let HistoryNode {
+            issue,
+            mut history,
+            mut comments,
+        } = response.issue;
+
+        if let Some(cursor) = history.page_info.next() {
+            let query = format!("{}{}", history_query, HISTORY_FRAGMENT);
+            let variables = json!({ "id": id, "after": cursor });
+            let more: Vec<IssueHistory> = self
+                .fetch_all(&query, variables, &["issue", "history"])
+                .await?;
+            history.nodes.extend(more);
+        }
+        if let Some(cursor) = comments.page_info.next() {
+            let query = format!("{}{}", comments_query, COMMENT_FRAGMENT);
+            let variables = json!({ "id": id, "after": cursor });
+            let more: Vec<Comment> = self
+                .fetch_all(&query, variables, &["issue", "comments"])
+                .await?;
+            comments.nodes.extend(more);
+        }
+
+        let mut activity = IssueActivity {
+            issue,
+            history: history.nodes,
+            comments: comments.nodes,
+        };
+        activity.history.sort_by_key(|h| h.created_at);
+        activity.comments.sort_by_key(|c| c.created_at);
+        Ok(activity)
+    }
+
+    pub async fn get_users(&self) -> Result<Vec<User>> {
+        let query = r#"
+            query($after: String) {
+                users(first: 250, after: $after) {
+                    nodes {
+                        id
+                        name
+                        email
+                        displayName
+                        avatarUrl
+                    }
+                    pageInfo {
+                        hasNextPage
+                        endCursor
+                    }
+                }
+            }
+        "#;
+
+        self.fetch_all(query, json!({}), &["users"]).await
+    }
+
+    /// Labels usable on the team's issues: the team labels and the workspace labels.
+    pub async fn get_labels(&self, team_id: &str) -> Result<Vec<IssueLabel>> {
+        let query = r#"
+            query($teamId: ID, $after: String) {
+                issueLabels(first: 250, after: $after, filter: { or: [{ team: { id: { eq: $teamId } } }, { team: { null: true } }] }) {
+                    nodes {
+                        id
+                        name
+                        color
+                    }
+                    pageInfo {
+                        hasNextPage
+                        endCursor
+                    }
+                }
+            }
+        "#;
+
+        let variables = json!({
+            "teamId": team_id
+        });
+
+        self.fetch_all(query, variables, &["issueLabels"]).await
+    }
+
+    pub async fn get_workflow_states(&self, team_id: &str) -> Result<Vec<IssueState>> {
+        let query = r#"
+            query($teamId: ID) {
+                workflowStates(first: 100, filter: { team: { id: { eq: $teamId } } }) {
+                    nodes {
+                        id
+                        name
+                        color
+                        type
+                        position
+                    }
+                }
+            }
+        "#;
+
+        let variables = json!({
+            "teamId": team_id
+        });
+
+        #[derive(serde::Deserialize)]
+        struct StatesResponse {
+            #[serde(rename = "workflowStates")]
+            workflow_states: StatesConnection,
+        }
+
+        #[derive(serde::Deserialize)]
+        struct StatesConnection {
+            nodes: Vec<IssueState>,
+        }
+
+        let response: StatesResponse = self.execute_query(query, Some(variables)).await?;
+        Ok(response.workflow_states.nodes)
+    }
+
+    /// Cycles of a team, in chronological order.
+    pub async fn get_cycles(&self, team_id: &str) -> Result<Vec<Cycle>> {
+        let query = r#"
+            query($teamId: String!) {
+                team(id: $teamId) {
+                    cycles(first: 100) {
+                        nodes {
+                            id
+                            number
+                            name
+                            startsAt
+                            endsAt
+                            completedAt
+                            progress
+                            isActive
+                            isFuture
+                            issueCountHistory
+                            completedIssueCountHistory
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let variables = json!({
+            "teamId": team_id
+        });
+
+        #[derive(serde::Deserialize)]
+        struct TeamResponse {
+            team: TeamCycles,
+        }
+
+        #[derive(serde::Deserialize)]
+        struct TeamCycles {
+            cycles: CyclesConnection,
+        }
+
+        #[derive(serde::Deserialize)]
+        struct CyclesConnection {
+            nodes: Vec<Cycle>,
+        }
+
+        let response: TeamResponse = self.execute_query(query, Some(variables)).await?;
+        let mut cycles = response.team.cycles.nodes;
+        cycles.sort_by_key(|c| c.starts_at);
+        Ok(cycles)
+    }
+
+    /// Issues planned in a cycle, fetched page by page.
+    pub async fn get_cycle_issues(&self, cycle_id: &str) -> Result<Vec<Issue>> {
+        let query = r#"
+            query($id: String!, $after: String) {
+                cycle(id: $id) {
+                    issues(first: 100, after: $after) {
+                        nodes {
+                            ...IssueFields
+                        }
+                        pageInfo {
+                            hasNextPage
+                            endCursor
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let variables = json!({
+            "id": cycle_id
+        });
+
+        let query = format!("{}{}", query, ISSUE_FRAGMENT);
+        self.fetch_all(&query, variables, &["cycle", "issues"])
+            .await
+    }
+
+    pub async fn create_issue(&self, input: &IssueCreateInput) -> Result<Issue> {
+        let query = r#"
+            mutation($input: IssueCreateInput!) {
+                issueCreate(input: $input) {
+                    success
+                    issue {
+                        ...IssueFields
+                    }
+                }
+            }
+        "#;
+
+        let variables = json!({
+            "input": input
+        });
+
+        #[derive(serde::Deserialize)]
+        struct CreateIssueResponse {
+            #[serde(rename = "issueCreate")]
+            issue_create: IssueCreatePayload,
+        }
+
+        #[derive(serde::Deserialize)]
+        struct IssueCreatePayload {
+            success: bool,
+            issue: Option<Issue>,
+        }
+
+        let query = format!("{}{}", query, ISSUE_FRAGMENT);
+        let response: CreateIssueResponse = self.execute_query(&query, Some(variables)).await?;
+
+        if !response.issue_create.success {
+            return Err(anyhow::anyhow!("Failed to create issue"));
+        }
+
+        response
+            .issue_create
+            .issue
+            .context("Issue creation succeeded but no issue data returned")
+    }
+
+    pub async fn update_issue(&self, id: &str, input: &IssueUpdateInput) -> Result<Issue> {
+        let query = r#"
+            mutation($id: String!, $input: IssueUpdateInput!) {
+                issueUpdate(id: $id, input: $input) {
+                    success
+                    issue {
+                        ...IssueFields
+                    }
+                }
+            }
+        "#;
+
+        let variables = json!({
+            "id": id,
+            "input": input
+        });
+
+        #[derive(serde::Deserialize)]
+        struct UpdateIssueResponse {
+            #[serde(rename = "issueUpdate")]
+            issue_update: IssueUpdatePayload,
+        }
+
+        #[derive(serde::Deserialize)]
+        struct IssueUpdatePayload {
+            success: bool,
+            issue: Option<Issue>,
+        }
+
+        let query = format!("{}{}", query, ISSUE_FRAGMENT);
+        let response: UpdateIssueResponse = self.execute_query(&query, Some(variables)).await?;
+
+        if !response.issue_update.success {
+            return Err(anyhow::anyhow!("Failed to update issue"));
+        }
+
+        response
+            .issue_update
+            .issue
+            .context("Issue update succeeded but no issue data returned")
+    }
+
+    /// Relate two issues, `relation_type` is one of `blocks`, `related` or `duplicate`.
+    pub async fn create_issue_relation(
+        &self,
+        issue_id: &str,
+        related_issue_id: &str,
+        relation_type: &str,
+    ) -> Result<()> {
+        let query = r#"
+            mutation($input: IssueRelationCreateInput!) {
+                issueRelationCreate(input: $input) {
+                    success
+                }
+            }
+        "#;
+
+        let variables = json!({
+            "input": {
+                "issueId": issue_id,
+                "relatedIssueId": related_issue_id,
+                "type": relation_type
+            }
+        });
+
+        #[derive(serde::Deserialize)]
+        struct CreateRelationResponse {
+            #[serde(rename = "issueRelationCreate")]
+            issue_relation_create: RelationPayload,
+        }
+
+        #[derive(serde::Deserialize)]
+        struct RelationPayload {
+            success: bool,
+        }
+
+        let response: CreateRelationResponse = self.execute_query(query, Some(variables)).await?;
+
+        if !response.issue_relation_create.success {
+            return Err(anyhow::anyhow!("Failed to create issue relation"));
+        }
+        Ok(())
+    }
+
+    pub async fn delete_issue_relation(&self, id: &str) -> Result<()> {
+        let query = r#"
+            mutation($id: String!) {
+                issueRelationDelete(id: $id) {
+                    success
+                }
+            }
+        "#;
+
+        let variables = json!({ "id": id });
+
+        #[derive(serde::Deserialize)]
+        struct DeleteRelationResponse {
+            #[serde(rename = "issueRelationDelete")]
+            issue_relation_delete: RelationPayload,
+        }
+
+        #[derive(serde::Deserialize)]
+        struct RelationPayload {
+            success: bool,
+        }
+
+        let response: DeleteRelationResponse = self.execute_query(query, Some(variables)).await?;
+
+        if !response.issue_relation_delete.success {
+            return Err(anyhow::anyhow!("Failed to delete issue relation"));
+        }
+        Ok(())
+    }
+
+    /// Attach a link to an issue. Linear recognizes pull requests and other integrations from the
+    /// URL, and fetches the title when it is not given.
+    pub async fn attach_url(&self, issue_id: &str, url: &str, title: Option<&str>) -> Result<()> {
+        let query = r#"
+            mutation($issueId: String!, $url: String!, $title: String) {
+                attachmentLinkURL(issueId: $issueId, url: $url, title: $title) {
+                    success
+                }
+            }
+        "#;
+
+        let variables = json!({
+            "issueId": issue_id,
+            "url": url,
+            "title": title
+        });
+
+        #[derive(serde::Deserialize)]
+        struct AttachUrlResponse {
+            #[serde(rename = "attachmentLinkURL")]
+            attachment_link_url: AttachmentPayload,
+        }
+
+        #[derive(serde::Deserialize)]
+        struct AttachmentPayload {
+            success: bool,
+        }
+
+        let response: AttachUrlResponse = self.execute_query(query, Some(variables)).await?;
+
+        if !response.attachment_link_url.success {
+            return Err(anyhow::anyhow!("Failed to attach URL"));
+        }
+        Ok(())
+    }
+
+    /// Upload a local file to Linear's storage and attach it to an issue.
+    pub async fn attach_file(&self, issue_id: &str, path: &Path) -> Result<()> {
+        let content = tokio::fs::read(path)
+            .await
+            .with_context(|| format!("Could not read {}", path.display()))?;
+        let filename = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .context("The path has no file name")?;
+        let content_type = content_type(path);
+
+        let query = r#"
+            mutation($contentType: String!, $filename: String!, $size: Int!) {
+                fileUpload(contentType: $contentType, filename: $filename, size: $size) {
+                    success
+                    uploadFile {
+                        uploadUrl
+                        assetUrl
+                        headers {
+                            key
+                            value
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let variables = json!({
+            "contentType": content_type,
+            "filename": filename,
+            "size": content.len()
+        });
+
+        #[derive(serde::Deserialize)]
+        struct FileUploadResponse {
+            #[serde(rename = "fileUpload")]
+            file_upload: FileUploadPayload,
+        }
+
+        #[derive(serde::Deserialize)]
+        struct FileUploadPayload {
+            success: bool,
+            #[serde(rename = "uploadFile")]
+            upload_file: Option<UploadFile>,
+        }
+
+        #[derive(serde::Deserialize)]
+        struct UploadFile {
+            #[serde(rename = "uploadUrl")]
+            upload_url: String,
+            #[serde(rename = "assetUrl")]
+            asset_url: String,
+            headers: Vec<UploadHeader>,
+        }
+
+        #[derive(serde::Deserialize)]
+        struct UploadHeader {
+            key: String,
+            value: String,
+        }
+
+        let response: FileUploadResponse = self.execute_query(query, Some(variables)).await?;
+        let upload = match response.file_upload {
+            FileUploadPayload {
+                success: true,
+                upload_file: Some(upload),
+            } => upload,
+            _ => return Err(anyhow::anyhow!("Failed to prepare the file upload")),
+        };
+
+        let size = content.len();
+        let mut request = self
+            .client
+            .put(&upload.upload_url)
+            .header("Content-Type", content_type)
+            .header("Cache-Control", "public, max-age=31536000");
+        for header in &upload.headers {
+            request = request.header(&header.key, &header.value);
+        }
+        let status = request
+            .body(content)
+            .send()
+            .await
+            .context("Failed to upload the file")?
+            .status();
+        if !status.is_success() {
+            return Err(anyhow::anyhow!("Upload failed: {}", status));
+        }
+
+        self.create_attachment(issue_id, &filename, &upload.asset_url, &format_size(size))
+            .await
+    }
+
+    /// Attach a URL to an issue as is, without Linear fetching it.
+    pub async fn create_attachment(
+        &self,
+        issue_id: &str,
+        title: &str,
+        url: &str,
+        subtitle: &str,
+    ) -> Result<()> {
+        let query = r#"
+            mutation($input: AttachmentCreateInput!) {
+                attachmentCreate(input: $input) {
+                    success
+                }
+            }
+        "#;
+
+        let variables = json!({
+            "input": {
+                "issueId": issue_id,
+                "title": title,
+                "url": url,
+                "subtitle": subtitle
+            }
+        });
+
+        #[derive(serde::Deserialize)]
+        struct CreateAttachmentResponse {
+            #[serde(rename = "attachmentCreate")]
+            attachment_create: AttachmentPayload,
+        }
+
+        #[derive(serde::Deserialize)]
+        struct AttachmentPayload {
+            success: bool,
+        }
+
+        let response: CreateAttachmentResponse = self.execute_query(query, Some(variables)).await?;
+
+        if !response.attachment_create.success {
+            return Err(anyhow::anyhow!("Failed to create attachment"));
+        }
+        Ok(())
+    }
+
+    /// The viewer's notifications, newest first. Archived notifications are not returned.
+    pub async fn get_notifications(&self, first: Option<i32>) -> Result<Vec<Notification>> {
+        let query = r#"
+            query($first: Int) {
+                notifications(first: $first) {
+                    nodes {
+                        id
+                        type
+                        createdAt
+                        readAt
+                        snoozedUntilAt
+                        actor {
+                            id
+                            name
+                            email
+                            displayName
+                            avatarUrl
+                        }
+                        ... on IssueNotification {
+                            issue {
+                                ...IssueFields
+                            }
+                            comment {
+                                body
+                            }
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let variables = json!({
+            "first": first.unwrap_or(50)
+        });
+
+        #[derive(serde::Deserialize)]
+        struct NotificationsResponse {
+            notifications: NotificationsConnection,
+        }
+
+        #[derive(serde::Deserialize)]
+        struct NotificationsConnection {
+            nodes: Vec<Notification>,
+        }
+
+        let query = format!("{}{}", query, ISSUE_FRAGMENT);
+        let response: NotificationsResponse = self.execute_query(&query, Some(variables)).await?;
+        let mut notifications = response.notifications.nodes;
+        notifications.sort_by_key(|n| std::cmp::Reverse(n.created_at));
+        Ok(notifications)
+    }
+
+    /// Mark a notification as read (now) or unread.
+    pub async fn mark_notification_read(&self, id: &str, read: bool) -> Result<()> {
+        let read_at = if read { Some(Utc::now()) } else { None };
+        self.update_notification(id, json!({ "readAt": read_at }))
+            .await
+    }
+
+    /// Hide a notification from the inbox until the given time.
+    pub async fn snooze_notification(&self, id: &str, until: DateTime<Utc>) -> Result<()> {
+        self.update_notification(id, json!({ "snoozedUntilAt": until }))
+            .await
+    }
+
+    async fn update_notification(&self, id: &str, input: serde_json::Value) -> Result<()> {
+        let query = r#"
+            mutation($id: String!, $input: NotificationUpdateInput!) {
+                notificationUpdate(id: $id, input: $input) {
+                    success
+                }
+            }
+        "#;
+
+        let variables = json!({
+            "id": id,
+            "input": input
+        });
+
+        #[derive(serde::Deserialize)]
+        struct UpdateNotificationResponse {
+            #[serde(rename = "notificationUpdate")]
+            notification_update: NotificationPayload,
+        }
+
+        #[derive(serde::Deserialize)]
+        struct NotificationPayload {
+            success: bool,
+        }
+
+        let response: UpdateNotificationResponse =
+            self.execute_query(query, Some(variables)).await?;
+
+        if !response.notification_update.success {
+            return Err(anyhow::anyhow!("Failed to update notification"));
+        }
+        Ok(())
+    }
+
+    pub async fn archive_notification(&self, id: &str) -> Result<()> {
+        let query = r#"
+            mutation($id: String!) {
+                notificationArchive(id: $id) {
+                    success
+                }
+            }
+        "#;
+
+        let variables = json!({ "id": id });
+
+        #[derive(serde::Deserialize)]
+        struct ArchiveNotificationResponse {
+            #[serde(rename = "notificationArchive")]
+            notification_archive: NotificationPayload,
+        }
+
+        #[derive(serde::Deserialize)]
+        struct NotificationPayload {
+            success: bool,
+        }
+
+        let response: ArchiveNotificationResponse =
+            self.execute_query(query, Some(variables)).await?;
+
+        if !response.notification_archive.success {
+            return Err(anyhow::anyhow!("Failed to archive notification"));
+        }
+        Ok(())
+    }
+}
+
+/// MIME type of a file to upload, from its extension.
+fn content_type(path: &Path) -> &'static str {
+    let extension = path
+        .extension()
+        .map(|e| e.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    match extension.as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "svg" => "image/svg+xml",
+        "pdf" => "application/pdf",
+        "json" => "application/json",
+        "zip" => "application/zip",
+        "csv" => "text/csv",
+        "md" => "text/markdown",
+        "txt" | "log" => "text/plain",
+        "mp4" => "video/mp4",
+        "mov" => "video/quicktime",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Size of an uploaded file, shown as the subtitle of its attachment.
+fn format_size(bytes: usize) -> String {
+    match bytes {
+        b if b >= 1024 * 1024 => format!("{:.1} MB", b as f64 / (1024.0 * 1024.0)),
+        b if b >= 1024 => format!("{:.1} KB", b as f64 / 1024.0),
+        b => format!("{} B", b),
     }
 }
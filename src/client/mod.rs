@@ -6,5 +6,10 @@ pub mod types;
 
 pub use linear::LinearClient;
 pub use types::{
-    Issue, IssueState, IssuesConnection, PageInfo, Project, ProjectStatus, Team, User,
+    completion, Attachment, Comment, CustomView, Cycle, InverseIssueRelation, Issue, IssueActivity,
+    IssueChild, IssueCreateInput, IssueCycle, IssueHistory, IssueLabel, IssueLink, IssueMilestone,
+    IssueParent, IssueProject, IssueRelation, IssueState, IssueUpdateInput, IssuesConnection,
+    Notification, NotificationComment, PageInfo, Project, ProjectDetails, ProjectMilestone,
+    ProjectStatus, ProjectUpdate, RelatedIssue, RelationKind, Team, TeamDetails, TeamStats,
+    TeamWorkload, User,
 };
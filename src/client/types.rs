@@ -1,8 +1,24 @@
 // SPDX-FileCopyrightText: Copyright (C) Nicolas Lamirault <nicolas.lamirault@gmail.com>
 // SPDX-License-Identifier: Apache-2.0
 
-use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// Deserialize a GraphQL connection (`{ nodes: [...] }`) into its nodes.
+fn nodes<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    #[derive(Deserialize)]
+    struct Connection<T> {
+        nodes: Vec<T>,
+    }
+
+    Ok(Option::<Connection<T>>::deserialize(deserializer)?
+        .map(|c| c.nodes)
+        .unwrap_or_default())
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct User {
@@ -23,10 +39,63 @@ pub struct Team {
     pub description: Option<String>,
 }
 
+/// Member count and open issues of a team, shown in the teams list.
+#[derive(Debug, Clone, Default)]
+pub struct TeamStats {
+    pub members: usize,
+    /// Open issues by state type: triage, backlog, unstarted and started.
+    pub open: std::collections::BTreeMap<String, usize>,
+}
+
+impl TeamStats {
+    pub fn open(&self, state_type: &str) -> usize {
+        self.open.get(state_type).copied().unwrap_or(0)
+    }
+
+    pub fn open_total(&self) -> usize {
+        self.open.values().sum()
+    }
+}
+
+/// Members of a team and its open issues, for the workload view.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TeamWorkload {
+    #[serde(default, deserialize_with = "nodes")]
+    pub members: Vec<User>,
+    #[serde(default, deserialize_with = "nodes")]
+    pub issues: Vec<Issue>,
+}
+
+/// A team with everything shown on its detail screen.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TeamDetails {
+    #[serde(flatten)]
+    pub team: Team,
+    #[serde(default, deserialize_with = "nodes")]
+    pub members: Vec<User>,
+    #[serde(default, deserialize_with = "nodes")]
+    pub states: Vec<IssueState>,
+    #[serde(default, deserialize_with = "nodes")]
+    pub labels: Vec<IssueLabel>,
+    #[serde(rename = "activeCycle")]
+    pub active_cycle: Option<Cycle>,
+    #[serde(rename = "cyclesEnabled")]
+    pub cycles_enabled: bool,
+    /// Length of the cycles, in weeks.
+    #[serde(rename = "cycleDuration")]
+    pub cycle_duration: f64,
+    #[serde(rename = "triageEnabled")]
+    pub triage_enabled: bool,
+    #[serde(rename = "requirePriorityToLeaveTriage")]
+    pub require_priority_to_leave_triage: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Issue {
     pub id: String,
     pub title: String,
+    /// Only loaded with the details of the issue, by `LinearClient::get_issue`, like the
+    /// sub-issues, relations and attachments.
     pub description: Option<String>,
     pub identifier: String,
     pub priority: Option<i32>,
@@ -38,7 +107,321 @@ pub struct Issue {
     pub created_at: DateTime<Utc>,
     #[serde(rename = "updatedAt")]
     pub updated_at: DateTime<Utc>,
+    #[serde(rename = "completedAt")]
+    pub completed_at: Option<DateTime<Utc>>,
     pub url: String,
+    pub estimate: Option<f64>,
+    #[serde(rename = "dueDate")]
+    pub due_date: Option<NaiveDate>,
+    #[serde(default, deserialize_with = "nodes")]
+    pub labels: Vec<IssueLabel>,
+    pub project: Option<IssueProject>,
+    #[serde(rename = "projectMilestone")]
+    pub milestone: Option<IssueMilestone>,
+    pub cycle: Option<IssueCycle>,
+    pub parent: Option<IssueParent>,
+    /// Sub-issues, an issue with sub-issues is shown as a tree.
+    #[serde(default, deserialize_with = "nodes")]
+    pub children: Vec<IssueChild>,
+    /// Relations created from this issue.
+    #[serde(default, deserialize_with = "nodes")]
+    pub relations: Vec<IssueRelation>,
+    /// Relations created from other issues to this one.
+    #[serde(rename = "inverseRelations", default, deserialize_with = "nodes")]
+    pub inverse_relations: Vec<InverseIssueRelation>,
+    #[serde(default, deserialize_with = "nodes")]
+    pub attachments: Vec<Attachment>,
+}
+
+impl Issue {
+    /// Completed and total sub-issues, canceled sub-issues are not counted.
+    /// None when the issue has no sub-issues, or they are not loaded.
+    pub fn sub_issue_completion(&self) -> Option<(usize, usize)> {
+        completion(self.children.iter().map(|c| &c.state))
+    }
+
+    /// Relations in both directions, seen from this issue.
+    pub fn links(&self) -> Vec<IssueLink<'_>> {
+        let forward = self.relations.iter().map(|r| IssueLink {
+            id: &r.id,
+            kind: RelationKind::new(&r.relation_type, false),
+            issue: &r.related_issue,
+        });
+        let inverse = self.inverse_relations.iter().map(|r| IssueLink {
+            id: &r.id,
+            kind: RelationKind::new(&r.relation_type, true),
+            issue: &r.issue,
+        });
+        let mut links: Vec<IssueLink> = forward.chain(inverse).collect();
+        links.sort_by_key(|l| l.kind);
+        links
+    }
+
+    /// Issues blocking this one that are neither completed nor canceled.
+    pub fn open_blockers(&self) -> Vec<&RelatedIssue> {
+        self.links()
+            .into_iter()
+            .filter(|l| l.kind == RelationKind::BlockedBy && !l.issue.is_resolved())
+            .map(|l| l.issue)
+            .collect()
+    }
+}
+
+/// Completed and total issues among issues in these states, canceled issues are not counted.
+/// None when there are no issues.
+pub fn completion<'a>(states: impl Iterator<Item = &'a IssueState>) -> Option<(usize, usize)> {
+    let mut states = states.peekable();
+    states.peek()?;
+    let states: Vec<&IssueState> = states.filter(|s| s.state_type != "canceled").collect();
+    let done = states
+        .iter()
+        .filter(|s| s.state_type == "completed")
+        .count();
+    Some((done, states.len()))
+}
+
+/// How an issue relates to another one, from the point of view of the first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RelationKind {
+    BlockedBy,
+    Blocks,
+    Related,
+    DuplicateOf,
+    DuplicatedBy,
+}
+
+impl RelationKind {
+    /// Kind of a relation of `relation_type`, `inverse` when the relation was created from the
+    /// other issue.
+    fn new(relation_type: &str, inverse: bool) -> Self {
+        match (relation_type, inverse) {
+            ("blocks", false) => RelationKind::Blocks,
+            ("blocks", true) => RelationKind::BlockedBy,
+            ("duplicate", false) => RelationKind::DuplicateOf,
+            ("duplicate", true) => RelationKind::DuplicatedBy,
+            _ => RelationKind::Related,
+        }
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            RelationKind::BlockedBy => "Blocked by",
+            RelationKind::Blocks => "Blocks",
+            RelationKind::Related => "Related to",
+            RelationKind::DuplicateOf => "Duplicate of",
+            RelationKind::DuplicatedBy => "Duplicated by",
+        }
+    }
+
+    /// Parse the kind typed in the relation prompt, e.g. `blocked-by`.
+    pub fn parse(text: &str) -> Option<Self> {
+        match text.to_lowercase().replace(['-', '_', ' '], "").as_str() {
+            "blocks" => Some(RelationKind::Blocks),
+            "blockedby" => Some(RelationKind::BlockedBy),
+            "related" | "relatedto" => Some(RelationKind::Related),
+            "duplicate" | "duplicateof" => Some(RelationKind::DuplicateOf),
+            "duplicatedby" => Some(RelationKind::DuplicatedBy),
+            _ => None,
+        }
+    }
+
+    /// Relation type for the `issueRelationCreate` mutation, and whether the relation is created
+    /// from the other issue.
+    pub fn relation_type(&self) -> (&'static str, bool) {
+        match self {
+            RelationKind::Blocks => ("blocks", false),
+            RelationKind::BlockedBy => ("blocks", true),
+            RelationKind::Related => ("related", false),
+            RelationKind::DuplicateOf => ("duplicate", false),
+            RelationKind::DuplicatedBy => ("duplicate", true),
+        }
+    }
+}
+
+/// A relation seen from one of its issues.
+#[derive(Debug, Clone, Copy)]
+pub struct IssueLink<'a> {
+    /// Id of the relation, to remove it.
+    pub id: &'a str,
+    pub kind: RelationKind,
+    pub issue: &'a RelatedIssue,
+}
+
+/// A relation created from an issue, to `related_issue`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueRelation {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub relation_type: String,
+    #[serde(rename = "relatedIssue")]
+    pub related_issue: RelatedIssue,
+}
+
+/// A relation created from `issue` to another issue.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InverseIssueRelation {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub relation_type: String,
+    pub issue: RelatedIssue,
+}
+
+/// The other issue of a relation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelatedIssue {
+    pub id: String,
+    pub identifier: String,
+    pub title: String,
+    pub state: IssueState,
+}
+
+impl RelatedIssue {
+    pub fn is_resolved(&self) -> bool {
+        matches!(self.state.state_type.as_str(), "completed" | "canceled")
+    }
+}
+
+/// A link or a file attached to an issue, like a pull request or an uploaded screenshot.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Attachment {
+    pub id: String,
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub url: String,
+    /// Integration that created the attachment, e.g. `github` or `sentry`.
+    #[serde(rename = "sourceType")]
+    pub source_type: Option<String>,
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+}
+
+impl Attachment {
+    /// Name of the integration the attachment comes from.
+    pub fn source(&self) -> String {
+        match self.source_type.as_deref() {
+            Some("github") | Some("githubPr") => "GitHub".to_string(),
+            Some("gitlab") => "GitLab".to_string(),
+            Some("sentry") => "Sentry".to_string(),
+            Some("slack") => "Slack".to_string(),
+            Some("figma") => "Figma".to_string(),
+            Some("zendesk") => "Zendesk".to_string(),
+            Some("upload") => "File".to_string(),
+            Some(source) if !source.is_empty() => {
+                let mut chars = source.chars();
+                chars
+                    .next()
+                    .map(|c| c.to_uppercase().chain(chars).collect())
+                    .unwrap_or_default()
+            }
+            _ => "Link".to_string(),
+        }
+    }
+}
+
+/// The parent of a sub-issue.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueParent {
+    pub id: String,
+    pub identifier: String,
+    pub title: String,
+}
+
+/// A sub-issue, with its state to count the completed ones.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueChild {
+    pub id: String,
+    pub identifier: String,
+    pub title: String,
+    pub state: IssueState,
+}
+
+/// The project an issue belongs to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueProject {
+    pub id: String,
+    pub name: String,
+}
+
+/// The milestone of its project an issue belongs to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueMilestone {
+    pub id: String,
+    pub name: String,
+}
+
+/// The cycle an issue is planned in.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueCycle {
+    pub id: String,
+    pub number: f64,
+    pub name: Option<String>,
+}
+
+impl IssueCycle {
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) if !name.is_empty() => name.clone(),
+            _ => format!("Cycle {}", self.number),
+        }
+    }
+}
+
+/// A cycle of a team, with its scope and completion tracked daily by Linear.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Cycle {
+    pub id: String,
+    pub number: f64,
+    pub name: Option<String>,
+    #[serde(rename = "startsAt")]
+    pub starts_at: DateTime<Utc>,
+    #[serde(rename = "endsAt")]
+    pub ends_at: DateTime<Utc>,
+    #[serde(rename = "completedAt")]
+    pub completed_at: Option<DateTime<Utc>>,
+    /// Completed share of the scope, between 0 and 1.
+    pub progress: f64,
+    #[serde(rename = "isActive")]
+    pub is_active: bool,
+    #[serde(rename = "isFuture")]
+    pub is_future: bool,
+    /// Number of issues in the cycle, one entry per day since it started.
+    #[serde(rename = "issueCountHistory", default)]
+    pub issue_count_history: Vec<f64>,
+    /// Number of completed issues, one entry per day since the cycle started.
+    #[serde(rename = "completedIssueCountHistory", default)]
+    pub completed_issue_count_history: Vec<f64>,
+}
+
+impl Cycle {
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) if !name.is_empty() => name.clone(),
+            _ => format!("Cycle {}", self.number),
+        }
+    }
+
+    /// "Past", "Current" or "Upcoming".
+    pub fn timing(&self) -> &'static str {
+        if self.is_active {
+            "Current"
+        } else if self.is_future {
+            "Upcoming"
+        } else {
+            "Past"
+        }
+    }
+
+    /// Number of issues in the cycle, as of the last day tracked.
+    pub fn scope(&self) -> usize {
+        self.issue_count_history.last().copied().unwrap_or(0.0) as usize
+    }
+
+    pub fn completed(&self) -> usize {
+        self.completed_issue_count_history
+            .last()
+            .copied()
+            .unwrap_or(0.0) as usize
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -48,6 +431,119 @@ pub struct IssueState {
     pub color: String,
     #[serde(rename = "type")]
     pub state_type: String,
+    /// Order of the state within its team, only fetched with the team's workflow states.
+    pub position: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Comment {
+    pub id: String,
+    pub body: String,
+    pub user: Option<User>,
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+}
+
+/// A change made to an issue, as recorded in its history. Only the changed fields are set.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueHistory {
+    pub id: String,
+    pub actor: Option<User>,
+    #[serde(rename = "fromState")]
+    pub from_state: Option<IssueState>,
+    #[serde(rename = "toState")]
+    pub to_state: Option<IssueState>,
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+    #[serde(rename = "fromPriority")]
+    pub from_priority: Option<f64>,
+    #[serde(rename = "toPriority")]
+    pub to_priority: Option<f64>,
+    #[serde(rename = "fromAssignee")]
+    pub from_assignee: Option<User>,
+    #[serde(rename = "toAssignee")]
+    pub to_assignee: Option<User>,
+    #[serde(rename = "fromTitle")]
+    pub from_title: Option<String>,
+    #[serde(rename = "toTitle")]
+    pub to_title: Option<String>,
+    #[serde(rename = "fromEstimate")]
+    pub from_estimate: Option<f64>,
+    #[serde(rename = "toEstimate")]
+    pub to_estimate: Option<f64>,
+    #[serde(rename = "fromDueDate")]
+    pub from_due_date: Option<NaiveDate>,
+    #[serde(rename = "toDueDate")]
+    pub to_due_date: Option<NaiveDate>,
+    #[serde(rename = "fromProject")]
+    pub from_project: Option<IssueProject>,
+    #[serde(rename = "toProject")]
+    pub to_project: Option<IssueProject>,
+    #[serde(rename = "fromCycle")]
+    pub from_cycle: Option<IssueCycle>,
+    #[serde(rename = "toCycle")]
+    pub to_cycle: Option<IssueCycle>,
+    #[serde(rename = "addedLabels", default)]
+    pub added_labels: Option<Vec<IssueLabel>>,
+    #[serde(rename = "removedLabels", default)]
+    pub removed_labels: Option<Vec<IssueLabel>>,
+    #[serde(rename = "updatedDescription", default)]
+    pub updated_description: Option<bool>,
+}
+
+/// An issue with its recent history and comments.
+#[derive(Debug, Clone)]
+pub struct IssueActivity {
+    pub issue: Issue,
+    pub history: Vec<IssueHistory>,
+    pub comments: Vec<Comment>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueLabel {
+    pub id: String,
+    pub name: String,
+    pub color: String,
+}
+
+/// Fields accepted by the `issueCreate` mutation. Unset fields are left to Linear's defaults.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct IssueCreateInput {
+    #[serde(rename = "teamId")]
+    pub team_id: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i32>,
+    #[serde(rename = "assigneeId", skip_serializing_if = "Option::is_none")]
+    pub assignee_id: Option<String>,
+    #[serde(rename = "labelIds", skip_serializing_if = "Vec::is_empty")]
+    pub label_ids: Vec<String>,
+    #[serde(rename = "stateId", skip_serializing_if = "Option::is_none")]
+    pub state_id: Option<String>,
+    /// Makes the new issue a sub-issue of this one.
+    #[serde(rename = "parentId", skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+}
+
+/// Fields accepted by the `issueUpdate` mutation. Only the fields that are set are changed.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct IssueUpdateInput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i32>,
+    #[serde(rename = "assigneeId", skip_serializing_if = "Option::is_none")]
+    pub assignee_id: Option<String>,
+    #[serde(rename = "stateId", skip_serializing_if = "Option::is_none")]
+    pub state_id: Option<String>,
+    #[serde(rename = "addedLabelIds", skip_serializing_if = "Option::is_none")]
+    pub added_label_ids: Option<Vec<String>>,
+    #[serde(rename = "removedLabelIds", skip_serializing_if = "Option::is_none")]
+    pub removed_label_ids: Option<Vec<String>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -67,6 +563,69 @@ pub struct ProjectStatus {
     pub status_type: String,
 }
 
+/// A project with everything shown on its detail screen.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectDetails {
+    #[serde(flatten)]
+    pub project: Project,
+    #[serde(rename = "startDate")]
+    pub start_date: Option<NaiveDate>,
+    #[serde(rename = "targetDate")]
+    pub target_date: Option<NaiveDate>,
+    /// Completed share of the project, between 0 and 1.
+    pub progress: f64,
+    #[serde(default, deserialize_with = "nodes")]
+    pub members: Vec<User>,
+    #[serde(rename = "projectMilestones", default, deserialize_with = "nodes")]
+    pub milestones: Vec<ProjectMilestone>,
+    #[serde(rename = "projectUpdates", default, deserialize_with = "nodes")]
+    pub updates: Vec<ProjectUpdate>,
+    #[serde(default, deserialize_with = "nodes")]
+    pub issues: Vec<Issue>,
+}
+
+impl ProjectDetails {
+    pub fn latest_update(&self) -> Option<&ProjectUpdate> {
+        self.updates.iter().max_by_key(|u| u.created_at)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectMilestone {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "targetDate")]
+    pub target_date: Option<NaiveDate>,
+    #[serde(rename = "sortOrder")]
+    pub sort_order: f64,
+}
+
+impl ProjectMilestone {
+    /// Completed and total issues of the milestone among the project's issues, canceled issues
+    /// are not counted.
+    pub fn completion(&self, issues: &[Issue]) -> (usize, usize) {
+        completion(
+            issues
+                .iter()
+                .filter(|i| i.milestone.as_ref().is_some_and(|m| m.id == self.id))
+                .map(|i| &i.state),
+        )
+        .unwrap_or_default()
+    }
+}
+
+/// A status update posted on a project.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectUpdate {
+    pub id: String,
+    pub body: String,
+    /// "onTrack", "atRisk" or "offTrack".
+    pub health: Option<String>,
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+    pub user: Option<User>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct GraphQLResponse<T> {
     pub data: Option<T>,
@@ -78,6 +637,25 @@ pub struct GraphQLError {
     pub message: String,
     pub locations: Option<Vec<Location>>,
     pub path: Option<Vec<String>>,
+    /// Machine readable details, Linear classifies the error with `code` and `type`.
+    pub extensions: Option<serde_json::Value>,
+}
+
+impl GraphQLError {
+    /// The `code` or `type` of the error, lowercase without separators (e.g. "entitynotfound").
+    pub fn kind(&self) -> Option<String> {
+        let extensions = self.extensions.as_ref()?;
+        ["code", "type"]
+            .iter()
+            .filter_map(|key| extensions.get(key)?.as_str())
+            .map(|kind| {
+                kind.chars()
+                    .filter(char::is_ascii_alphanumeric)
+                    .collect::<String>()
+                    .to_lowercase()
+            })
+            .next()
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -110,3 +688,78 @@ pub struct PageInfo {
     #[serde(rename = "endCursor")]
     pub end_cursor: Option<String>,
 }
+
+/// A custom view saved in the Linear workspace.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomView {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+}
+
+/// An entry of the viewer's notifications inbox.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Notification {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub notification_type: String,
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+    #[serde(rename = "readAt")]
+    pub read_at: Option<DateTime<Utc>>,
+    #[serde(rename = "snoozedUntilAt")]
+    pub snoozed_until_at: Option<DateTime<Utc>>,
+    pub actor: Option<User>,
+    /// Only set for issue notifications.
+    pub issue: Option<Issue>,
+    pub comment: Option<NotificationComment>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationComment {
+    pub body: String,
+}
+
+impl Notification {
+    pub fn is_unread(&self) -> bool {
+        self.read_at.is_none()
+    }
+
+    pub fn is_snoozed(&self, now: DateTime<Utc>) -> bool {
+        self.snoozed_until_at.is_some_and(|until| until > now)
+    }
+
+    /// Human readable description of the notification type, e.g. "Mentioned you".
+    pub fn describe(&self) -> String {
+        match self.notification_type.as_str() {
+            "issueMention" | "issueCommentMention" | "issueNewCommentMention" => {
+                "Mentioned you".to_string()
+            }
+            "issueAssignedToYou" => "Assigned to you".to_string(),
+            "issueUnassignedFromYou" => "Unassigned from you".to_string(),
+            "issueStatusChanged" | "issueStatusChangedAll" => "Changed status".to_string(),
+            "issueNewComment" | "issueCommentReaction" => "Commented".to_string(),
+            "issueCreated" => "Created".to_string(),
+            "issuePriorityUrgent" => "Marked urgent".to_string(),
+            "issueDue" => "Due soon".to_string(),
+            "issueBlocking" => "Blocking".to_string(),
+            "issueUnblocked" => "Unblocked".to_string(),
+            "issueSubscribed" => "Subscribed you".to_string(),
+            other => {
+                // Fall back to splitting the camelCase type: "issueReminder" -> "Issue reminder"
+                let mut text = String::new();
+                for (i, c) in other.chars().enumerate() {
+                    if i == 0 {
+                        text.extend(c.to_uppercase());
+                    } else if c.is_uppercase() {
+                        text.push(' ');
+                        text.extend(c.to_lowercase());
+                    } else {
+                        text.push(c);
+                    }
+                }
+                text
+            }
+        }
+    }
+}
@@ -1,19 +1,33 @@
 // SPDX-FileCopyrightText: Copyright (C) Nicolas Lamirault <nicolas.lamirault@gmail.com>
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod validate;
+
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::utils::{GroupBy, IssueColumn, ProjectColumn, SortOrder};
+
+pub use validate::{validate, Diagnostic, Severity};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Config {
     pub api_key: Option<String>,
     pub refresh_interval: u64,
     pub default_team_id: Option<String>,
     pub theme: ThemeConfig,
+    pub export: ExportConfig,
+    pub layout: LayoutConfig,
+    pub issues: IssuesConfig,
+    /// Saved queries shown as tabs after the built-in views.
+    pub views: Vec<SavedView>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ThemeConfig {
     pub primary_color: String,
     pub secondary_color: String,
@@ -21,6 +35,60 @@ pub struct ThemeConfig {
     pub text_color: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExportConfig {
+    pub issue_columns: Vec<IssueColumn>,
+    pub project_columns: Vec<ProjectColumn>,
+}
+
+/// How the issue list and the details of the selected issue share the screen.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LayoutConfig {
+    /// Show the details pane next to the list on startup.
+    pub show_details: bool,
+    pub split: SplitDirection,
+    /// Share of the screen used by the list, in percent (20-80).
+    pub list_ratio: u16,
+}
+
+/// Columns and sorting of the issues table.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IssuesConfig {
+    pub columns: Vec<IssueColumn>,
+    /// Column widths in characters, the title takes the remaining space.
+    pub widths: BTreeMap<IssueColumn, u16>,
+    /// Sort column on startup, the API order (most recently updated first) when unset.
+    pub sort: Option<IssueColumn>,
+    pub order: SortOrder,
+    pub group_by: GroupBy,
+}
+
+/// A named query, with the sort and grouping of its issues.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedView {
+    pub name: String,
+    #[serde(default)]
+    pub query: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort: Option<IssueColumn>,
+    #[serde(default)]
+    pub order: SortOrder,
+    #[serde(default)]
+    pub group_by: GroupBy,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SplitDirection {
+    /// List on the left, details on the right.
+    Horizontal,
+    /// List on top, details below.
+    Vertical,
+}
+
 impl Default for Config {
     fn default() -> Self {
         Self {
@@ -28,6 +96,10 @@ impl Default for Config {
             refresh_interval: 30,
             default_team_id: None,
             theme: ThemeConfig::default(),
+            export: ExportConfig::default(),
+            layout: LayoutConfig::default(),
+            issues: IssuesConfig::default(),
+            views: Vec::new(),
         }
     }
 }
@@ -43,42 +115,284 @@ impl Default for ThemeConfig {
     }
 }
 
+impl Default for ExportConfig {
+    fn default() -> Self {
+        Self {
+            issue_columns: IssueColumn::defaults(),
+            project_columns: ProjectColumn::defaults(),
+        }
+    }
+}
+
+impl Default for LayoutConfig {
+    fn default() -> Self {
+        Self {
+            show_details: false,
+            split: SplitDirection::Horizontal,
+            list_ratio: 60,
+        }
+    }
+}
+
+impl Default for IssuesConfig {
+    fn default() -> Self {
+        Self {
+            columns: IssueColumn::defaults(),
+            widths: BTreeMap::new(),
+            sort: None,
+            order: SortOrder::Ascending,
+            group_by: GroupBy::None,
+        }
+    }
+}
+
+impl IssuesConfig {
+    pub fn width(&self, column: IssueColumn) -> u16 {
+        self.widths
+            .get(&column)
+            .copied()
+            .unwrap_or_else(|| column.default_width())
+    }
+}
+
 impl Config {
-    pub fn load(config_path: Option<&str>) -> Result<Self> {
+    /// Path of the configuration file, either the given one or the default location.
+    pub fn path(config_path: Option<&str>) -> Result<PathBuf> {
+        if let Some(path) = config_path {
+            return Ok(Path::new(path).to_path_buf());
+        }
+
         let config_dir = dirs::config_dir()
             .context("Could not find config directory")?
             .join("ltui");
 
-        std::fs::create_dir_all(&config_dir).context("Could not create config directory")?;
+        Ok(config_dir.join("config.toml"))
+    }
 
-        let config_file = if let Some(path) = config_path {
-            Path::new(path).to_path_buf()
-        } else {
-            config_dir.join("config.toml")
-        };
+    /// Load the configuration file, falling back to the defaults if it does not exist.
+    /// Use `ltui config init` to create it.
+    pub fn load(config_path: Option<&str>) -> Result<Self> {
+        let config_file = Self::path(config_path)?;
 
-        if config_file.exists() {
+        let mut config = if config_file.exists() {
             let config_str =
                 std::fs::read_to_string(&config_file).context("Could not read config file")?;
 
-            let mut config: Config =
-                toml::from_str(&config_str).context("Could not parse config file")?;
+            Self::parse(&config_str)
+                .with_context(|| format!("Could not parse config file {}", config_file.display()))?
+        } else {
+            Config::default()
+        };
+
+        // Override with environment variable if present
+        if let Ok(token) = std::env::var("LINEAR_API_KEY") {
+            config.api_key = Some(token);
+        }
 
-            // Override with environment variable if present
-            if let Ok(token) = std::env::var("LINEAR_API_KEY") {
-                config.api_key = Some(token);
-            }
+        Ok(config)
+    }
+
+    /// Parse a configuration, reporting the first error with its line and column.
+    pub fn parse(content: &str) -> Result<Self> {
+        match validate(content)
+            .into_iter()
+            .find(|d| d.severity == Severity::Error)
+        {
+            Some(diagnostic) => Err(anyhow::anyhow!("{}", diagnostic)),
+            None => toml::from_str(content).context("Invalid configuration"),
+        }
+    }
 
-            Ok(config)
+    /// Set a single dotted key in the configuration file, leaving the other keys and the
+    /// comments untouched.
+    /// The new content is validated before it is written: errors, and warnings the change
+    /// introduces (an unknown key), refuse the write.
+    pub fn set_value(config_path: Option<&str>, key: &str, value: toml::Value) -> Result<()> {
+        let path = Self::path(config_path)?;
+        let content = if path.exists() {
+            std::fs::read_to_string(&path).context("Could not read config file")?
         } else {
-            let config = Config::default();
-            let config_str =
-                toml::to_string_pretty(&config).context("Could not serialize default config")?;
+            String::new()
+        };
+
+        let new_content = edit(&content, key, value).with_context(|| {
+            format!(
+                "Could not parse {}, run `ltui config validate` for details",
+                path.display()
+            )
+        })?;
+        // Warnings already in the file, like a stale key, are not this change's problem
+        let existing: Vec<String> = validate(&content).into_iter().map(|d| d.message).collect();
+        if let Some(problem) = validate(&new_content)
+            .into_iter()
+            .find(|d| d.severity == Severity::Error || !existing.contains(&d.message))
+        {
+            return Err(anyhow::anyhow!(
+                "Refusing to set '{}': {}",
+                key,
+                problem.message
+            ));
+        }
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).context("Could not create config directory")?;
+        }
+        std::fs::write(&path, new_content).context("Could not write config file")
+    }
+
+    pub fn save(&self, config_path: Option<&str>) -> Result<()> {
+        let config_file = Self::path(config_path)?;
+
+        if let Some(config_dir) = config_file.parent() {
+            std::fs::create_dir_all(config_dir).context("Could not create config directory")?;
+        }
+
+        let config_str = toml::to_string_pretty(self).context("Could not serialize config")?;
+        std::fs::write(&config_file, config_str).context("Could not write config file")
+    }
+}
+
+/// Look up a dotted key in a TOML value.
+pub fn lookup<'a>(value: &'a toml::Value, key: &str) -> Option<&'a toml::Value> {
+    key.split('.')
+        .try_fold(value, |value, segment| value.as_table()?.get(segment))
+}
+
+/// Set a dotted key in a TOML document, keeping its comments and formatting.
+fn edit(content: &str, key: &str, value: toml::Value) -> Result<String> {
+    let mut document: toml_edit::DocumentMut = content.parse()?;
+    let mut segments: Vec<&str> = key.split('.').collect();
+    let last = segments.pop().context("Empty key")?;
+
+    let mut current = document.as_table_mut();
+    for segment in segments {
+        current = current
+            .entry(segment)
+            .or_insert_with(|| {
+                let mut table = toml_edit::Table::new();
+                table.set_implicit(true);
+                toml_edit::Item::Table(table)
+            })
+            .as_table_mut()
+            .with_context(|| format!("'{}' is not a table", segment))?;
+    }
+
+    let mut item = to_item(value);
+    match current.get_mut(last) {
+        // Replace in place so the key keeps its comments
+        Some(existing) => {
+            if let (Some(old), Some(new)) = (existing.as_value(), item.as_value_mut()) {
+                *new.decor_mut() = old.decor().clone();
+            }
+            *existing = item;
+        }
+        None => {
+            current.insert(last, item);
+        }
+    }
+    Ok(document.to_string())
+}
 
-            std::fs::write(&config_file, config_str)
-                .context("Could not write default config file")?;
+/// Convert a value to a document item, writing tables and arrays of tables as sections.
+fn to_item(value: toml::Value) -> toml_edit::Item {
+    match value {
+        toml::Value::Table(table) => {
+            let mut section = toml_edit::Table::new();
+            for (key, value) in table {
+                section.insert(&key, to_item(value));
+            }
+            toml_edit::Item::Table(section)
+        }
+        toml::Value::Array(array) if !array.is_empty() && array.iter().all(|v| v.is_table()) => {
+            let mut sections = toml_edit::ArrayOfTables::new();
+            for value in array {
+                if let toml_edit::Item::Table(table) = to_item(value) {
+                    sections.push(table);
+                }
+            }
+            toml_edit::Item::ArrayOfTables(sections)
+        }
+        value => toml_edit::Item::Value(to_value(value)),
+    }
+}
 
-            Ok(config)
+fn to_value(value: toml::Value) -> toml_edit::Value {
+    match value {
+        toml::Value::String(s) => s.into(),
+        toml::Value::Integer(i) => i.into(),
+        toml::Value::Float(f) => f.into(),
+        toml::Value::Boolean(b) => b.into(),
+        toml::Value::Datetime(d) => toml_edit::Value::from(
+            d.to_string()
+                .parse::<toml_edit::Datetime>()
+                .expect("a TOML datetime is valid in any TOML document"),
+        ),
+        toml::Value::Array(array) => {
+            toml_edit::Value::Array(array.into_iter().map(to_value).collect())
         }
+        toml::Value::Table(table) => toml_edit::Value::InlineTable(
+            table
+                .into_iter()
+                .map(|(key, value)| (key, to_value(value)))
+                .collect(),
+        ),
+    }
+}
+
+/// Insert a value at a dotted key, creating the intermediate tables.
+pub fn insert(table: &mut toml::Table, key: &str, value: toml::Value) -> Result<()> {
+    let mut segments: Vec<&str> = key.split('.').collect();
+    let last = segments.pop().context("Empty key")?;
+
+    let mut current = table;
+    for segment in segments {
+        current = current
+            .entry(segment)
+            .or_insert_with(|| toml::Value::Table(toml::Table::new()))
+            .as_table_mut()
+            .with_context(|| format!("'{}' is not a table", segment))?;
+    }
+
+    current.insert(last.to_string(), value);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = r#"# Linear settings
+api_key = "lin_api_123" # personal key
+refresh_interval = 30
+
+# Column widths
+[issues.widths]
+# Wide enough for most titles
+title = 40
+"#;
+
+    #[test]
+    fn edit_keeps_comments() {
+        let content = edit(CONFIG, "issues.widths.title", toml::Value::Integer(55)).unwrap();
+        assert_eq!(content, CONFIG.replace("title = 40", "title = 55"));
+
+        let content = edit(CONFIG, "api_key", "lin_api_456".into()).unwrap();
+        assert_eq!(content, CONFIG.replace("lin_api_123", "lin_api_456"));
+    }
+
+    #[test]
+    fn edit_creates_tables() {
+        let content = edit(CONFIG, "theme.primary_color", "cyan".into()).unwrap();
+        assert!(content.starts_with(CONFIG));
+        assert!(content.ends_with("[theme]\nprimary_color = \"cyan\"\n"));
+    }
+
+    #[test]
+    fn edit_writes_arrays_of_tables() {
+        let mut view = toml::Table::new();
+        view.insert("name".into(), "Mine".into());
+        let content = edit(CONFIG, "views", toml::Value::Array(vec![view.into()])).unwrap();
+        assert!(content.starts_with(CONFIG));
+        assert!(content.ends_with("[[views]]\nname = \"Mine\"\n"));
     }
 }
@@ -0,0 +1,122 @@
+// SPDX-FileCopyrightText: Copyright (C) Nicolas Lamirault <nicolas.lamirault@gmail.com>
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt;
+use std::ops::Range;
+
+use toml::de::{DeTable, DeValue};
+use toml::Spanned;
+
+use super::Config;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+/// A problem found in a configuration file, located by 1-based line and column.
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let severity = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        write!(
+            f,
+            "{}:{}: {}: {}",
+            self.line, self.column, severity, self.message
+        )
+    }
+}
+
+/// Check a configuration file for syntax errors, type errors and unknown keys.
+pub fn validate(content: &str) -> Vec<Diagnostic> {
+    let document = match DeTable::parse(content) {
+        Ok(document) => document,
+        Err(e) => return vec![diagnostic(content, Severity::Error, &e)],
+    };
+
+    let mut unknown_keys = Vec::new();
+    let deserializer = toml::Deserializer::from(document.clone());
+    let result: Result<Config, _> =
+        serde_ignored::deserialize(deserializer, |path| unknown_keys.push(path.to_string()));
+
+    let mut diagnostics: Vec<Diagnostic> = unknown_keys
+        .into_iter()
+        .map(|key| {
+            let span = find_key(document.get_ref(), &key).unwrap_or(0..0);
+            let (line, column) = line_column(content, span.start);
+            Diagnostic {
+                severity: Severity::Warning,
+                line,
+                column,
+                message: format!("unknown key `{}`", key),
+            }
+        })
+        .collect();
+
+    if let Err(e) = result {
+        diagnostics.push(diagnostic(content, Severity::Error, &e));
+    }
+
+    diagnostics.sort_by_key(|d| (d.line, d.column));
+    diagnostics
+}
+
+fn diagnostic(content: &str, severity: Severity, error: &toml::de::Error) -> Diagnostic {
+    let (line, column) = line_column(content, error.span().map(|s| s.start).unwrap_or(0));
+    Diagnostic {
+        severity,
+        line,
+        column,
+        message: error.message().trim().to_string(),
+    }
+}
+
+/// Locate a dotted key path (as reported by `serde_ignored`) in the parsed document.
+fn find_key(table: &DeTable<'_>, path: &str) -> Option<Range<usize>> {
+    let mut segments = path.split('.');
+    let first = segments.next()?;
+    let (key, mut value) = table.get_key_value(first)?;
+    let mut span = key.span();
+
+    for segment in segments {
+        let next: &Spanned<DeValue<'_>> = match value.get_ref() {
+            DeValue::Table(table) => {
+                let (key, value) = table.get_key_value(segment)?;
+                span = key.span();
+                value
+            }
+            DeValue::Array(array) => {
+                let value = array.get(segment.parse::<usize>().ok()?)?;
+                span = value.span();
+                value
+            }
+            _ => return Some(span),
+        };
+        value = next;
+    }
+
+    Some(span)
+}
+
+fn line_column(content: &str, offset: usize) -> (usize, usize) {
+    let before = &content[..offset.min(content.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before
+        .rsplit('\n')
+        .next()
+        .map(|l| l.chars().count())
+        .unwrap_or(0)
+        + 1;
+    (line, column)
+}
@@ -0,0 +1,166 @@
+// SPDX-FileCopyrightText: Copyright (C) Nicolas Lamirault <nicolas.lamirault@gmail.com>
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{Context, Result};
+use clap::ValueEnum;
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use std::path::Path;
+
+use crate::utils::Column;
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum ExportFormat {
+    Markdown,
+    Csv,
+    Json,
+}
+
+impl ExportFormat {
+    /// Guess the format from a file extension.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_lowercase();
+        match extension.as_str() {
+            "md" | "markdown" => Some(ExportFormat::Markdown),
+            "csv" => Some(ExportFormat::Csv),
+            "json" => Some(ExportFormat::Json),
+            _ => None,
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Markdown => "md",
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+        }
+    }
+}
+
+/// Parse comma separated column names, e.g. "identifier,title,state".
+pub fn parse_columns<C: ValueEnum>(text: &str) -> Result<Vec<C>, String> {
+    let columns: Vec<C> = text
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(|name| C::from_str(name, true).map_err(|_| format!("Unknown column '{}'", name)))
+        .collect::<Result<_, _>>()?;
+    if columns.is_empty() {
+        return Err("No columns".to_string());
+    }
+    Ok(columns)
+}
+
+/// Column names as accepted by `parse_columns`.
+pub fn column_names<C: ValueEnum>(columns: &[C]) -> String {
+    columns
+        .iter()
+        .filter_map(|c| c.to_possible_value())
+        .map(|v| v.get_name().to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Render the selected columns of items in the given format. JSON objects are keyed by the
+/// column names accepted by `parse_columns`.
+pub fn render<T, C>(items: &[&T], columns: &[C], format: ExportFormat) -> Result<String>
+where
+    C: Column<T> + ValueEnum,
+{
+    match format {
+        ExportFormat::Markdown => Ok(render_markdown(items, columns)),
+        ExportFormat::Csv => render_csv(items, columns),
+        ExportFormat::Json => {
+            let records: Vec<Record<T, C>> =
+                items.iter().map(|&item| Record { item, columns }).collect();
+            serde_json::to_string_pretty(&records).context("Could not serialize items")
+        }
+    }
+}
+
+/// Write items to a file, using the format matching its extension unless one is given.
+pub fn write<T, C>(
+    path: &Path,
+    items: &[&T],
+    columns: &[C],
+    format: Option<ExportFormat>,
+) -> Result<ExportFormat>
+where
+    C: Column<T> + ValueEnum,
+{
+    let format = format
+        .or_else(|| ExportFormat::from_path(path))
+        .with_context(|| {
+            format!(
+                "Unknown export format for {}, use a .md, .csv or .json file",
+                path.display()
+            )
+        })?;
+
+    let content = render(items, columns, format)?;
+    std::fs::write(path, content).with_context(|| format!("Could not write {}", path.display()))?;
+    Ok(format)
+}
+
+/// The selected columns of an item, serialized as an object in column order.
+struct Record<'a, T, C> {
+    item: &'a T,
+    columns: &'a [C],
+}
+
+impl<T, C: Column<T> + ValueEnum> Serialize for Record<'_, T, C> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.columns.len()))?;
+        for column in self.columns {
+            if let Some(name) = column.to_possible_value() {
+                map.serialize_entry(name.get_name(), &column.value(self.item))?;
+            }
+        }
+        map.end()
+    }
+}
+
+fn render_markdown<T, C: Column<T>>(items: &[&T], columns: &[C]) -> String {
+    let escape = |value: String| value.replace('|', "\\|").replace(['\r', '\n'], " ");
+
+    let mut lines = Vec::with_capacity(items.len() + 2);
+    lines.push(format!(
+        "| {} |",
+        columns
+            .iter()
+            .map(|c| c.title())
+            .collect::<Vec<_>>()
+            .join(" | ")
+    ));
+    lines.push(format!(
+        "|{}|",
+        columns
+            .iter()
+            .map(|_| " --- ")
+            .collect::<Vec<_>>()
+            .join("|")
+    ));
+
+    for item in items {
+        lines.push(format!(
+            "| {} |",
+            columns
+                .iter()
+                .map(|c| escape(c.value(item)))
+                .collect::<Vec<_>>()
+                .join(" | ")
+        ));
+    }
+
+    lines.join("\n") + "\n"
+}
+
+fn render_csv<T, C: Column<T>>(items: &[&T], columns: &[C]) -> Result<String> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer.write_record(columns.iter().map(|c| c.title()))?;
+    for item in items {
+        writer.write_record(columns.iter().map(|c| c.value(item)))?;
+    }
+
+    let bytes = writer.into_inner().context("Could not write CSV")?;
+    String::from_utf8(bytes).context("CSV output is not valid UTF-8")
+}
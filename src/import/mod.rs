@@ -0,0 +1,320 @@
+// SPDX-FileCopyrightText: Copyright (C) Nicolas Lamirault <nicolas.lamirault@gmail.com>
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{Context, Result};
+use clap::ValueEnum;
+use serde_json::{json, Map, Value};
+use std::path::Path;
+
+use crate::client::{Issue, IssueCreateInput, IssueLabel, IssueState, LinearClient, User};
+use crate::utils::parse_priority;
+
+/// Base of the URL attached to imported issues to recognize them on re-runs.
+const EXTERNAL_ID_URL: &str = "ltui://import/";
+
+#[derive(Debug, Clone, Copy, PartialEq, ValueEnum)]
+pub enum ImportFormat {
+    Csv,
+    Json,
+}
+
+impl ImportFormat {
+    /// Guess the format from a file extension.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_lowercase();
+        match extension.as_str() {
+            "csv" => Some(ImportFormat::Csv),
+            "json" => Some(ImportFormat::Json),
+            _ => None,
+        }
+    }
+}
+
+/// An issue read from the import file, before names are resolved to Linear ids.
+#[derive(Debug, Clone, Default)]
+pub struct ImportRow {
+    pub title: String,
+    pub description: Option<String>,
+    pub priority: Option<i32>,
+    pub assignee: Option<String>,
+    pub labels: Vec<String>,
+    pub state: Option<String>,
+    pub external_id: Option<String>,
+}
+
+pub struct ImportFile {
+    /// Rows numbered from 1, each either parsed or with the reason it could not be.
+    pub rows: Vec<(usize, Result<ImportRow>)>,
+    /// Columns that do not map to an issue field.
+    pub ignored_columns: Vec<String>,
+}
+
+/// Read a CSV file with a header row, or a JSON array of objects.
+pub fn read(path: &Path, format: Option<ImportFormat>) -> Result<ImportFile> {
+    let format = format
+        .or_else(|| ImportFormat::from_path(path))
+        .with_context(|| {
+            format!(
+                "Unknown import format for {}, use a .csv or .json file",
+                path.display()
+            )
+        })?;
+
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Could not read {}", path.display()))?;
+
+    let records = match format {
+        ImportFormat::Csv => read_csv(&content)?,
+        ImportFormat::Json => read_json(&content)?,
+    };
+
+    let mut ignored_columns = Vec::new();
+    for record in &records {
+        for key in record.keys() {
+            if field(key).is_none() && !ignored_columns.contains(key) {
+                ignored_columns.push(key.clone());
+            }
+        }
+    }
+
+    let rows = records
+        .iter()
+        .enumerate()
+        .map(|(i, record)| (i + 1, parse_row(record)))
+        .collect();
+
+    Ok(ImportFile {
+        rows,
+        ignored_columns,
+    })
+}
+
+fn read_csv(content: &str) -> Result<Vec<Map<String, Value>>> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(content.as_bytes());
+
+    let headers: Vec<String> = reader
+        .headers()
+        .context("Could not read the CSV header")?
+        .iter()
+        .map(normalize_key)
+        .collect();
+
+    let mut records = Vec::new();
+    for record in reader.records() {
+        let record = record.context("Could not read CSV record")?;
+        let map = headers
+            .iter()
+            .zip(record.iter())
+            .filter(|(_, value)| !value.is_empty())
+            .map(|(key, value)| (key.clone(), Value::String(value.to_string())))
+            .collect();
+        records.push(map);
+    }
+    Ok(records)
+}
+
+fn read_json(content: &str) -> Result<Vec<Map<String, Value>>> {
+    let values: Vec<Value> =
+        serde_json::from_str(content).context("Expected a JSON array of objects")?;
+
+    values
+        .into_iter()
+        .enumerate()
+        .map(|(i, value)| match value {
+            Value::Object(object) => Ok(object
+                .into_iter()
+                .filter(|(_, value)| !value.is_null())
+                .map(|(key, value)| (normalize_key(&key), value))
+                .collect()),
+            _ => Err(anyhow::anyhow!("Record {} is not a JSON object", i + 1)),
+        })
+        .collect()
+}
+
+fn normalize_key(key: &str) -> String {
+    key.trim().to_lowercase().replace([' ', '-'], "_")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Field {
+    Title,
+    Description,
+    Priority,
+    Assignee,
+    Labels,
+    State,
+    ExternalId,
+}
+
+fn field(key: &str) -> Option<Field> {
+    match key {
+        "title" | "name" | "summary" => Some(Field::Title),
+        "description" | "body" => Some(Field::Description),
+        "priority" => Some(Field::Priority),
+        "assignee" | "assignee_email" | "email" => Some(Field::Assignee),
+        "labels" | "label" => Some(Field::Labels),
+        "state" | "status" => Some(Field::State),
+        "external_id" | "externalid" => Some(Field::ExternalId),
+        _ => None,
+    }
+}
+
+fn parse_row(record: &Map<String, Value>) -> Result<ImportRow> {
+    let mut row = ImportRow::default();
+
+    for (key, value) in record {
+        match field(key) {
+            Some(Field::Title) => row.title = text(value),
+            Some(Field::Description) => row.description = optional_text(value),
+            Some(Field::Priority) => {
+                if let Some(priority) = optional_text(value) {
+                    row.priority = Some(
+                        parse_priority(&priority)
+                            .with_context(|| format!("Invalid priority '{}'", priority))?,
+                    );
+                }
+            }
+            Some(Field::Assignee) => row.assignee = optional_text(value),
+            Some(Field::Labels) => row.labels = labels(value),
+            Some(Field::State) => row.state = optional_text(value),
+            Some(Field::ExternalId) => row.external_id = optional_text(value),
+            None => {}
+        }
+    }
+
+    if row.title.is_empty() {
+        return Err(anyhow::anyhow!("Missing title"));
+    }
+
+    Ok(row)
+}
+
+fn text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.trim().to_string(),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+fn optional_text(value: &Value) -> Option<String> {
+    Some(text(value)).filter(|s| !s.is_empty())
+}
+
+/// Labels given as a JSON array, or as a comma or semicolon separated list.
+fn labels(value: &Value) -> Vec<String> {
+    let names: Vec<String> = match value {
+        Value::Array(values) => values.iter().map(text).collect(),
+        value => text(value).split([',', ';']).map(str::to_string).collect(),
+    };
+
+    names
+        .iter()
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .collect()
+}
+
+/// Users, labels and workflow states of the team, used to resolve names to ids.
+pub struct Resolver {
+    team_id: String,
+    users: Vec<User>,
+    labels: Vec<IssueLabel>,
+    states: Vec<IssueState>,
+}
+
+impl Resolver {
+    pub async fn load(client: &LinearClient, team_id: &str) -> Result<Self> {
+        Ok(Self {
+            team_id: team_id.to_string(),
+            users: client.get_users().await.context("Could not fetch users")?,
+            labels: client
+                .get_labels(team_id)
+                .await
+                .context("Could not fetch labels")?,
+            states: client
+                .get_workflow_states(team_id)
+                .await
+                .context("Could not fetch workflow states")?,
+        })
+    }
+
+    /// Build the create mutation input for a row, failing on any name that does not exist.
+    pub fn resolve(&self, row: &ImportRow) -> Result<IssueCreateInput> {
+        let assignee_id = match &row.assignee {
+            Some(email) => Some(
+                self.users
+                    .iter()
+                    .find(|u| {
+                        u.email
+                            .as_deref()
+                            .is_some_and(|e| e.eq_ignore_ascii_case(email))
+                    })
+                    .map(|u| u.id.clone())
+                    .with_context(|| format!("No user with email '{}'", email))?,
+            ),
+            None => None,
+        };
+
+        let label_ids = row
+            .labels
+            .iter()
+            .map(|name| {
+                self.labels
+                    .iter()
+                    .find(|l| l.name.eq_ignore_ascii_case(name))
+                    .map(|l| l.id.clone())
+                    .with_context(|| format!("Unknown label '{}'", name))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let state_id = match &row.state {
+            Some(name) => Some(
+                self.states
+                    .iter()
+                    .find(|s| s.name.eq_ignore_ascii_case(name))
+                    .map(|s| s.id.clone())
+                    .with_context(|| format!("Unknown state '{}'", name))?,
+            ),
+            None => None,
+        };
+
+        Ok(IssueCreateInput {
+            team_id: self.team_id.clone(),
+            title: row.title.clone(),
+            description: row.description.clone(),
+            priority: row.priority,
+            assignee_id,
+            label_ids,
+            state_id,
+            parent_id: None,
+        })
+    }
+}
+
+/// URL of the attachment keeping the external id of an imported issue.
+pub fn external_url(external_id: &str) -> String {
+    let mut url = reqwest::Url::parse(EXTERNAL_ID_URL).expect("valid base URL");
+    url.path_segments_mut()
+        .expect("base URL has a path")
+        .pop_if_empty()
+        .push(external_id);
+    url.to_string()
+}
+
+/// Find an issue of the team previously imported with the given external id.
+pub async fn find_imported(
+    client: &LinearClient,
+    team_id: &str,
+    external_id: &str,
+) -> Result<Option<Issue>> {
+    let filter = json!({
+        "team": { "id": { "eq": team_id } },
+        "attachments": { "some": { "url": { "eq": external_url(external_id) } } }
+    });
+
+    let issues = client.search_issues(filter, Some(1)).await?.nodes;
+    Ok(issues.into_iter().next())
+}
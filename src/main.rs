@@ -2,24 +2,34 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod app;
+pub mod cache;
 pub mod cli;
 pub mod client;
 pub mod config;
+pub mod export;
+pub mod import;
+pub mod query;
+pub mod report;
 pub mod ui;
 pub mod utils;
 
 use anyhow::Result;
-use clap::Parser;
+use clap::{CommandFactory, Parser};
+use clap_complete::env::CompleteEnv;
 use cli::Cli;
 
 #[tokio::main]
 async fn main() -> Result<()> {
+    CompleteEnv::with_factory(Cli::command)
+        .var(cli::completions::COMPLETE_VAR)
+        .complete();
+
     let cli = Cli::parse();
 
     match cli.run().await {
         Ok(_) => Ok(()),
         Err(e) => {
-            eprintln!("Error: {}", e);
+            eprintln!("Error: {:#}", e);
             std::process::exit(1);
         }
     }
@@ -0,0 +1,683 @@
+// SPDX-FileCopyrightText: Copyright (C) Nicolas Lamirault <nicolas.lamirault@gmail.com>
+// SPDX-License-Identifier: Apache-2.0
+
+//! A small query language for issues, turned into Linear's `IssueFilter` input.
+//!
+//! A query is a list of terms, all of which must match:
+//!
+//! ```text
+//! state:started assignee:me priority>=high label:bug updated:<7d project:"Q3 Launch"
+//! ```
+//!
+//! Comma separated values match any of them (`state:todo,started`), and words without a
+//! field are searched in the title.
+
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, Utc};
+use serde_json::{json, Value};
+use std::fmt;
+
+use crate::utils::{parse_duration, parse_priority};
+
+pub const FIELDS: &[&str] = &[
+    "state",
+    "assignee",
+    "creator",
+    "priority",
+    "estimate",
+    "label",
+    "project",
+    "team",
+    "cycle",
+    "created",
+    "updated",
+    "completed",
+    "due",
+];
+
+const STATE_TYPES: &[&str] = &[
+    "triage",
+    "backlog",
+    "unstarted",
+    "started",
+    "completed",
+    "canceled",
+];
+
+/// A problem in a query, located by its 1-based column.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryError {
+    pub column: usize,
+    pub message: String,
+}
+
+impl fmt::Display for QueryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "column {}: {}", self.column, self.message)
+    }
+}
+
+impl std::error::Error for QueryError {}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Op {
+    Eq,
+    Lt,
+    Lte,
+    Gt,
+    Gte,
+}
+
+impl Op {
+    fn symbol(&self) -> &'static str {
+        match self {
+            Op::Eq => ":",
+            Op::Lt => "<",
+            Op::Lte => "<=",
+            Op::Gt => ">",
+            Op::Gte => ">=",
+        }
+    }
+
+    fn comparator(&self) -> &'static str {
+        match self {
+            Op::Eq => "eq",
+            Op::Lt => "lt",
+            Op::Lte => "lte",
+            Op::Gt => "gt",
+            Op::Gte => "gte",
+        }
+    }
+
+    /// The comparison seen from the other side, e.g. "less than 7 days ago" is "after".
+    fn flip(&self) -> Self {
+        match self {
+            Op::Eq => Op::Eq,
+            Op::Lt => Op::Gt,
+            Op::Lte => Op::Gte,
+            Op::Gt => Op::Lt,
+            Op::Gte => Op::Lte,
+        }
+    }
+}
+
+/// One `field<op>value` term, or a word searched in the title when `field` is empty.
+#[derive(Debug, Clone)]
+struct Term {
+    column: usize,
+    field: String,
+    op: Op,
+    values: Vec<String>,
+}
+
+/// A parsed query.
+#[derive(Debug, Clone)]
+pub struct Query {
+    pub text: String,
+    filters: Vec<Value>,
+    fields: Vec<String>,
+}
+
+impl Query {
+    /// Parse a query, resolving relative dates against the current time.
+    pub fn parse(input: &str) -> Result<Self, QueryError> {
+        Self::parse_at(input, Utc::now())
+    }
+
+    fn parse_at(input: &str, now: DateTime<Utc>) -> Result<Self, QueryError> {
+        let terms = tokenize(input)?;
+        let filters = terms
+            .iter()
+            .map(|term| filter(term, now))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            text: input.trim().to_string(),
+            fields: terms.into_iter().map(|t| t.field).collect(),
+            filters,
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.filters.is_empty()
+    }
+
+    /// The `IssueFilter` of the query, restricted to a team unless the query names one.
+    pub fn filter(&self, team_id: Option<&str>) -> Value {
+        let mut filters = self.filters.clone();
+        if let Some(team_id) = team_id.filter(|_| !self.fields.iter().any(|f| f == "team")) {
+            filters.insert(0, json!({ "team": { "id": { "eq": team_id } } }));
+        }
+
+        match filters.len() {
+            0 => json!({}),
+            1 => filters.remove(0),
+            _ => json!({ "and": filters }),
+        }
+    }
+}
+
+fn tokenize(input: &str) -> Result<Vec<Term>, QueryError> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut terms = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        if chars[i].is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        while i < chars.len() && (chars[i].is_ascii_alphabetic() || chars[i] == '_') {
+            i += 1;
+        }
+        let field: String = chars[start..i].iter().collect::<String>().to_lowercase();
+
+        let mut op = None;
+        if !field.is_empty() && i < chars.len() {
+            if chars[i] == ':' {
+                i += 1;
+                op = Some(Op::Eq);
+            }
+            let comparison = match (chars.get(i), chars.get(i + 1)) {
+                (Some('<'), Some('=')) => Some((Op::Lte, 2)),
+                (Some('>'), Some('=')) => Some((Op::Gte, 2)),
+                (Some('<'), _) => Some((Op::Lt, 1)),
+                (Some('>'), _) => Some((Op::Gt, 1)),
+                _ => None,
+            };
+            if let Some((comparison, len)) = comparison {
+                i += len;
+                op = Some(comparison);
+            }
+        }
+
+        let Some(op) = op else {
+            // A word or a quoted phrase searched in the title
+            i = start;
+            let value = read_value(&chars, &mut i, false)?;
+            terms.push(Term {
+                column: start + 1,
+                field: String::new(),
+                op: Op::Eq,
+                values: vec![value],
+            });
+            continue;
+        };
+
+        let field = match field.as_str() {
+            "status" => "state".to_string(),
+            "labels" => "label".to_string(),
+            _ => field,
+        };
+        if !FIELDS.contains(&field.as_str()) {
+            return Err(QueryError {
+                column: start + 1,
+                message: format!(
+                    "unknown field `{}`, expected one of {}",
+                    field,
+                    FIELDS.join(", ")
+                ),
+            });
+        }
+
+        let mut values = vec![read_value(&chars, &mut i, true)?];
+        while chars.get(i) == Some(&',') {
+            i += 1;
+            values.push(read_value(&chars, &mut i, true)?);
+        }
+        if values.iter().any(String::is_empty) {
+            return Err(QueryError {
+                column: i + 1,
+                message: format!("missing value for `{}`", field),
+            });
+        }
+
+        terms.push(Term {
+            column: start + 1,
+            field,
+            op,
+            values,
+        });
+    }
+
+    Ok(terms)
+}
+
+/// Read a quoted or bare value, stopping at whitespace (and commas in lists).
+fn read_value(chars: &[char], i: &mut usize, list: bool) -> Result<String, QueryError> {
+    if chars.get(*i) == Some(&'"') {
+        let start = *i;
+        *i += 1;
+        let mut value = String::new();
+        while let Some(&c) = chars.get(*i) {
+            *i += 1;
+            if c == '"' {
+                return Ok(value);
+            }
+            value.push(c);
+        }
+        return Err(QueryError {
+            column: start + 1,
+            message: "unterminated quote".to_string(),
+        });
+    }
+
+    let mut value = String::new();
+    while let Some(&c) = chars.get(*i) {
+        if c.is_whitespace() || (list && c == ',') {
+            break;
+        }
+        value.push(c);
+        *i += 1;
+    }
+    Ok(value)
+}
+
+fn filter(term: &Term, now: DateTime<Utc>) -> Result<Value, QueryError> {
+    let error = |message: String| QueryError {
+        column: term.column,
+        message,
+    };
+    let single = || match term.values.as_slice() {
+        [value] => Ok(value.as_str()),
+        _ => Err(error(format!(
+            "`{}{}` takes a single value",
+            term.field,
+            term.op.symbol()
+        ))),
+    };
+    let equality_only = || {
+        if term.op == Op::Eq {
+            Ok(())
+        } else {
+            Err(error(format!(
+                "`{}` cannot be used with {}",
+                term.op.symbol(),
+                term.field
+            )))
+        }
+    };
+
+    let filter = match term.field.as_str() {
+        "" => json!({ "title": { "containsIgnoreCase": term.values[0] } }),
+        "state" => {
+            equality_only()?;
+            let states = term
+                .values
+                .iter()
+                .map(|value| {
+                    let value = value.to_lowercase();
+                    if STATE_TYPES.contains(&value.as_str()) {
+                        json!({ "type": { "eq": value } })
+                    } else {
+                        json!({ "name": { "eqIgnoreCase": value } })
+                    }
+                })
+                .collect();
+            json!({ "state": any_of(states) })
+        }
+        "assignee" | "creator" => {
+            equality_only()?;
+            let users = term
+                .values
+                .iter()
+                .map(|value| match value.to_lowercase().as_str() {
+                    "me" => json!({ "isMe": { "eq": true } }),
+                    "none" if term.field == "assignee" => json!({ "null": true }),
+                    _ if value.contains('@') => json!({ "email": { "eqIgnoreCase": value } }),
+                    _ => json!({ "displayName": { "eqIgnoreCase": value } }),
+                })
+                .collect();
+            json!({ term.field.clone(): any_of(users) })
+        }
+        "priority" => {
+            let priorities = term
+                .values
+                .iter()
+                .map(|value| {
+                    parse_priority(value).ok_or_else(|| {
+                        error(format!(
+                            "invalid priority `{}`, expected urgent, high, medium, low or none",
+                            value
+                        ))
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            // Compare by importance: urgent (1) > high > medium > low (4) > none (0)
+            let importance = |p: i32| if p == 0 { 0 } else { 5 - p };
+            let matching: Vec<i32> = match term.op {
+                Op::Eq => priorities,
+                op => {
+                    single()?;
+                    let bound = importance(priorities[0]);
+                    (0..=4)
+                        .filter(|p| {
+                            let p = importance(*p);
+                            match op {
+                                Op::Lt => p < bound,
+                                Op::Lte => p <= bound,
+                                Op::Gt => p > bound,
+                                _ => p >= bound,
+                            }
+                        })
+                        .collect()
+                }
+            };
+            json!({ "priority": { "in": matching } })
+        }
+        "estimate" => {
+            let value = single()?;
+            if value.eq_ignore_ascii_case("none") {
+                equality_only()?;
+                json!({ "estimate": { "null": true } })
+            } else {
+                let estimate: f64 = value
+                    .parse()
+                    .map_err(|_| error(format!("invalid estimate `{}`", value)))?;
+                json!({ "estimate": { term.op.comparator(): estimate } })
+            }
+        }
+        "label" => {
+            equality_only()?;
+            let labels = term
+                .values
+                .iter()
+                .map(|value| json!({ "labels": { "some": { "name": { "eqIgnoreCase": value } } } }))
+                .collect();
+            any_of(labels)
+        }
+        "project" | "team" => {
+            equality_only()?;
+            let key = if term.field == "team" { "key" } else { "name" };
+            let items = term
+                .values
+                .iter()
+                .map(|value| {
+                    if term.field == "project" && value.eq_ignore_ascii_case("none") {
+                        json!({ "null": true })
+                    } else {
+                        json!({ key: { "eqIgnoreCase": value } })
+                    }
+                })
+                .collect();
+            json!({ term.field.clone(): any_of(items) })
+        }
+        "cycle" => {
+            equality_only()?;
+            let cycles = term
+                .values
+                .iter()
+                .map(|value| match value.to_lowercase().as_str() {
+                    "current" | "active" => Ok(json!({ "isActive": { "eq": true } })),
+                    "next" => Ok(json!({ "isNext": { "eq": true } })),
+                    "previous" => Ok(json!({ "isPrevious": { "eq": true } })),
+                    "none" => Ok(json!({ "null": true })),
+                    _ => value
+                        .parse::<f64>()
+                        .map(|number| json!({ "number": { "eq": number } }))
+                        .map_err(|_| {
+                            error(format!(
+                                "invalid cycle `{}`, expected a number, current, next, previous or none",
+                                value
+                            ))
+                        }),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            json!({ "cycle": any_of(cycles) })
+        }
+        "created" | "updated" | "completed" => {
+            let value = single()?;
+            let key = format!("{}At", term.field);
+            let comparison = match parse_duration(value) {
+                // "updated:<7d" is less than 7 days ago, so after that time, and
+                // "updated:7d" means within the last 7 days
+                Some(age) => match term.op {
+                    Op::Eq => json!({ "gte": now - age }),
+                    op => json!({ op.flip().comparator(): now - age }),
+                },
+                None => {
+                    let date = parse_date(value).ok_or_else(|| {
+                        error(format!(
+                            "invalid date `{}`, expected a duration (e.g. 7d) or YYYY-MM-DD",
+                            value
+                        ))
+                    })?;
+                    // A date covers the whole day: after it starts the next day
+                    let start = date.and_time(NaiveTime::MIN).and_utc();
+                    let end = start + Duration::days(1);
+                    match term.op {
+                        Op::Eq => json!({ "gte": start, "lt": end }),
+                        Op::Lt => json!({ "lt": start }),
+                        Op::Lte => json!({ "lt": end }),
+                        Op::Gt => json!({ "gte": end }),
+                        Op::Gte => json!({ "gte": start }),
+                    }
+                }
+            };
+            json!({ key: comparison })
+        }
+        "due" => {
+            let value = single()?;
+            let today = now.date_naive();
+            let comparison = if value.eq_ignore_ascii_case("none") {
+                equality_only()?;
+                json!({ "null": true })
+            } else if value.eq_ignore_ascii_case("overdue") {
+                equality_only()?;
+                json!({ "lt": today.to_string() })
+            } else {
+                match parse_duration(value) {
+                    // "due:<7d" is due in less than 7 days
+                    Some(duration) => {
+                        let date = (now + duration).date_naive().to_string();
+                        match term.op {
+                            Op::Eq => json!({ "gte": today.to_string(), "lte": date }),
+                            op => json!({ op.comparator(): date }),
+                        }
+                    }
+                    None => {
+                        let date = parse_date(value).ok_or_else(|| {
+                            error(format!(
+                                "invalid date `{}`, expected a duration (e.g. 7d), YYYY-MM-DD, overdue or none",
+                                value
+                            ))
+                        })?;
+                        json!({ term.op.comparator(): date.to_string() })
+                    }
+                }
+            };
+            json!({ "dueDate": comparison })
+        }
+        field => return Err(error(format!("unknown field `{}`", field))),
+    };
+
+    Ok(filter)
+}
+
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()
+}
+
+/// A single filter, or an `or` of several.
+fn any_of(mut filters: Vec<Value>) -> Value {
+    if filters.len() == 1 {
+        filters.remove(0)
+    } else {
+        json!({ "or": filters })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 3, 15, 12, 0, 0).unwrap()
+    }
+
+    fn filter(input: &str) -> Value {
+        Query::parse_at(input, now()).unwrap().filter(None)
+    }
+
+    fn error(input: &str) -> QueryError {
+        Query::parse_at(input, now()).unwrap_err()
+    }
+
+    #[test]
+    fn empty_query() {
+        assert!(Query::parse_at("  ", now()).unwrap().is_empty());
+        assert_eq!(filter(""), json!({}));
+    }
+
+    #[test]
+    fn restricts_to_team_unless_named() {
+        let query = Query::parse_at("state:started", now()).unwrap();
+        assert_eq!(
+            query.filter(Some("team-1")),
+            json!({ "and": [
+                { "team": { "id": { "eq": "team-1" } } },
+                { "state": { "type": { "eq": "started" } } },
+            ] })
+        );
+
+        let query = Query::parse_at("team:ENG", now()).unwrap();
+        assert_eq!(
+            query.filter(Some("team-1")),
+            json!({ "team": { "key": { "eqIgnoreCase": "ENG" } } })
+        );
+    }
+
+    #[test]
+    fn unknown_field_error_column() {
+        let e = error("state:todo foo:bar");
+        assert_eq!(e.column, 12);
+        assert!(e.message.starts_with("unknown field `foo`"));
+    }
+
+    #[test]
+    fn unterminated_quote_error_column() {
+        let e = error(r#"label:bug project:"Q3 Launch"#);
+        assert_eq!(e.column, 19);
+        assert_eq!(e.message, "unterminated quote");
+    }
+
+    #[test]
+    fn missing_value_error_column() {
+        let e = error("state:todo, assignee:me");
+        assert_eq!(e.column, 12);
+        assert_eq!(e.message, "missing value for `state`");
+    }
+
+    #[test]
+    fn invalid_value_error_column() {
+        let e = error("assignee:me priority:soon");
+        assert_eq!(e.column, 13);
+        assert!(e.message.starts_with("invalid priority `soon`"));
+
+        let e = error("label<bug");
+        assert_eq!(e.column, 1);
+        assert_eq!(e.message, "`<` cannot be used with label");
+    }
+
+    #[test]
+    fn quoted_values() {
+        assert_eq!(
+            filter(r#"project:"Q3 Launch""#),
+            json!({ "project": { "name": { "eqIgnoreCase": "Q3 Launch" } } })
+        );
+        assert_eq!(
+            filter(r#"label:"needs review",bug"#),
+            json!({ "or": [
+                { "labels": { "some": { "name": { "eqIgnoreCase": "needs review" } } } },
+                { "labels": { "some": { "name": { "eqIgnoreCase": "bug" } } } },
+            ] })
+        );
+        assert_eq!(
+            filter(r#""login page""#),
+            json!({ "title": { "containsIgnoreCase": "login page" } })
+        );
+    }
+
+    #[test]
+    fn priority_comparisons() {
+        let priorities = |input: &str| filter(input)["priority"]["in"].clone();
+        assert_eq!(priorities("priority:high,urgent"), json!([2, 1]));
+        assert_eq!(priorities("priority>=high"), json!([1, 2]));
+        assert_eq!(priorities("priority>high"), json!([1]));
+        assert_eq!(priorities("priority<=low"), json!([0, 4]));
+        assert_eq!(priorities("priority<medium"), json!([0, 4]));
+        assert_eq!(priorities("priority>none"), json!([1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn relative_dates() {
+        let week_ago = json!(now() - Duration::days(7));
+        assert_eq!(
+            filter("updated:7d"),
+            json!({ "updatedAt": { "gte": week_ago } })
+        );
+        assert_eq!(
+            filter("updated:<7d"),
+            json!({ "updatedAt": { "gt": week_ago } })
+        );
+        assert_eq!(
+            filter("created:>=7d"),
+            json!({ "createdAt": { "lte": week_ago } })
+        );
+    }
+
+    #[test]
+    fn absolute_dates_cover_the_whole_day() {
+        let start = json!(Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap());
+        let end = json!(Utc.with_ymd_and_hms(2024, 3, 2, 0, 0, 0).unwrap());
+        assert_eq!(
+            filter("created:2024-03-01"),
+            json!({ "createdAt": { "gte": start, "lt": end } })
+        );
+        assert_eq!(
+            filter("created<2024-03-01"),
+            json!({ "createdAt": { "lt": start } })
+        );
+        assert_eq!(
+            filter("created<=2024-03-01"),
+            json!({ "createdAt": { "lt": end } })
+        );
+        assert_eq!(
+            filter("created>2024-03-01"),
+            json!({ "createdAt": { "gte": end } })
+        );
+        assert_eq!(
+            filter("created>=2024-03-01"),
+            json!({ "createdAt": { "gte": start } })
+        );
+    }
+
+    #[test]
+    fn invalid_date() {
+        let e = error("completed:yesterday");
+        assert_eq!(e.column, 1);
+        assert!(e.message.starts_with("invalid date `yesterday`"));
+
+        let e = error("updated:-3d");
+        assert!(e.message.starts_with("invalid date `-3d`"));
+    }
+
+    #[test]
+    fn due_dates() {
+        assert_eq!(
+            filter("due:overdue"),
+            json!({ "dueDate": { "lt": "2024-03-15" } })
+        );
+        assert_eq!(
+            filter("due:7d"),
+            json!({ "dueDate": { "gte": "2024-03-15", "lte": "2024-03-22" } })
+        );
+        assert_eq!(
+            filter("due<=2024-04-01"),
+            json!({ "dueDate": { "lte": "2024-04-01" } })
+        );
+    }
+}
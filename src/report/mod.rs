@@ -0,0 +1,277 @@
+// SPDX-FileCopyrightText: Copyright (C) Nicolas Lamirault <nicolas.lamirault@gmail.com>
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local, Utc};
+use clap::ValueEnum;
+use serde_json::json;
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use crate::client::{Issue, IssueActivity, LinearClient, Team};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, ValueEnum)]
+pub enum ReportFormat {
+    #[default]
+    Markdown,
+    /// Slack message formatting (mrkdwn)
+    Slack,
+}
+
+/// Why an issue is part of the standup report, in display order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Section {
+    Completed,
+    Moved,
+    Created,
+    Commented,
+    InProgress,
+}
+
+impl Section {
+    pub fn title(&self) -> &'static str {
+        match self {
+            Section::Completed => "Completed",
+            Section::Moved => "Moved",
+            Section::Created => "Created",
+            Section::Commented => "Commented on",
+            Section::InProgress => "In progress",
+        }
+    }
+}
+
+pub struct Entry {
+    pub section: Section,
+    pub issue: Issue,
+    /// Extra context, e.g. the state change or the number of comments.
+    pub note: Option<String>,
+}
+
+pub struct TeamReport {
+    pub team: Team,
+    pub entries: Vec<Entry>,
+}
+
+pub struct Standup {
+    pub since: DateTime<Utc>,
+    pub teams: Vec<TeamReport>,
+}
+
+/// Collect the viewer's activity since the given time, optionally for a single team.
+pub async fn standup(
+    client: &LinearClient,
+    since: DateTime<Utc>,
+    team_id: Option<&str>,
+) -> Result<Standup> {
+    let viewer = client.get_viewer().await?;
+    let is_me = json!({ "isMe": { "eq": true } });
+
+    let mut activity_filter = json!({
+        "updatedAt": { "gte": since },
+        "or": [
+            { "assignee": is_me },
+            { "creator": is_me },
+            { "comments": { "some": { "user": is_me, "createdAt": { "gte": since } } } }
+        ]
+    });
+    let mut in_progress_filter = json!({
+        "assignee": is_me,
+        "state": { "type": { "eq": "started" } }
+    });
+    if let Some(team_id) = team_id {
+        let team = json!({ "id": { "eq": team_id } });
+        activity_filter["team"] = team.clone();
+        in_progress_filter["team"] = team;
+    }
+
+    let activity = client
+        .get_issue_activity(activity_filter, since, None)
+        .await
+        .context("Could not fetch recent activity")?;
+    let in_progress = client
+        .search_issues(in_progress_filter, Some(100))
+        .await
+        .context("Could not fetch issues in progress")?
+        .nodes;
+
+    let mut entries: Vec<Entry> = activity
+        .into_iter()
+        .filter_map(|activity| classify(activity, &viewer.id, since))
+        .collect();
+
+    let listed: HashSet<String> = entries.iter().map(|e| e.issue.id.clone()).collect();
+    entries.extend(
+        in_progress
+            .into_iter()
+            .filter(|issue| !listed.contains(&issue.id))
+            .map(|issue| Entry {
+                section: Section::InProgress,
+                note: Some(issue.state.name.clone()),
+                issue,
+            }),
+    );
+
+    let mut teams: Vec<TeamReport> = Vec::new();
+    for entry in entries {
+        match teams.iter_mut().find(|t| t.team.id == entry.issue.team.id) {
+            Some(report) => report.entries.push(entry),
+            None => teams.push(TeamReport {
+                team: entry.issue.team.clone(),
+                entries: vec![entry],
+            }),
+        }
+    }
+
+    teams.sort_by(|a, b| a.team.key.cmp(&b.team.key));
+    for report in &mut teams {
+        report.entries.sort_by(|a, b| {
+            a.section
+                .cmp(&b.section)
+                .then(b.issue.updated_at.cmp(&a.issue.updated_at))
+        });
+    }
+
+    Ok(Standup { since, teams })
+}
+
+/// Pick the most significant thing the viewer did on an issue since the given time.
+fn classify(activity: IssueActivity, viewer_id: &str, since: DateTime<Utc>) -> Option<Entry> {
+    let IssueActivity {
+        issue,
+        history,
+        comments,
+    } = activity;
+
+    let is_assignee = issue.assignee.as_ref().is_some_and(|a| a.id == viewer_id);
+    let moves: Vec<_> = history
+        .iter()
+        .filter(|h| h.created_at >= since && h.to_state.is_some())
+        .filter(|h| h.actor.as_ref().is_some_and(|a| a.id == viewer_id))
+        .collect();
+
+    let completed = issue.state.state_type == "completed"
+        && issue.completed_at.is_some_and(|at| at >= since)
+        && (is_assignee || !moves.is_empty());
+
+    let (section, note) = if completed {
+        (Section::Completed, None)
+    } else if !moves.is_empty() {
+        let from = moves
+            .iter()
+            .min_by_key(|h| h.created_at)
+            .and_then(|h| h.from_state.as_ref())
+            .map(|s| s.name.as_str())
+            .unwrap_or("?");
+        let to = moves
+            .iter()
+            .max_by_key(|h| h.created_at)
+            .and_then(|h| h.to_state.as_ref())
+            .map(|s| s.name.as_str())
+            .unwrap_or("?");
+        (Section::Moved, Some(format!("{} → {}", from, to)))
+    } else if issue.creator.id == viewer_id && issue.created_at >= since {
+        (Section::Created, None)
+    } else {
+        let count = comments
+            .iter()
+            .filter(|c| c.user.as_ref().is_some_and(|u| u.id == viewer_id))
+            .count();
+        if count == 0 {
+            return None;
+        }
+        let plural = if count == 1 { "" } else { "s" };
+        (
+            Section::Commented,
+            Some(format!("{} comment{}", count, plural)),
+        )
+    };
+
+    Some(Entry {
+        section,
+        issue,
+        note,
+    })
+}
+
+pub fn render(standup: &Standup, format: ReportFormat) -> String {
+    let since = standup
+        .since
+        .with_timezone(&Local)
+        .format("%a %b %-d, %H:%M");
+    let mut out = String::new();
+
+    match format {
+        ReportFormat::Markdown => {
+            let _ = writeln!(out, "# Standup since {}", since);
+        }
+        ReportFormat::Slack => {
+            let _ = writeln!(out, "*Standup since {}*", since);
+        }
+    }
+
+    if standup.teams.is_empty() {
+        let _ = writeln!(out, "\nNo activity.");
+        return out;
+    }
+
+    for report in &standup.teams {
+        match format {
+            ReportFormat::Markdown => {
+                let _ = writeln!(out, "\n## {} ({})", report.team.name, report.team.key);
+            }
+            ReportFormat::Slack => {
+                let _ = writeln!(out, "\n*{}*", report.team.name);
+            }
+        }
+
+        let mut current = None;
+        for entry in &report.entries {
+            if current != Some(entry.section) {
+                current = Some(entry.section);
+                match format {
+                    ReportFormat::Markdown => {
+                        let _ = writeln!(out, "\n### {}\n", entry.section.title());
+                    }
+                    ReportFormat::Slack => {
+                        let _ = writeln!(out, "_{}_", entry.section.title());
+                    }
+                }
+            }
+
+            let issue = &entry.issue;
+            let note = entry
+                .note
+                .as_ref()
+                .map(|n| format!(" ({})", n))
+                .unwrap_or_default();
+            match format {
+                ReportFormat::Markdown => {
+                    let _ = writeln!(
+                        out,
+                        "- [{}]({}) {}{}",
+                        issue.identifier, issue.url, issue.title, note
+                    );
+                }
+                ReportFormat::Slack => {
+                    let _ = writeln!(
+                        out,
+                        "• <{}|{}> {}{}",
+                        issue.url,
+                        issue.identifier,
+                        slack_escape(&issue.title),
+                        note
+                    );
+                }
+            }
+        }
+    }
+
+    out
+}
+
+/// Escape the characters Slack treats as control sequences.
+fn slack_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
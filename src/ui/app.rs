@@ -1,6 +1,7 @@
 // SPDX-FileCopyrightText: Copyright (C) Nicolas Lamirault <nicolas.lamirault@gmail.com>
 // SPDX-License-Identifier: Apache-2.0
 
+use anyhow::Context;
 use crossterm::event::{KeyCode, KeyModifiers};
 use ratatui::{
     layout::{Constraint, Direction, Layout},
@@ -10,17 +11,148 @@ use ratatui::{
     Frame,
 };
 
-use crate::client::{LinearClient, Team};
+use crate::cache;
+use crate::client::{
+    CustomView, Cycle, Issue, IssueCreateInput, IssueLabel, IssueUpdateInput, LinearClient,
+    RelationKind, Team,
+};
+use crate::config::{Config, SavedView};
+use crate::export::{self, ExportFormat};
+use crate::query::Query;
+use crate::report;
 use crate::ui::{
-    components::{HelpComponent, IssuesComponent, ProjectsComponent, TeamsComponent},
+    components::{
+        BoardComponent, CyclesComponent, DetailsTab, HelpComponent, InboxComponent,
+        IssuesComponent, LabelPickerAction, LabelPickerComponent, MyIssuesComponent,
+        ProjectsComponent, PromptAction, PromptComponent, Relation, ReportComponent,
+        TeamsComponent, WorkloadComponent, Zoom,
+    },
     events::{AppEvent, EventHandler},
 };
+use crate::utils::{
+    clipboard, editor, frontmatter, parse_duration, Column, IssueColumn, ProjectColumn,
+};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum View {
     Issues,
     Projects,
     Teams,
+    Board,
+    MyIssues,
+    Inbox,
+    Cycles,
+    Workload,
+    /// A saved view, by index in `AppState::views`.
+    Saved(usize),
+}
+
+/// What the value entered in the prompt popup is used for.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PromptKind {
+    ExportIssues,
+    ExportProjects,
+    /// Columns of an export to `path`, comma separated.
+    ExportColumns {
+        target: ExportTarget,
+        path: String,
+    },
+    /// "y" to replace the existing file of an export.
+    ExportOverwrite {
+        target: ExportTarget,
+        path: String,
+        columns: String,
+    },
+    Query,
+    SaveView,
+    Snooze,
+    /// URL or path of a local file to attach to an issue.
+    Attach {
+        issue_id: String,
+        identifier: String,
+    },
+    /// Kind and identifier of an issue to relate to.
+    Relation {
+        issue_id: String,
+        identifier: String,
+    },
+    /// Title of a new sub-issue.
+    SubIssue {
+        parent_id: String,
+        identifier: String,
+        team_id: String,
+    },
+}
+
+/// What the `E` action exports.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExportTarget {
+    Issues,
+    Projects,
+}
+
+/// What the labels checked in the label picker are used for.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PickerKind {
+    /// Set the labels of an issue.
+    EditLabels {
+        issue_id: String,
+        identifier: String,
+    },
+    /// Only show the issues with one of the labels.
+    FilterLabels,
+}
+
+/// Where the issues of a saved view come from.
+pub enum ViewSource {
+    /// A query saved in the configuration.
+    Config(SavedView),
+    /// A custom view of the Linear workspace, read-only.
+    Linear(CustomView),
+}
+
+/// A saved view shown as a tab after the built-in views.
+pub struct ViewTab {
+    pub source: ViewSource,
+    pub issues: IssuesComponent,
+    pub loaded: bool,
+    pub error: Option<String>,
+}
+
+impl ViewTab {
+    fn new(source: ViewSource, config: &Config) -> Self {
+        let mut issues = IssuesComponent::with_config(config);
+        match &source {
+            ViewSource::Config(view) => {
+                issues.name = Some(view.name.clone());
+                issues.group_by = view.group_by;
+                issues.set_sort(view.sort, view.order);
+            }
+            ViewSource::Linear(view) => {
+                issues.name = Some(view.name.clone());
+            }
+        }
+        Self {
+            source,
+            issues,
+            loaded: false,
+            error: None,
+        }
+    }
+
+    fn title(&self) -> String {
+        let name = match &self.source {
+            ViewSource::Config(view) => view.name.clone(),
+            ViewSource::Linear(view) => format!("◆ {}", view.name),
+        };
+        if self.error.is_some() {
+            format!("{} (!)", name)
+        } else if self.loaded {
+            format!("{} ({})", name, self.issues.issues().len())
+        } else {
+            format!("{} (…)", name)
+        }
+    }
 }
 
 pub struct AppState {
@@ -30,48 +162,146 @@ pub struct AppState {
     pub issues_component: IssuesComponent,
     pub projects_component: ProjectsComponent,
     pub teams_component: TeamsComponent,
+    pub board_component: BoardComponent,
+    pub my_issues_component: MyIssuesComponent,
+    pub inbox_component: InboxComponent,
+    pub cycles_component: CyclesComponent,
+    pub workload_component: WorkloadComponent,
+    pub views: Vec<ViewTab>,
+    pub prompt: Option<(PromptKind, PromptComponent)>,
+    pub label_picker: Option<(PickerKind, LabelPickerComponent)>,
+    pub report: Option<ReportComponent>,
+    pub status_message: Option<String>,
     pub loading: bool,
 }
 
+impl Default for AppState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl AppState {
     pub fn new() -> Self {
+        Self::with_config(&Config::default())
+    }
+
+    pub fn with_config(config: &Config) -> Self {
         Self {
             current_view: View::Issues,
             current_team: None,
             show_help: false,
-            issues_component: IssuesComponent::new(),
-            projects_component: ProjectsComponent::new(),
+            issues_component: IssuesComponent::with_config(config),
+            projects_component: ProjectsComponent::with_config(config),
             teams_component: TeamsComponent::new(),
+            board_component: BoardComponent::new(),
+            my_issues_component: MyIssuesComponent::with_config(config),
+            inbox_component: InboxComponent::with_config(config),
+            cycles_component: CyclesComponent::new(),
+            workload_component: WorkloadComponent::with_config(config),
+            views: Vec::new(),
+            prompt: None,
+            label_picker: None,
+            report: None,
+            status_message: None,
             loading: false,
         }
     }
+
+    /// The issues table of the current view, if it shows one.
+    pub fn current_issues(&self) -> Option<&IssuesComponent> {
+        match self.current_view {
+            View::Issues => Some(&self.issues_component),
+            View::Projects if self.projects_component.detail.is_some() => {
+                Some(&self.projects_component.issues)
+            }
+            View::MyIssues => Some(self.my_issues_component.current()),
+            View::Inbox if self.inbox_component.open => Some(&self.inbox_component.issue),
+            View::Workload if self.workload_component.open => Some(&self.workload_component.issues),
+            View::Saved(index) => self.views.get(index).map(|tab| &tab.issues),
+            _ => None,
+        }
+    }
+
+    pub fn current_issues_mut(&mut self) -> Option<&mut IssuesComponent> {
+        match self.current_view {
+            View::Issues => Some(&mut self.issues_component),
+            View::Projects if self.projects_component.detail.is_some() => {
+                Some(&mut self.projects_component.issues)
+            }
+            View::MyIssues => Some(self.my_issues_component.current_mut()),
+            View::Inbox if self.inbox_component.open => Some(&mut self.inbox_component.issue),
+            View::Workload if self.workload_component.open => {
+                Some(&mut self.workload_component.issues)
+            }
+            View::Saved(index) => self.views.get_mut(index).map(|tab| &mut tab.issues),
+            _ => None,
+        }
+    }
+
+    /// Replace an issue after it was updated, in every view showing it.
+    pub fn replace_issue(&mut self, issue: Issue) {
+        self.issues_component.replace_issue(issue.clone());
+        for tab in &mut self.views {
+            tab.issues.replace_issue(issue.clone());
+        }
+        for table in self.my_issues_component.tables_mut() {
+            table.replace_issue(issue.clone());
+        }
+        self.inbox_component.replace_issue(issue.clone());
+        self.projects_component.replace_issue(issue.clone());
+        self.workload_component.replace_issue(issue.clone());
+        self.board_component.replace_issue(issue);
+    }
+
+    /// Views in tab order.
+    fn tabs(&self) -> Vec<View> {
+        let mut tabs = vec![
+            View::Issues,
+            View::Projects,
+            View::Teams,
+            View::Board,
+            View::MyIssues,
+            View::Inbox,
+            View::Cycles,
+            View::Workload,
+        ];
+        tabs.extend((0..self.views.len()).map(View::Saved));
+        tabs
+    }
 }
 
 pub struct TuiApp {
     state: AppState,
     client: LinearClient,
+    config: Config,
+    /// Configuration file given on the command line, the default location when unset.
+    config_path: Option<String>,
     event_handler: EventHandler,
+    initial_team: Option<String>,
 }
 
 impl TuiApp {
-    pub fn new(client: LinearClient) -> Self {
+    pub fn new(
+        client: LinearClient,
+        config: Config,
+        config_path: Option<String>,
+        initial_team: Option<String>,
+    ) -> Self {
         Self {
-            state: AppState::new(),
+            state: AppState::with_config(&config),
             client,
+            config,
+            config_path,
             event_handler: EventHandler::new(),
+            initial_team,
         }
     }
 
     pub async fn run(&mut self) -> anyhow::Result<()> {
-        crossterm::terminal::enable_raw_mode()?;
-        let mut stdout = std::io::stdout();
-        crossterm::execute!(
-            stdout,
-            crossterm::terminal::EnterAlternateScreen,
-            crossterm::cursor::Hide
-        )?;
+        enter_terminal()?;
 
-        let backend = ratatui::backend::CrosstermBackend::new(stdout);
+        let backend = ratatui::backend::CrosstermBackend::new(std::io::stdout());
         let mut terminal = ratatui::Terminal::new(backend)?;
 
         self.event_handler.start();
@@ -79,12 +309,7 @@ impl TuiApp {
 
         let result = self.run_app(&mut terminal).await;
 
-        crossterm::terminal::disable_raw_mode()?;
-        crossterm::execute!(
-            terminal.backend_mut(),
-            crossterm::terminal::LeaveAlternateScreen,
-            crossterm::cursor::Show
-        )?;
+        leave_terminal()?;
         terminal.show_cursor()?;
 
         result
@@ -92,15 +317,34 @@ impl TuiApp {
 
     async fn run_app(
         &mut self,
-        terminal: &mut ratatui::Terminal<
-            ratatui::backend::CrosstermBackend<std::io::Stdout>,
-        >,
+        terminal: &mut ratatui::Terminal<ratatui::backend::CrosstermBackend<std::io::Stdout>>,
     ) -> anyhow::Result<()> {
         loop {
             terminal.draw(|f| self.render(f))?;
 
+            // Drawn with its loading message first
+            if self.load_details().await
+                || self.load_history().await
+                || self.load_current_view().await
+            {
+                continue;
+            }
+
             if let Some(event) = self.event_handler.next().await {
                 match event {
+                    AppEvent::Key(key_event) if self.state.prompt.is_some() => {
+                        self.handle_prompt_input(key_event.code).await?;
+                    }
+                    AppEvent::Key(key_event) if self.state.label_picker.is_some() => {
+                        self.handle_label_picker_input(key_event.code).await;
+                    }
+                    AppEvent::Key(key_event) if self.state.report.is_some() => {
+                        if let Some(report) = self.state.report.as_mut() {
+                            if !report.handle_key(key_event.code) {
+                                self.state.report = None;
+                            }
+                        }
+                    }
                     AppEvent::Key(key_event) => match (key_event.code, key_event.modifiers) {
                         (KeyCode::Char('q'), _) | (KeyCode::Char('c'), KeyModifiers::CONTROL) => {
                             break;
@@ -109,7 +353,20 @@ impl TuiApp {
                             self.state.show_help = !self.state.show_help;
                         }
                         (KeyCode::Char('r'), _) => {
-                            self.refresh_current_view().await?;
+                            self.refresh().await;
+                        }
+                        (KeyCode::Char('R'), _) => {
+                            self.open_standup_report().await;
+                        }
+                        (KeyCode::Char('e'), _) if self.state.current_issues().is_some() => {
+                            self.edit_selected_issue(terminal).await?;
+                        }
+                        // Saved views have their own keys, the digits stay with the built-in views
+                        (KeyCode::Char(c @ '1'..='9'), KeyModifiers::ALT) => {
+                            let index = c as usize - '1' as usize;
+                            if index < self.state.views.len() {
+                                self.state.current_view = View::Saved(index);
+                            }
                         }
                         (KeyCode::Char('1'), _) => {
                             self.state.current_view = View::Issues;
@@ -120,6 +377,21 @@ impl TuiApp {
                         (KeyCode::Char('3'), _) => {
                             self.state.current_view = View::Teams;
                         }
+                        (KeyCode::Char('4'), _) => {
+                            self.state.current_view = View::Board;
+                        }
+                        (KeyCode::Char('5'), _) => {
+                            self.state.current_view = View::MyIssues;
+                        }
+                        (KeyCode::Char('6'), _) => {
+                            self.state.current_view = View::Inbox;
+                        }
+                        (KeyCode::Char('7'), _) => {
+                            self.state.current_view = View::Cycles;
+                        }
+                        (KeyCode::Char('8'), _) => {
+                            self.state.current_view = View::Workload;
+                        }
                         (KeyCode::Tab, _) => {
                             self.next_view();
                         }
@@ -128,12 +400,15 @@ impl TuiApp {
                         }
                         _ => {
                             if !self.state.show_help {
+                                self.state.status_message = None;
                                 self.handle_view_input(key_event.code).await?;
                             }
                         }
                     },
+                    // The workload fetches every open issue of the team, only `r` refreshes it
+                    AppEvent::Refresh if self.state.current_view == View::Workload => {}
                     AppEvent::Refresh => {
-                        self.refresh_current_view().await?;
+                        self.refresh().await;
                     }
                     AppEvent::Tick => {}
                     AppEvent::Quit => break,
@@ -144,22 +419,262 @@ impl TuiApp {
         Ok(())
     }
 
+    /// Resize the sorted column and save its width in the configuration file.
+    fn resize_issue_column(&mut self, delta: i16) {
+        let Some((column, width)) = self
+            .state
+            .current_issues_mut()
+            .and_then(|issues| issues.resize_column(delta))
+        else {
+            self.state.status_message =
+                Some("Sort by a column (s) other than the title to resize it".to_string());
+            return;
+        };
+
+        self.state.issues_component.widths.insert(column, width);
+        for tab in &mut self.state.views {
+            tab.issues.widths.insert(column, width);
+        }
+        for table in self.state.my_issues_component.tables_mut() {
+            table.widths.insert(column, width);
+        }
+        self.config.issues.widths.insert(column, width);
+        let key = format!("issues.widths.{}", column.key());
+        self.state.status_message = match Config::set_value(
+            self.config_path.as_deref(),
+            &key,
+            toml::Value::Integer(width.into()),
+        ) {
+            Ok(()) => Some(format!("{} width: {}", column.title(), width)),
+            Err(e) => Some(format!("Could not save the column width: {:#}", e)),
+        };
+    }
+
+    /// Resize the list pane and save its share of the screen in the configuration file.
+    fn resize_list(&mut self, delta: i16) {
+        let Some(ratio) = self
+            .state
+            .current_issues_mut()
+            .map(|issues| issues.resize_list(delta))
+        else {
+            return;
+        };
+
+        self.state.issues_component.list_ratio = ratio;
+        for tab in &mut self.state.views {
+            tab.issues.list_ratio = ratio;
+        }
+        for table in self.state.my_issues_component.tables_mut() {
+            table.list_ratio = ratio;
+        }
+        self.config.layout.list_ratio = ratio;
+        self.state.status_message = match Config::set_value(
+            self.config_path.as_deref(),
+            "layout.list_ratio",
+            toml::Value::Integer(ratio.into()),
+        ) {
+            Ok(()) => Some(format!("List width: {}%", ratio)),
+            Err(e) => Some(format!("Could not save the list width: {:#}", e)),
+        };
+    }
+
+    fn handle_issues_input(&mut self, key_code: KeyCode) {
+        let saved = self.state.current_view != View::Issues;
+        match key_code {
+            KeyCode::Char('/') if saved => {
+                self.state.status_message =
+                    Some("This view is read-only, use / in the Issues view".to_string());
+            }
+            KeyCode::Left | KeyCode::Char('h') if self.state.current_view == View::MyIssues => {
+                self.state.my_issues_component.previous_relation();
+            }
+            KeyCode::Right | KeyCode::Char('l') if self.state.current_view == View::MyIssues => {
+                self.state.my_issues_component.next_relation();
+            }
+            KeyCode::Char('/') => {
+                let query = self
+                    .state
+                    .issues_component
+                    .query
+                    .as_ref()
+                    .map(|q| q.text.clone())
+                    .unwrap_or_default();
+                self.state.prompt = Some((
+                    PromptKind::Query,
+                    PromptComponent::new("Query (empty to clear)", query)
+                        .with_validator(|input| Query::parse(input).err().map(|e| e.to_string())),
+                ));
+            }
+            KeyCode::Char('V') if !saved => {
+                self.open_prompt(PromptKind::SaveView, "Save view as", String::new());
+            }
+            KeyCode::Char('-') => {
+                self.resize_issue_column(-1);
+            }
+            KeyCode::Char('+') | KeyCode::Char('=') => {
+                self.resize_issue_column(1);
+            }
+            KeyCode::Char('F') => {
+                self.open_label_filter();
+            }
+            KeyCode::Char('E') => {
+                let path = self.default_export_path("issues");
+                self.open_prompt(PromptKind::ExportIssues, "Export issues to", path);
+            }
+            KeyCode::Char('o') => {
+                self.open_attachment();
+            }
+            KeyCode::Char('y') => {
+                self.copy_attachment_url();
+            }
+            _ => {
+                let Some(issues) = self.state.current_issues_mut() else {
+                    return;
+                };
+                match key_code {
+                    KeyCode::Down | KeyCode::Char('j') if issues.zoom == Zoom::Details => {
+                        issues.scroll_description(1);
+                    }
+                    KeyCode::Up | KeyCode::Char('k') if issues.zoom == Zoom::Details => {
+                        issues.scroll_description(-1);
+                    }
+                    KeyCode::Down | KeyCode::Char('j') => {
+                        issues.select_next();
+                    }
+                    KeyCode::Up | KeyCode::Char('k') => {
+                        issues.select_previous();
+                    }
+                    KeyCode::PageDown => {
+                        let page = issues.description_page();
+                        issues.scroll_description(page);
+                    }
+                    KeyCode::PageUp => {
+                        let page = issues.description_page();
+                        issues.scroll_description(-page);
+                    }
+                    KeyCode::Char('v') => {
+                        if let Err(e) = issues.open_selected_issue() {
+                            eprintln!("Failed to open issue in browser: {}", e);
+                        }
+                    }
+                    KeyCode::Char('d') => {
+                        issues.toggle_details();
+                    }
+                    KeyCode::Char('z') => {
+                        issues.cycle_zoom();
+                    }
+                    KeyCode::Char('|') => {
+                        issues.toggle_split();
+                    }
+                    KeyCode::Char('<') => {
+                        self.resize_list(-5);
+                    }
+                    KeyCode::Char('>') => {
+                        self.resize_list(5);
+                    }
+                    KeyCode::Char('s') => {
+                        issues.cycle_sort();
+                    }
+                    KeyCode::Char('S') => {
+                        issues.toggle_sort_order();
+                    }
+                    KeyCode::Char('g') => {
+                        issues.cycle_group_by();
+                    }
+                    KeyCode::Char(' ') => {
+                        issues.toggle_group();
+                    }
+                    KeyCode::Char('C') => {
+                        issues.toggle_all_groups();
+                    }
+                    KeyCode::Char('t') => {
+                        issues.toggle_sub_issues();
+                    }
+                    KeyCode::Char(']') => {
+                        issues.move_relation_cursor(1);
+                    }
+                    KeyCode::Char('[') => {
+                        issues.move_relation_cursor(-1);
+                    }
+                    KeyCode::Char('}') => {
+                        issues.move_attachment_cursor(1);
+                    }
+                    KeyCode::Char('H') => {
+                        issues.toggle_history();
+                    }
+                    KeyCode::Char('c') if issues.details_tab == DetailsTab::History => {
+                        issues.toggle_history_comments();
+                    }
+                    KeyCode::Char('{') => {
+                        issues.move_attachment_cursor(-1);
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
     async fn handle_view_input(&mut self, key_code: KeyCode) -> anyhow::Result<()> {
         match self.state.current_view {
-            View::Issues => match key_code {
+            View::Issues | View::MyIssues | View::Saved(_) => {
+                if !self.handle_issue_action(key_code).await {
+                    self.handle_issues_input(key_code);
+                }
+            }
+            View::Projects if self.state.projects_component.graph => match key_code {
                 KeyCode::Down | KeyCode::Char('j') => {
-                    self.state.issues_component.select_next();
+                    self.state.projects_component.scroll_graph(1);
                 }
                 KeyCode::Up | KeyCode::Char('k') => {
-                    self.state.issues_component.select_previous();
+                    self.state.projects_component.scroll_graph(-1);
                 }
-                KeyCode::Char('v') => {
-                    if let Err(e) = self.state.issues_component.open_selected_issue() {
-                        eprintln!("Failed to open issue in browser: {}", e);
+                KeyCode::PageDown => {
+                    self.state.projects_component.scroll_graph(10);
+                }
+                KeyCode::PageUp => {
+                    self.state.projects_component.scroll_graph(-10);
+                }
+                KeyCode::Char('G') | KeyCode::Esc | KeyCode::Backspace => {
+                    self.state.projects_component.toggle_graph();
+                }
+                _ => {}
+            },
+            View::Projects if self.state.projects_component.detail.is_some() => match key_code {
+                KeyCode::Char('G') => {
+                    self.state.projects_component.toggle_graph();
+                }
+                KeyCode::Enter => {
+                    self.state.projects_component.issues.zoom = Zoom::Details;
+                }
+                KeyCode::Esc | KeyCode::Backspace
+                    if self.state.projects_component.issues.zoom == Zoom::Details =>
+                {
+                    self.state.projects_component.issues.zoom = Zoom::None;
+                }
+                KeyCode::Esc | KeyCode::Backspace => {
+                    self.state.projects_component.close_project();
+                }
+                _ => {
+                    if !self.handle_issue_action(key_code).await {
+                        self.handle_issues_input(key_code);
+                    }
+                }
+            },
+            View::Inbox => self.handle_inbox_input(key_code).await,
+            View::Cycles => match key_code {
+                KeyCode::Down | KeyCode::Char('j') => {
+                    self.state.cycles_component.select_next();
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    self.state.cycles_component.select_previous();
+                }
+                KeyCode::Enter => {
+                    if let Some(cycle) = self.state.cycles_component.selected_cycle().cloned() {
+                        self.load_cycle_chart(cycle).await;
                     }
                 }
-                KeyCode::Char('d') => {
-                    self.state.issues_component.toggle_details();
+                KeyCode::Char('b') => {
+                    self.state.cycles_component.toggle_mode();
                 }
                 _ => {}
             },
@@ -170,6 +685,16 @@ impl TuiApp {
                 KeyCode::Up | KeyCode::Char('k') => {
                     self.state.projects_component.select_previous();
                 }
+                KeyCode::Enter => {
+                    if let Some(project) = self.state.projects_component.selected_project() {
+                        let id = project.id.clone();
+                        self.load_project(&id).await;
+                    }
+                }
+                KeyCode::Char('E') => {
+                    let path = self.default_export_path("projects");
+                    self.open_prompt(PromptKind::ExportProjects, "Export projects to", path);
+                }
                 _ => {}
             },
             View::Teams => match key_code {
@@ -179,98 +704,1424 @@ impl TuiApp {
                 KeyCode::Up | KeyCode::Char('k') => {
                     self.state.teams_component.select_previous();
                 }
+                KeyCode::Esc | KeyCode::Backspace
+                    if self.state.teams_component.detail.is_some() =>
+                {
+                    self.state.teams_component.close_team();
+                }
                 KeyCode::Enter => {
-                    if let Some(team) = self.state.teams_component.selected_team() {
-                        self.state.current_team = Some(team.clone());
+                    let team = match &self.state.teams_component.detail {
+                        Some(detail) => Some(detail.team.clone()),
+                        None => self.state.teams_component.selected_team().cloned(),
+                    };
+                    if let Some(team) = team {
+                        self.state.teams_component.close_team();
+                        self.state.current_team = Some(team);
                         self.state.current_view = View::Issues;
-                        self.load_team_data().await?;
+                        if let Err(e) = self.load_team_data().await {
+                            self.state.status_message =
+                                Some(format!("Could not load the team: {:#}", e));
+                        }
+                        self.reset_views();
+                    }
+                }
+                KeyCode::Char('d') if self.state.teams_component.detail.is_none() => {
+                    if let Some(id) = self
+                        .state
+                        .teams_component
+                        .selected_team()
+                        .map(|t| t.id.clone())
+                    {
+                        self.load_team(&id).await;
+                    }
+                }
+                _ => {}
+            },
+            View::Workload if self.state.workload_component.open => match key_code {
+                KeyCode::Esc | KeyCode::Backspace
+                    if self.state.workload_component.issues.zoom == Zoom::Details =>
+                {
+                    self.state.workload_component.issues.zoom = Zoom::None;
+                }
+                KeyCode::Esc | KeyCode::Backspace => {
+                    self.state.workload_component.close_assignee();
+                }
+                _ => {
+                    if !self.handle_issue_action(key_code).await {
+                        self.handle_issues_input(key_code);
                     }
                 }
+            },
+            View::Workload => match key_code {
+                KeyCode::Down | KeyCode::Char('j') => {
+                    self.state.workload_component.select_next();
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    self.state.workload_component.select_previous();
+                }
+                KeyCode::Enter => {
+                    self.state.workload_component.open_assignee();
+                }
+                KeyCode::Char('m') => {
+                    self.state.workload_component.toggle_measure();
+                }
+                _ => {}
+            },
+            View::Board => match key_code {
+                KeyCode::Down | KeyCode::Char('j') => {
+                    self.state.board_component.select_next();
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    self.state.board_component.select_previous();
+                }
+                KeyCode::Left | KeyCode::Char('h') => {
+                    self.state.board_component.previous_column();
+                }
+                KeyCode::Right | KeyCode::Char('l') => {
+                    self.state.board_component.next_column();
+                }
+                KeyCode::Char('H') => {
+                    self.move_selected_card(-1).await;
+                }
+                KeyCode::Char('L') => {
+                    self.move_selected_card(1).await;
+                }
                 _ => {}
             },
         }
         Ok(())
     }
 
-    fn next_view(&mut self) {
-        self.state.current_view = match self.state.current_view {
-            View::Issues => View::Projects,
-            View::Projects => View::Teams,
-            View::Teams => View::Issues,
-        };
-    }
+    async fn handle_inbox_input(&mut self, key_code: KeyCode) {
+        let inbox = &mut self.state.inbox_component;
+        if inbox.open {
+            match key_code {
+                KeyCode::Esc | KeyCode::Backspace => inbox.close(),
+                KeyCode::Down
+                | KeyCode::Up
+                | KeyCode::Char('j')
+                | KeyCode::Char('k')
+                | KeyCode::PageDown
+                | KeyCode::PageUp
+                | KeyCode::Char('v')
+                | KeyCode::Char('o')
+                | KeyCode::Char('y')
+                | KeyCode::Char('{')
+                | KeyCode::Char('}')
+                | KeyCode::Char('H')
+                | KeyCode::Char('c') => self.handle_issues_input(key_code),
+                _ => {}
+            }
+            return;
+        }
 
-    fn previous_view(&mut self) {
-        self.state.current_view = match self.state.current_view {
-            View::Issues => View::Teams,
-            View::Projects => View::Issues,
-            View::Teams => View::Projects,
-        };
+        match key_code {
+            KeyCode::Down | KeyCode::Char('j') => {
+                inbox.select_next();
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                inbox.select_previous();
+            }
+            KeyCode::Enter => {
+                if !inbox.open_selected() {
+                    self.state.status_message =
+                        Some("This notification is not about an issue".to_string());
+                    return;
+                }
+                if inbox.selected_notification().is_some_and(|n| n.is_unread()) {
+                    self.toggle_notification_read().await;
+                }
+            }
+            KeyCode::Char('m') => {
+                self.toggle_notification_read().await;
+            }
+            KeyCode::Char('s') if inbox.selected_notification().is_some() => {
+                self.open_prompt(
+                    PromptKind::Snooze,
+                    "Snooze for (e.g. 3h, 1d, 1w)",
+                    "1d".to_string(),
+                );
+            }
+            KeyCode::Char('a') => {
+                self.archive_notification().await;
+            }
+            _ => {}
+        }
     }
 
-    async fn load_initial_data(&mut self) -> anyhow::Result<()> {
-        self.state.loading = true;
-
-        let teams = self.client.get_teams().await?;
-        self.state.teams_component.update_teams(teams);
+    /// Mark the selected notification as read, or as unread when it was read.
+    async fn toggle_notification_read(&mut self) {
+        let Some(notification) = self.state.inbox_component.selected_notification() else {
+            return;
+        };
+        let (id, read) = (notification.id.clone(), notification.is_unread());
 
-        if let Some(team) = self.state.teams_component.teams.first() {
-            self.state.current_team = Some(team.clone());
-            self.load_team_data().await?;
+        match self.client.mark_notification_read(&id, read).await {
+            Ok(()) => {
+                if let Some(notification) = self.state.inbox_component.selected_notification_mut() {
+                    notification.read_at = read.then(chrono::Utc::now);
+                }
+            }
+            Err(e) => {
+                self.state.status_message =
+                    Some(format!("Could not update the notification: {:#}", e));
+            }
         }
-
-        self.state.loading = false;
-        Ok(())
     }
 
-    async fn load_team_data(&mut self) -> anyhow::Result<()> {
-        if let Some(ref team) = self.state.current_team {
-            let issues = self.client.get_issues(Some(&team.id), None).await?;
-            self.state.issues_component.update_issues(issues.nodes);
+    async fn archive_notification(&mut self) {
+        let Some(notification) = self.state.inbox_component.selected_notification() else {
+            return;
+        };
+        let id = notification.id.clone();
 
-            let projects = self.client.get_projects(Some(&team.id)).await?;
-            self.state.projects_component.update_projects(projects);
+        match self.client.archive_notification(&id).await {
+            Ok(()) => {
+                self.state.inbox_component.remove(&id);
+                self.state.status_message = Some("Notification archived".to_string());
+            }
+            Err(e) => {
+                self.state.status_message =
+                    Some(format!("Could not archive the notification: {:#}", e));
+            }
         }
-        Ok(())
     }
 
-    async fn refresh_current_view(&mut self) -> anyhow::Result<()> {
-        match self.state.current_view {
-            View::Teams => {
-                let teams = self.client.get_teams().await?;
-                self.state.teams_component.update_teams(teams);
+    /// Pick the labels of the selected issue among the labels of its team.
+    async fn open_label_editor(&mut self) {
+        let Some(issue) = self.state.current_issues().and_then(|c| c.selected_issue()) else {
+            return;
+        };
+        let (issue_id, identifier) = (issue.id.clone(), issue.identifier.clone());
+        let checked: Vec<String> = issue.labels.iter().map(|l| l.id.clone()).collect();
+
+        match self.client.get_labels(&issue.team.id).await {
+            Ok(labels) => {
+                let picker = LabelPickerComponent::new(
+                    format!("Labels of {}", identifier),
+                    labels,
+                    &checked,
+                );
+                self.state.label_picker = Some((
+                    PickerKind::EditLabels {
+                        issue_id,
+                        identifier,
+                    },
+                    picker,
+                ));
             }
-            _ => {
-                self.load_team_data().await?;
+            Err(e) => {
+                self.state.status_message = Some(format!("Could not load the labels: {:#}", e));
             }
         }
-        Ok(())
     }
 
-    fn render(&mut self, f: &mut Frame) {
-        if self.state.show_help {
-            HelpComponent::render(f, f.area());
-            return;
+    /// Actions on the selected issue that call the API, shared by the issue lists.
+    /// Returns false when the key is not one of them.
+    async fn handle_issue_action(&mut self, key_code: KeyCode) -> bool {
+        match key_code {
+            KeyCode::Char('L') => self.open_label_editor().await,
+            KeyCode::Char('p') => self.jump_to_parent().await,
+            KeyCode::Char('A') => self.open_sub_issue_prompt(),
+            KeyCode::Char('J') => self.jump_to_relation().await,
+            KeyCode::Char('B') => self.open_relation_prompt(),
+            KeyCode::Char('X') => self.remove_relation().await,
+            KeyCode::Char('U') => self.open_attach_prompt(),
+            _ => return false,
         }
+        true
+    }
 
-        let chunks = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([
-                Constraint::Length(3),
-                Constraint::Min(0),
-                Constraint::Length(1),
-            ])
-            .split(f.area());
-
-        // Header with tabs
-        let tab_titles = vec!["Issues", "Projects", "Teams"];
-        let selected_tab = match self.state.current_view {
-            View::Issues => 0,
-            View::Projects => 1,
-            View::Teams => 2,
+    /// Select the parent of the selected issue.
+    async fn jump_to_parent(&mut self) {
+        let Some(issue) = self.state.current_issues().and_then(|c| c.selected_issue()) else {
+            return;
         };
+        let Some(parent) = issue.parent.clone() else {
+            self.state.status_message = Some(format!("{} has no parent", issue.identifier));
+            return;
+        };
+        self.jump_to_issue(&parent.id, &parent.identifier).await;
+    }
 
-        let tabs = Tabs::new(tab_titles)
+    /// Select the issue of the relation highlighted in the details.
+    async fn jump_to_relation(&mut self) {
+        let Some(issues) = self.state.current_issues() else {
+            return;
+        };
+        let Some(link) = issues.selected_link() else {
+            self.state.status_message = Some("The issue has no relations".to_string());
+            return;
+        };
+        let (id, identifier) = (link.issue.id.clone(), link.issue.identifier.clone());
+        self.jump_to_issue(&id, &identifier).await;
+    }
+
+    /// Select an issue in the current list, loading it into the list when it is not there.
+    async fn jump_to_issue(&mut self, id: &str, identifier: &str) {
+        let Some(issues) = self.state.current_issues_mut() else {
+            return;
+        };
+        if issues.select_issue(id) {
+            return;
+        }
+        match self.client.get_issue(id).await {
+            Ok(issue) => {
+                cache::remember(None, std::slice::from_ref(&issue));
+                let Some(issues) = self.state.current_issues_mut() else {
+                    return;
+                };
+                issues.add_issue(issue);
+                if !issues.select_issue(id) {
+                    self.state.status_message =
+                        Some(format!("{} is hidden by the filter", identifier));
+                }
+            }
+            Err(e) => {
+                self.state.status_message = Some(format!("Could not load {}: {:#}", identifier, e));
+            }
+        }
+    }
+
+    fn open_relation_prompt(&mut self) {
+        let Some(issue) = self.state.current_issues().and_then(|c| c.selected_issue()) else {
+            return;
+        };
+        let title = format!(
+            "Relate {} (blocks, blocked-by, related, duplicate-of) to",
+            issue.identifier
+        );
+        let kind = PromptKind::Relation {
+            issue_id: issue.id.clone(),
+            identifier: issue.identifier.clone(),
+        };
+        self.open_prompt(kind, &title, "blocks ".to_string());
+    }
+
+    /// Remove the relation highlighted in the details.
+    async fn remove_relation(&mut self) {
+        let Some(issues) = self.state.current_issues() else {
+            return;
+        };
+        let (Some(issue), Some(link)) = (issues.selected_issue(), issues.selected_link()) else {
+            return;
+        };
+        let message = format!(
+            "Removed: {} {} {}",
+            issue.identifier,
+            link.kind.title().to_lowercase(),
+            link.issue.identifier
+        );
+        let (relation_id, ids) = (
+            link.id.to_string(),
+            [issue.id.clone(), link.issue.id.clone()],
+        );
+
+        let result = match self.client.delete_issue_relation(&relation_id).await {
+            Ok(()) => self.reload_issues(&ids).await,
+            Err(e) => Err(e),
+        };
+        self.state.status_message = Some(match result {
+            Ok(()) => message,
+            Err(e) => format!("Could not remove the relation: {:#}", e),
+        });
+    }
+
+    fn open_attachment(&mut self) {
+        let Some(issues) = self.state.current_issues() else {
+            return;
+        };
+        if let Err(e) = issues.open_selected_attachment() {
+            self.state.status_message = Some(format!("Could not open the attachment: {:#}", e));
+        }
+    }
+
+    fn copy_attachment_url(&mut self) {
+        let Some(attachment) = self
+            .state
+            .current_issues()
+            .and_then(|c| c.selected_attachment())
+        else {
+            self.state.status_message = Some("The issue has no attachments".to_string());
+            return;
+        };
+        self.state.status_message = Some(match clipboard::copy(&attachment.url) {
+            Ok(()) => format!("Copied {}", attachment.url),
+            Err(e) => format!("Could not copy the URL: {:#}", e),
+        });
+    }
+
+    fn open_attach_prompt(&mut self) {
+        let Some(issue) = self.state.current_issues().and_then(|c| c.selected_issue()) else {
+            return;
+        };
+        let title = format!("Attach a URL or a file to {}", issue.identifier);
+        let kind = PromptKind::Attach {
+            issue_id: issue.id.clone(),
+            identifier: issue.identifier.clone(),
+        };
+        self.open_prompt(kind, &title, String::new());
+    }
+
+    /// Load the details of the selected issue when they are shown. Returns whether they were
+    /// needed.
+    async fn load_details(&mut self) -> bool {
+        let Some(id) = self.state.current_issues().and_then(|c| c.details_needed()) else {
+            return false;
+        };
+        let details = match self.client.get_issue(&id).await {
+            Ok(issue) => Some(issue),
+            Err(e) => {
+                self.state.status_message = Some(format!("Could not load the details: {:#}", e));
+                None
+            }
+        };
+        if let Some(issues) = self.state.current_issues_mut() {
+            issues.set_details(id, details);
+        }
+        true
+    }
+
+    /// Load the history of the selected issue when its history tab is shown.
+    /// Returns whether it was needed.
+    async fn load_history(&mut self) -> bool {
+        let Some(id) = self.state.current_issues().and_then(|c| c.history_needed()) else {
+            return false;
+        };
+        match self.client.get_issue_history(&id).await {
+            Ok(activity) => {
+                cache::remember(None, std::slice::from_ref(&activity.issue));
+                if let Some(issues) = self.state.current_issues_mut() {
+                    issues.set_history(activity);
+                }
+            }
+            Err(e) => {
+                if let Some(issues) = self.state.current_issues_mut() {
+                    issues.details_tab = DetailsTab::Description;
+                }
+                self.state.status_message = Some(format!("Could not load the history: {:#}", e));
+            }
+        }
+        true
+    }
+
+    /// Fetch issues again after a change to their relations.
+    async fn reload_issues(&mut self, ids: &[String]) -> anyhow::Result<()> {
+        for id in ids {
+            let issue = self.client.get_issue(id).await?;
+            cache::remember(None, std::slice::from_ref(&issue));
+            self.state.replace_issue(issue);
+        }
+        Ok(())
+    }
+
+    fn open_sub_issue_prompt(&mut self) {
+        let Some(issue) = self.state.current_issues().and_then(|c| c.selected_issue()) else {
+            return;
+        };
+        let title = format!("Sub-issue of {}", issue.identifier);
+        let kind = PromptKind::SubIssue {
+            parent_id: issue.id.clone(),
+            identifier: issue.identifier.clone(),
+            team_id: issue.team.id.clone(),
+        };
+        self.open_prompt(kind, &title, String::new());
+    }
+
+    /// Pick labels to filter the issues with, among the labels of the loaded issues.
+    fn open_label_filter(&mut self) {
+        let Some(issues) = self.state.current_issues() else {
+            return;
+        };
+
+        let mut labels: Vec<IssueLabel> = Vec::new();
+        for label in issues.issues().iter().flat_map(|i| &i.labels) {
+            if !labels
+                .iter()
+                .any(|l| l.name.eq_ignore_ascii_case(&label.name))
+            {
+                labels.push(label.clone());
+            }
+        }
+        if labels.is_empty() {
+            self.state.status_message = Some("None of the issues have labels".to_string());
+            return;
+        }
+
+        let checked: Vec<String> = labels
+            .iter()
+            .filter(|l| {
+                issues
+                    .label_filter()
+                    .iter()
+                    .any(|name| name.eq_ignore_ascii_case(&l.name))
+            })
+            .map(|l| l.id.clone())
+            .collect();
+        let picker = LabelPickerComponent::new("Filter by labels", labels, &checked);
+        self.state.label_picker = Some((PickerKind::FilterLabels, picker));
+    }
+
+    async fn handle_label_picker_input(&mut self, key_code: KeyCode) {
+        let Some((kind, picker)) = self.state.label_picker.as_mut() else {
+            return;
+        };
+
+        let checked = match picker.handle_key(key_code) {
+            LabelPickerAction::None => return,
+            LabelPickerAction::Cancel => {
+                self.state.label_picker = None;
+                return;
+            }
+            LabelPickerAction::Submit(checked) => checked,
+        };
+        let kind = kind.clone();
+        let labels: Vec<IssueLabel> = picker
+            .labels()
+            .iter()
+            .filter(|l| checked.contains(&l.id))
+            .cloned()
+            .collect();
+        self.state.label_picker = None;
+
+        match kind {
+            PickerKind::FilterLabels => {
+                let names: Vec<String> = labels.into_iter().map(|l| l.name).collect();
+                self.state.status_message = Some(if names.is_empty() {
+                    "Label filter cleared".to_string()
+                } else {
+                    format!("Showing the issues labeled {}", names.join(" or "))
+                });
+                if let Some(issues) = self.state.current_issues_mut() {
+                    issues.set_label_filter(names);
+                }
+            }
+            PickerKind::EditLabels {
+                issue_id,
+                identifier,
+            } => {
+                let Some(issue) = self
+                    .state
+                    .current_issues()
+                    .and_then(|c| c.issues().iter().find(|i| i.id == issue_id))
+                else {
+                    return;
+                };
+                let current: Vec<String> = issue.labels.iter().map(|l| l.id.clone()).collect();
+                let added: Vec<String> = labels
+                    .iter()
+                    .map(|l| l.id.clone())
+                    .filter(|id| !current.contains(id))
+                    .collect();
+                let removed: Vec<String> = current
+                    .into_iter()
+                    .filter(|id| !checked.contains(id))
+                    .collect();
+                if added.is_empty() && removed.is_empty() {
+                    return;
+                }
+
+                let input = IssueUpdateInput {
+                    added_label_ids: Some(added).filter(|ids| !ids.is_empty()),
+                    removed_label_ids: Some(removed).filter(|ids| !ids.is_empty()),
+                    ..Default::default()
+                };
+                match self.client.update_issue(&issue_id, &input).await {
+                    Ok(issue) => {
+                        self.state.replace_issue(issue);
+                        self.state.status_message =
+                            Some(format!("Updated the labels of {}", identifier));
+                    }
+                    Err(e) => {
+                        self.state.status_message = Some(format!(
+                            "Could not update the labels of {}: {:#}",
+                            identifier, e
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Move the selected board card to the state `offset` columns away.
+    async fn move_selected_card(&mut self, offset: isize) {
+        let board = &self.state.board_component;
+        let (Some(issue), Some(state)) = (board.selected_issue(), board.adjacent_state(offset))
+        else {
+            return;
+        };
+
+        let input = IssueUpdateInput {
+            state_id: Some(state.id.clone()),
+            ..Default::default()
+        };
+        let (identifier, state_name) = (issue.identifier.clone(), state.name.clone());
+
+        match self.client.update_issue(&issue.id, &input).await {
+            Ok(issue) => {
+                self.state.replace_issue(issue);
+                self.state.status_message = Some(format!("Moved {} to {}", identifier, state_name));
+            }
+            Err(e) => {
+                self.state.status_message = Some(format!("Could not move {}: {:#}", identifier, e));
+            }
+        }
+    }
+
+    fn open_prompt(&mut self, kind: PromptKind, title: &str, initial: String) {
+        self.state.prompt = Some((kind, PromptComponent::new(title, initial)));
+    }
+
+    async fn handle_prompt_input(&mut self, key_code: KeyCode) -> anyhow::Result<()> {
+        let Some((kind, prompt)) = self.state.prompt.as_mut() else {
+            return Ok(());
+        };
+
+        match prompt.handle_key(key_code) {
+            PromptAction::None => {}
+            PromptAction::Cancel => {
+                self.state.prompt = None;
+            }
+            PromptAction::Submit(value) => {
+                let kind = kind.clone();
+                match self.submit_prompt(&kind, value.trim()).await {
+                    Ok(message) => {
+                        // Unless the prompt was followed by another one
+                        if self.state.prompt.as_ref().is_some_and(|(k, _)| *k == kind) {
+                            self.state.prompt = None;
+                        }
+                        if !message.is_empty() {
+                            self.state.status_message = Some(message);
+                        }
+                    }
+                    Err(e) => {
+                        if let Some((_, prompt)) = self.state.prompt.as_mut() {
+                            prompt.error = Some(format!("{:#}", e));
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Act on a submitted prompt value, returning the message to show in the status bar.
+    async fn submit_prompt(&mut self, kind: &PromptKind, value: &str) -> anyhow::Result<String> {
+        match kind {
+            PromptKind::ExportIssues | PromptKind::ExportProjects => {
+                let path = std::path::Path::new(value);
+                if ExportFormat::from_path(path).is_none() {
+                    return Err(anyhow::anyhow!("Use a .md, .csv or .json file"));
+                }
+                let (target, columns, validator): (_, _, fn(&str) -> Option<String>) =
+                    if *kind == PromptKind::ExportIssues {
+                        (
+                            ExportTarget::Issues,
+                            export::column_names(&self.config.export.issue_columns),
+                            |input| export::parse_columns::<IssueColumn>(input).err(),
+                        )
+                    } else {
+                        (
+                            ExportTarget::Projects,
+                            export::column_names(&self.config.export.project_columns),
+                            |input| export::parse_columns::<ProjectColumn>(input).err(),
+                        )
+                    };
+                let kind = PromptKind::ExportColumns {
+                    target,
+                    path: value.to_string(),
+                };
+                self.state.prompt = Some((
+                    kind,
+                    PromptComponent::new("Columns (comma separated)", columns)
+                        .with_validator(validator),
+                ));
+                Ok(String::new())
+            }
+            PromptKind::ExportColumns { target, path } => {
+                if std::path::Path::new(path).exists() {
+                    let kind = PromptKind::ExportOverwrite {
+                        target: *target,
+                        path: path.clone(),
+                        columns: value.to_string(),
+                    };
+                    let title = format!("{} exists, overwrite it? (y/n)", absolute(path));
+                    self.open_prompt(kind, &title, String::new());
+                    return Ok(String::new());
+                }
+                self.export(*target, path, value)
+            }
+            PromptKind::ExportOverwrite {
+                target,
+                path,
+                columns,
+            } => {
+                if !matches!(value.to_lowercase().as_str(), "y" | "yes") {
+                    return Ok("Export canceled".to_string());
+                }
+                self.export(*target, path, columns)
+            }
+            PromptKind::Query => {
+                let query = Query::parse(value)?;
+                if query.is_empty() {
+                    self.state.issues_component.query = None;
+                    self.load_team_data().await?;
+                    return Ok("Query cleared".to_string());
+                }
+
+                let count = self.search_issues(query).await?;
+                Ok(format!("{} issues match the query", count))
+            }
+            PromptKind::SaveView => self.save_view(value),
+            PromptKind::Snooze => {
+                let duration = parse_duration(value)
+                    .filter(|d| *d > chrono::Duration::zero())
+                    .ok_or_else(|| anyhow::anyhow!("Invalid duration '{}'", value))?;
+                let Some(notification) = self.state.inbox_component.selected_notification() else {
+                    return Ok(String::new());
+                };
+                let id = notification.id.clone();
+                let until = chrono::Utc::now() + duration;
+
+                self.client.snooze_notification(&id, until).await?;
+                self.state.inbox_component.remove(&id);
+                Ok(format!(
+                    "Snoozed until {}",
+                    until.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M")
+                ))
+            }
+            PromptKind::Attach {
+                issue_id,
+                identifier,
+            } => {
+                if value.is_empty() {
+                    return Err(anyhow::anyhow!("Enter a URL or the path of a file"));
+                }
+                if value.starts_with("http://") || value.starts_with("https://") {
+                    self.client.attach_url(issue_id, value, None).await?;
+                } else {
+                    let path = match value.strip_prefix("~/") {
+                        Some(rest) => dirs::home_dir()
+                            .context("Could not find the home directory")?
+                            .join(rest),
+                        None => std::path::PathBuf::from(value),
+                    };
+                    if !path.is_file() {
+                        return Err(anyhow::anyhow!("No file at {}", path.display()));
+                    }
+                    self.client.attach_file(issue_id, &path).await?;
+                }
+                self.reload_issues(std::slice::from_ref(issue_id)).await?;
+                Ok(format!("Attached {} to {}", value, identifier))
+            }
+            PromptKind::Relation {
+                issue_id,
+                identifier,
+            } => {
+                let (kind, other) = value
+                    .rsplit_once(char::is_whitespace)
+                    .and_then(|(kind, other)| Some((RelationKind::parse(kind.trim())?, other)))
+                    .ok_or_else(|| {
+                        anyhow::anyhow!("Expected a relation and an issue, e.g. blocks ENG-12")
+                    })?;
+                let other = self
+                    .client
+                    .get_issue(other)
+                    .await
+                    .with_context(|| format!("Could not find issue {}", other))?;
+
+                let (relation_type, inverse) = kind.relation_type();
+                let (from, to) = if inverse {
+                    (&other.id, issue_id)
+                } else {
+                    (issue_id, &other.id)
+                };
+                self.client
+                    .create_issue_relation(from, to, relation_type)
+                    .await?;
+                self.reload_issues(&[issue_id.clone(), other.id.clone()])
+                    .await?;
+                Ok(format!(
+                    "{} {} {}",
+                    identifier,
+                    kind.title().to_lowercase(),
+                    other.identifier
+                ))
+            }
+            PromptKind::SubIssue {
+                parent_id,
+                identifier,
+                team_id,
+            } => {
+                if value.is_empty() {
+                    return Err(anyhow::anyhow!("The title cannot be empty"));
+                }
+                let input = IssueCreateInput {
+                    team_id: team_id.clone(),
+                    title: value.to_string(),
+                    parent_id: Some(parent_id.clone()),
+                    ..Default::default()
+                };
+                let issue = self.client.create_issue(&input).await?;
+                cache::remember(None, std::slice::from_ref(&issue));
+                let created = issue.identifier.clone();
+
+                if let Some(issues) = self.state.current_issues_mut() {
+                    let id = issue.id.clone();
+                    issues.add_issue(issue);
+                    issues.select_issue(&id);
+                }
+                Ok(format!("Created {} under {}", created, identifier))
+            }
+        }
+    }
+
+    /// Save the query, sort and grouping of the issues view as a new tab.
+    fn save_view(&mut self, name: &str) -> anyhow::Result<String> {
+        if name.is_empty() {
+            return Err(anyhow::anyhow!("The name cannot be empty"));
+        }
+        if self.config.views.iter().any(|v| v.name == name) {
+            return Err(anyhow::anyhow!("A view named '{}' already exists", name));
+        }
+
+        let issues = &self.state.issues_component;
+        let view = SavedView {
+            name: name.to_string(),
+            query: issues
+                .query
+                .as_ref()
+                .map(|q| q.text.clone())
+                .unwrap_or_default(),
+            sort: issues.sort(),
+            order: issues.order(),
+            group_by: issues.group_by,
+        };
+
+        let mut views = self.config.views.clone();
+        views.push(view.clone());
+        Config::set_value(
+            self.config_path.as_deref(),
+            "views",
+            toml::Value::try_from(&views)?,
+        )?;
+        self.config.views = views;
+
+        // Saved views come before the Linear ones
+        let mut tab = ViewTab::new(ViewSource::Config(view), &self.config);
+        tab.issues.query = issues.query.clone();
+        tab.issues.update_issues(issues.issues().to_vec());
+        tab.loaded = true;
+        let index = self
+            .state
+            .views
+            .iter()
+            .take_while(|t| matches!(t.source, ViewSource::Config(_)))
+            .count();
+        self.state.views.insert(index, tab);
+
+        Ok(format!("Saved view '{}'", name))
+    }
+
+    /// Show the issues matching a query, in the current team unless the query names one.
+    async fn search_issues(&mut self, query: Query) -> anyhow::Result<usize> {
+        let team_id = self.state.current_team.as_ref().map(|t| t.id.as_str());
+        let issues = self
+            .client
+            .search_issues(query.filter(team_id), Some(100))
+            .await?
+            .nodes;
+        cache::remember(None, &issues);
+
+        let count = issues.len();
+        self.state.issues_component.show_query(Some(query), issues);
+        Ok(count)
+    }
+
+    /// Edit the title and description of the selected issue in $EDITOR, then send the
+    /// changed fields. Refuses to overwrite changes made on Linear in the meantime.
+    async fn edit_selected_issue(
+        &mut self,
+        terminal: &mut ratatui::Terminal<ratatui::backend::CrosstermBackend<std::io::Stdout>>,
+    ) -> anyhow::Result<()> {
+        let Some(id) = self
+            .state
+            .current_issues()
+            .and_then(|c| c.selected_issue())
+            .map(|i| i.id.clone())
+        else {
+            return Ok(());
+        };
+        // The description is not loaded with the list
+        let issue = match self.client.get_issue(&id).await {
+            Ok(issue) => issue,
+            Err(e) => {
+                self.state.status_message = Some(format!("Could not load the issue: {:#}", e));
+                return Ok(());
+            }
+        };
+
+        let original_description = issue.description.clone().unwrap_or_default();
+        let document = frontmatter::Document {
+            fields: vec![("title".to_string(), issue.title.clone())],
+            body: original_description.trim_matches('\n').to_string(),
+        };
+        let content = document.render(&[&format!(
+            "{}: edit the title and the description below, then save and quit",
+            issue.identifier
+        )]);
+
+        self.event_handler.pause().await;
+        leave_terminal()?;
+        let edited = editor::edit_text(&content, "md");
+        enter_terminal()?;
+        terminal.clear()?;
+        self.event_handler.resume();
+
+        self.state.status_message = Some(match self.apply_edit(&issue, &document, edited).await {
+            Ok(message) => message,
+            Err(e) => format!("{}: {:#}", issue.identifier, e),
+        });
+        Ok(())
+    }
+
+    async fn apply_edit(
+        &mut self,
+        issue: &Issue,
+        original: &frontmatter::Document,
+        edited: anyhow::Result<String>,
+    ) -> anyhow::Result<String> {
+        let content = edited?;
+        let document = frontmatter::Document::parse(&content)
+            .map_err(|e| keep_edit(&issue.identifier, &content, e))?;
+
+        let title = document.field("title").unwrap_or_default().trim();
+        if title.is_empty() {
+            return Err(keep_edit(
+                &issue.identifier,
+                &content,
+                anyhow::anyhow!("The title cannot be empty"),
+            ));
+        }
+
+        let input = IssueUpdateInput {
+            title: Some(title.to_string()).filter(|t| t != issue.title.trim()),
+            description: Some(document.body.clone()).filter(|d| {
+                frontmatter::normalize_newlines(d)
+                    != frontmatter::normalize_newlines(&original.body)
+            }),
+            ..Default::default()
+        };
+        if input.title.is_none() && input.description.is_none() {
+            return Ok(format!("{}: no changes", issue.identifier));
+        }
+
+        let current = self.client.get_issue(&issue.id).await?;
+        if current.updated_at != issue.updated_at {
+            return Err(keep_edit(
+                &issue.identifier,
+                &content,
+                anyhow::anyhow!(
+                    "changed on Linear in the meantime, refresh with 'r' and edit again"
+                ),
+            ));
+        }
+
+        let updated = self
+            .client
+            .update_issue(&issue.id, &input)
+            .await
+            .map_err(|e| keep_edit(&issue.identifier, &content, e))?;
+        self.state.replace_issue(updated);
+
+        let changed: Vec<&str> = [
+            input.title.as_ref().map(|_| "title"),
+            input.description.as_ref().map(|_| "description"),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        Ok(format!(
+            "{}: updated {}",
+            issue.identifier,
+            changed.join(" and ")
+        ))
+    }
+
+    /// Open the standup report popup with the viewer's activity of the last day.
+    async fn open_standup_report(&mut self) {
+        let since = chrono::Utc::now() - chrono::Duration::days(1);
+        match report::standup(&self.client, since, None).await {
+            Ok(standup) => self.state.report = Some(ReportComponent::new(standup)),
+            Err(e) => {
+                self.state.status_message = Some(format!("Could not build report: {:#}", e));
+            }
+        }
+    }
+
+    /// Write the visible issues or the projects to a file, returning the status message.
+    fn export(&self, target: ExportTarget, path: &str, columns: &str) -> anyhow::Result<String> {
+        let (count, format) = match target {
+            ExportTarget::Issues => {
+                let columns = export::parse_columns::<IssueColumn>(columns)
+                    .map_err(|e| anyhow::anyhow!(e))?;
+                let issues = self
+                    .state
+                    .current_issues()
+                    .map(|c| c.visible_issues())
+                    .unwrap_or_default();
+                let format = export::write(std::path::Path::new(path), &issues, &columns, None)?;
+                (issues.len(), format)
+            }
+            ExportTarget::Projects => {
+                let columns = export::parse_columns::<ProjectColumn>(columns)
+                    .map_err(|e| anyhow::anyhow!(e))?;
+                let projects: Vec<_> = self.state.projects_component.projects.iter().collect();
+                let format = export::write(std::path::Path::new(path), &projects, &columns, None)?;
+                (projects.len(), format)
+            }
+        };
+        let kind = match target {
+            ExportTarget::Issues => "issues",
+            ExportTarget::Projects => "projects",
+        };
+        Ok(format!(
+            "Exported {} {} to {} ({:?})",
+            count,
+            kind,
+            absolute(path),
+            format
+        ))
+    }
+
+    fn default_export_path(&self, kind: &str) -> String {
+        let team = self
+            .state
+            .current_team
+            .as_ref()
+            .map(|t| t.key.to_lowercase())
+            .unwrap_or_else(|| "linear".to_string());
+        format!(
+            "{}-{}-{}.md",
+            team,
+            kind,
+            chrono::Local::now().format("%Y-%m-%d")
+        )
+    }
+
+    fn next_view(&mut self) {
+        let tabs = self.state.tabs();
+        let index = tabs
+            .iter()
+            .position(|v| *v == self.state.current_view)
+            .unwrap_or(0);
+        self.state.current_view = tabs[(index + 1) % tabs.len()].clone();
+    }
+
+    fn previous_view(&mut self) {
+        let tabs = self.state.tabs();
+        let index = tabs
+            .iter()
+            .position(|v| *v == self.state.current_view)
+            .unwrap_or(0);
+        self.state.current_view = tabs[(index + tabs.len() - 1) % tabs.len()].clone();
+    }
+
+    /// Fetch the viewer's open issues in every team, for each relation.
+    async fn load_my_issues(&mut self) {
+        let viewer = match &self.state.my_issues_component.viewer {
+            Some(viewer) => viewer.clone(),
+            None => match self.client.get_viewer().await {
+                Ok(viewer) => {
+                    self.state.my_issues_component.viewer = Some(viewer.clone());
+                    viewer
+                }
+                Err(e) => {
+                    self.state.status_message =
+                        Some(format!("Could not load your issues: {:#}", e));
+                    return;
+                }
+            },
+        };
+
+        for relation in Relation::ALL {
+            match self
+                .client
+                .search_issues(relation.filter(&viewer.id), Some(100))
+                .await
+            {
+                Ok(issues) => {
+                    cache::remember(None, &issues.nodes);
+                    self.state
+                        .my_issues_component
+                        .update_issues(relation, issues.nodes);
+                }
+                Err(e) => {
+                    self.state.status_message = Some(format!(
+                        "Could not load your {} issues: {:#}",
+                        relation.title().to_lowercase(),
+                        e
+                    ));
+                }
+            }
+        }
+    }
+
+    /// Open the detail screen of a project.
+    async fn load_project(&mut self, id: &str) {
+        match self.client.get_project(id).await {
+            Ok(project) => {
+                cache::remember(None, &project.issues);
+                self.state.projects_component.open_project(project);
+            }
+            Err(e) => {
+                self.state.status_message = Some(format!("Could not load the project: {:#}", e));
+            }
+        }
+    }
+
+    async fn load_team(&mut self, id: &str) {
+        match self.client.get_team(id).await {
+            Ok(team) => self.state.teams_component.open_team(team),
+            Err(e) => {
+                self.state.status_message = Some(format!("Could not load the team: {:#}", e));
+            }
+        }
+    }
+
+    async fn load_team_stats(&mut self) {
+        match self.client.get_team_stats().await {
+            Ok(stats) => self.state.teams_component.update_stats(stats),
+            Err(e) => {
+                self.state.status_message = Some(format!("Could not load the team stats: {:#}", e));
+            }
+        }
+    }
+
+    /// Fetch the cycles of the current team and chart the active one.
+    async fn load_cycles(&mut self) {
+        let Some(team) = self.state.current_team.clone() else {
+            return;
+        };
+
+        match self.client.get_cycles(&team.id).await {
+            Ok(cycles) => {
+                self.state.cycles_component.update_cycles(cycles);
+                if let Some(cycle) = self.state.cycles_component.active_cycle().cloned() {
+                    self.load_cycle_chart(cycle).await;
+                }
+            }
+            Err(e) => {
+                self.state.status_message = Some(format!("Could not load the cycles: {:#}", e));
+            }
+        }
+    }
+
+    /// Fetch the members and open issues of the current team for the workload view.
+    async fn load_workload(&mut self) {
+        self.state.workload_component.loaded = true;
+        let Some(team) = self.state.current_team.clone() else {
+            return;
+        };
+
+        match self.client.get_team_workload(&team.id).await {
+            Ok(workload) => {
+                cache::remember(None, &workload.issues);
+                self.state.workload_component.update_workload(workload);
+            }
+            Err(e) => {
+                self.state.status_message = Some(format!("Could not load the workload: {:#}", e));
+            }
+        }
+    }
+
+    async fn load_cycle_chart(&mut self, cycle: Cycle) {
+        match self.client.get_cycle_issues(&cycle.id).await {
+            Ok(issues) => {
+                self.state.cycles_component.show_chart(cycle, issues);
+            }
+            Err(e) => {
+                self.state.status_message = Some(format!(
+                    "Could not load the issues of {}: {:#}",
+                    cycle.display_name(),
+                    e
+                ));
+            }
+        }
+    }
+
+    async fn load_notifications(&mut self) {
+        match self.client.get_notifications(Some(100)).await {
+            Ok(notifications) => {
+                let issues: Vec<Issue> = notifications
+                    .iter()
+                    .filter_map(|n| n.issue.clone())
+                    .collect();
+                cache::remember(None, &issues);
+                self.state
+                    .inbox_component
+                    .update_notifications(notifications);
+            }
+            Err(e) => {
+                self.state.status_message =
+                    Some(format!("Could not load the notifications: {:#}", e));
+            }
+        }
+    }
+
+    /// Create the tabs of the saved views and of the workspace's custom views. Their issues are
+    /// loaded when the tab is first shown.
+    async fn create_views(&mut self) {
+        self.state.views = self
+            .config
+            .views
+            .iter()
+            .map(|view| ViewTab::new(ViewSource::Config(view.clone()), &self.config))
+            .collect();
+
+        match self.client.get_custom_views().await {
+            Ok(views) => self.state.views.extend(
+                views
+                    .into_iter()
+                    .map(|view| ViewTab::new(ViewSource::Linear(view), &self.config)),
+            ),
+            Err(e) => {
+                self.state.status_message =
+                    Some(format!("Could not load the Linear views: {:#}", e));
+            }
+        }
+    }
+
+    /// Load the issues of the views again when they are next shown, after a change of team.
+    fn reset_views(&mut self) {
+        for tab in &mut self.state.views {
+            tab.loaded = false;
+            tab.error = None;
+        }
+    }
+
+    /// Load the shown view tab or the workload on their first visit, returns whether it was loaded.
+    async fn load_current_view(&mut self) -> bool {
+        let index = match self.state.current_view {
+            View::Workload if !self.state.workload_component.loaded => {
+                self.load_workload().await;
+                return true;
+            }
+            View::Saved(index) => index,
+            _ => return false,
+        };
+        if self
+            .state
+            .views
+            .get(index)
+            .is_none_or(|tab| tab.loaded || tab.error.is_some())
+        {
+            return false;
+        }
+        self.load_view(index).await;
+        true
+    }
+
+    /// Fetch the issues of a saved view, keeping the error in its tab.
+    async fn load_view(&mut self, index: usize) {
+        let Some(tab) = self.state.views.get(index) else {
+            return;
+        };
+        let team_id = self.state.current_team.as_ref().map(|t| t.id.as_str());
+
+        let result = match &tab.source {
+            ViewSource::Config(view) => match Query::parse(&view.query) {
+                Ok(query) => self
+                    .client
+                    .search_issues(query.filter(team_id), Some(100))
+                    .await
+                    .map(|issues| (Some(query), issues.nodes)),
+                Err(e) => Err(anyhow::anyhow!("Invalid query: {}", e)),
+            },
+            ViewSource::Linear(view) => self
+                .client
+                .get_custom_view_issues(&view.id, Some(100))
+                .await
+                .map(|issues| (None, issues.nodes)),
+        };
+
+        let tab = &mut self.state.views[index];
+        match result {
+            Ok((query, issues)) => {
+                cache::remember(None, &issues);
+                tab.issues.query = query;
+                tab.issues.update_issues(issues);
+                tab.loaded = true;
+                tab.error = None;
+            }
+            Err(e) => {
+                let name = tab.issues.name.clone().unwrap_or_default();
+                tab.error = Some(format!("{:#}", e));
+                self.state.status_message =
+                    Some(format!("Could not load the view '{}': {:#}", name, e));
+            }
+        }
+    }
+
+    async fn load_initial_data(&mut self) -> anyhow::Result<()> {
+        self.state.loading = true;
+
+        let teams = self.client.get_teams().await?;
+        cache::remember(Some(&teams), &[]);
+        self.state.teams_component.update_teams(teams);
+
+        let teams = &self.state.teams_component.teams;
+        let initial_team = self
+            .initial_team
+            .as_deref()
+            .and_then(|wanted| {
+                teams
+                    .iter()
+                    .find(|t| t.id == wanted || t.key.eq_ignore_ascii_case(wanted))
+            })
+            .or_else(|| teams.first());
+
+        if let Some(team) = initial_team {
+            self.state.current_team = Some(team.clone());
+            self.load_team_data().await?;
+        }
+
+        self.load_team_stats().await;
+        self.load_my_issues().await;
+        self.load_notifications().await;
+        self.create_views().await;
+
+        self.state.loading = false;
+        Ok(())
+    }
+
+    async fn load_team_data(&mut self) -> anyhow::Result<()> {
+        if let Some(team) = self.state.current_team.clone() {
+            let issues = self.client.get_issues(Some(&team.id), None).await?;
+            cache::remember(None, &issues.nodes);
+            self.state
+                .board_component
+                .update_issues(issues.nodes.clone());
+            match self.state.issues_component.query.clone() {
+                // Parse again to move relative dates along
+                Some(query) => {
+                    self.search_issues(Query::parse(&query.text)?).await?;
+                }
+                None => self.state.issues_component.update_issues(issues.nodes),
+            }
+
+            let states = self.client.get_workflow_states(&team.id).await?;
+            self.state.board_component.update_states(states);
+
+            let projects = self.client.get_projects(Some(&team.id)).await?;
+            self.state.projects_component.update_projects(projects);
+
+            self.load_cycles().await;
+            self.state.workload_component.reset();
+        }
+        Ok(())
+    }
+
+    /// Refresh the current view, a failure is shown in the status bar and the view keeps its
+    /// data.
+    async fn refresh(&mut self) {
+        if let Err(e) = self.refresh_current_view().await {
+            self.state.status_message = Some(format!("Could not refresh: {:#}", e));
+        }
+    }
+
+    async fn refresh_current_view(&mut self) -> anyhow::Result<()> {
+        if let Some(issues) = self.state.current_issues_mut() {
+            issues.clear_history();
+        }
+        match self.state.current_view {
+            View::Teams => {
+                let teams = self.client.get_teams().await?;
+                cache::remember(Some(&teams), &[]);
+                self.state.teams_component.update_teams(teams);
+                self.load_team_stats().await;
+                if let Some(id) = self
+                    .state
+                    .teams_component
+                    .detail
+                    .as_ref()
+                    .map(|t| t.team.id.clone())
+                {
+                    self.load_team(&id).await;
+                }
+            }
+            View::MyIssues => {
+                self.load_my_issues().await;
+            }
+            View::Inbox => {
+                self.load_notifications().await;
+            }
+            View::Cycles => {
+                self.load_cycles().await;
+            }
+            View::Workload => {
+                self.load_workload().await;
+            }
+            View::Projects => {
+                self.load_team_data().await?;
+                if let Some(project) = &self.state.projects_component.detail {
+                    let id = project.project.id.clone();
+                    self.load_project(&id).await;
+                }
+            }
+            View::Saved(index) => {
+                self.load_view(index).await;
+            }
+            _ => {
+                self.load_team_data().await?;
+            }
+        }
+        Ok(())
+    }
+
+    fn render(&mut self, f: &mut Frame) {
+        if self.state.show_help {
+            HelpComponent::render(f, f.area());
+            return;
+        }
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Length(1),
+            ])
+            .split(f.area());
+
+        // Header with tabs
+        let mut tab_titles = vec![
+            "Issues".to_string(),
+            "Projects".to_string(),
+            "Teams".to_string(),
+            "Board".to_string(),
+            "My Issues".to_string(),
+            match self.state.inbox_component.unread_count() {
+                0 => "Inbox".to_string(),
+                unread => format!("Inbox ({})", unread),
+            },
+            "Cycles".to_string(),
+            "Workload".to_string(),
+        ];
+        tab_titles.extend(self.state.views.iter().map(ViewTab::title));
+        let selected_tab = match self.state.current_view {
+            View::Issues => 0,
+            View::Projects => 1,
+            View::Teams => 2,
+            View::Board => 3,
+            View::MyIssues => 4,
+            View::Inbox => 5,
+            View::Cycles => 6,
+            View::Workload => 7,
+            View::Saved(index) => 8 + index,
+        };
+
+        let tabs = Tabs::new(tab_titles)
             .block(Block::default().borders(Borders::ALL).title(" Linear TUI "))
             .select(selected_tab)
             .style(Style::default().fg(Color::White))
@@ -289,6 +2140,26 @@ impl TuiApp {
             View::Teams => {
                 self.state.teams_component.render(f, chunks[1]);
             }
+            View::Board => {
+                self.state.board_component.render(f, chunks[1]);
+            }
+            View::MyIssues => {
+                self.state.my_issues_component.render(f, chunks[1]);
+            }
+            View::Inbox => {
+                self.state.inbox_component.render(f, chunks[1]);
+            }
+            View::Cycles => {
+                self.state.cycles_component.render(f, chunks[1]);
+            }
+            View::Workload => {
+                self.state.workload_component.render(f, chunks[1]);
+            }
+            View::Saved(index) => {
+                if let Some(tab) = self.state.views.get_mut(index) {
+                    tab.issues.render(f, chunks[1]);
+                }
+            }
         }
 
         // Status bar
@@ -299,17 +2170,73 @@ impl TuiApp {
             .map(|t| format!(" Team: {} ", t.name))
             .unwrap_or_else(|| " No team selected ".to_string());
 
-        let status_line = Line::from(vec![
-            Span::styled(team_name, Style::default().fg(Color::Cyan)),
-            Span::raw(" | "),
-            Span::styled("Press '?' for help", Style::default().fg(Color::Gray)),
-            Span::raw(" | "),
-            Span::styled("Press 'q' to quit", Style::default().fg(Color::Gray)),
-        ]);
+        let status_line = if let Some(message) = &self.state.status_message {
+            Line::from(vec![
+                Span::styled(team_name, Style::default().fg(Color::Cyan)),
+                Span::raw(" | "),
+                Span::styled(message.as_str(), Style::default().fg(Color::Yellow)),
+            ])
+        } else {
+            Line::from(vec![
+                Span::styled(team_name, Style::default().fg(Color::Cyan)),
+                Span::raw(" | "),
+                Span::styled("Press '?' for help", Style::default().fg(Color::Gray)),
+                Span::raw(" | "),
+                Span::styled("Press 'q' to quit", Style::default().fg(Color::Gray)),
+            ])
+        };
 
         let status_bar = ratatui::widgets::Paragraph::new(status_line)
             .block(Block::default().borders(Borders::TOP));
 
         f.render_widget(status_bar, chunks[2]);
+
+        if let Some(report) = &self.state.report {
+            report.render(f, f.area());
+        }
+
+        if let Some((_, picker)) = self.state.label_picker.as_mut() {
+            picker.render(f, f.area());
+        }
+
+        if let Some((_, prompt)) = &self.state.prompt {
+            prompt.render(f, f.area());
+        }
+    }
+}
+
+fn enter_terminal() -> anyhow::Result<()> {
+    crossterm::terminal::enable_raw_mode()?;
+    crossterm::execute!(
+        std::io::stdout(),
+        crossterm::terminal::EnterAlternateScreen,
+        crossterm::cursor::Hide
+    )?;
+    Ok(())
+}
+
+fn leave_terminal() -> anyhow::Result<()> {
+    crossterm::terminal::disable_raw_mode()?;
+    crossterm::execute!(
+        std::io::stdout(),
+        crossterm::terminal::LeaveAlternateScreen,
+        crossterm::cursor::Show
+    )?;
+    Ok(())
+}
+
+/// A path as shown to the user, absolute since relative ones depend on where ltui was started.
+fn absolute(path: &str) -> String {
+    std::path::absolute(path)
+        .map(|p| p.display().to_string())
+        .unwrap_or_else(|_| path.to_string())
+}
+
+/// Save edited text that could not be applied, so the changes are not lost.
+fn keep_edit(identifier: &str, content: &str, error: anyhow::Error) -> anyhow::Error {
+    let path = std::env::temp_dir().join(format!("ltui-{}.md", identifier));
+    match std::fs::write(&path, content) {
+        Ok(()) => anyhow::anyhow!("{:#} (edits saved to {})", error, path.display()),
+        Err(_) => error,
     }
 }
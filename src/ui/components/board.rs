@@ -0,0 +1,328 @@
+// SPDX-FileCopyrightText: Copyright (C) Nicolas Lamirault <nicolas.lamirault@gmail.com>
+// SPDX-License-Identifier: Apache-2.0
+
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::client::{Issue, IssueState};
+use crate::utils::state_type_rank;
+
+/// Narrowest column the board renders, more states than fit are scrolled horizontally.
+const MIN_COLUMN_WIDTH: u16 = 26;
+
+/// Issues laid out in one column per workflow state.
+pub struct BoardComponent {
+    pub states: Vec<IssueState>,
+    pub issues: Vec<Issue>,
+    pub column: usize,
+    columns: Vec<ListState>,
+}
+
+impl Default for BoardComponent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BoardComponent {
+    pub fn new() -> Self {
+        Self {
+            states: Vec::new(),
+            issues: Vec::new(),
+            column: 0,
+            columns: Vec::new(),
+        }
+    }
+
+    /// Replace the workflow states, ordered the way Linear shows them: by type, then position.
+    pub fn update_states(&mut self, mut states: Vec<IssueState>) {
+        states.sort_by(|a, b| {
+            state_type_rank(&a.state_type)
+                .cmp(&state_type_rank(&b.state_type))
+                .then(
+                    a.position
+                        .unwrap_or(0.0)
+                        .total_cmp(&b.position.unwrap_or(0.0)),
+                )
+        });
+        self.states = states;
+        self.columns = vec![ListState::default(); self.states.len()];
+        self.column = self.column.min(self.states.len().saturating_sub(1));
+        self.reset_selections();
+    }
+
+    pub fn update_issues(&mut self, issues: Vec<Issue>) {
+        self.issues = issues;
+        self.reset_selections();
+    }
+
+    /// Replace an issue after it was updated, keeping the card selected in its new column.
+    pub fn replace_issue(&mut self, issue: Issue) {
+        let id = issue.id.clone();
+        match self.issues.iter_mut().find(|i| i.id == id) {
+            Some(existing) => *existing = issue,
+            None => return,
+        }
+
+        self.reset_selections();
+        if let Some(column) = self
+            .states
+            .iter()
+            .position(|s| self.column_issues_of(&s.id).iter().any(|i| i.id == id))
+        {
+            let row = self.column_issues(column).iter().position(|i| i.id == id);
+            self.column = column;
+            self.columns[column].select(row);
+        }
+    }
+
+    /// Keep each column's selection within its cards.
+    fn reset_selections(&mut self) {
+        for column in 0..self.columns.len() {
+            let len = self.column_issues(column).len();
+            let selected = match self.columns[column].selected() {
+                Some(_) if len == 0 => None,
+                Some(i) => Some(i.min(len - 1)),
+                None if len > 0 => Some(0),
+                None => None,
+            };
+            self.columns[column].select(selected);
+        }
+    }
+
+    fn column_issues_of(&self, state_id: &str) -> Vec<&Issue> {
+        self.issues
+            .iter()
+            .filter(|i| i.state.id == state_id)
+            .collect()
+    }
+
+    pub fn column_issues(&self, column: usize) -> Vec<&Issue> {
+        match self.states.get(column) {
+            Some(state) => self.column_issues_of(&state.id),
+            None => Vec::new(),
+        }
+    }
+
+    pub fn next_column(&mut self) {
+        if self.column + 1 < self.states.len() {
+            self.column += 1;
+        }
+    }
+
+    pub fn previous_column(&mut self) {
+        self.column = self.column.saturating_sub(1);
+    }
+
+    pub fn select_next(&mut self) {
+        let len = self.column_issues(self.column).len();
+        if let Some(state) = self.columns.get_mut(self.column) {
+            if len > 0 {
+                let i = state.selected().map_or(0, |i| (i + 1) % len);
+                state.select(Some(i));
+            }
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        let len = self.column_issues(self.column).len();
+        if let Some(state) = self.columns.get_mut(self.column) {
+            if len > 0 {
+                let i = state
+                    .selected()
+                    .map_or(0, |i| if i == 0 { len - 1 } else { i - 1 });
+                state.select(Some(i));
+            }
+        }
+    }
+
+    pub fn selected_issue(&self) -> Option<&Issue> {
+        let row = self.columns.get(self.column)?.selected()?;
+        self.column_issues(self.column).get(row).copied()
+    }
+
+    /// The state next to the selected card's column, `offset` columns away.
+    pub fn adjacent_state(&self, offset: isize) -> Option<&IssueState> {
+        let column = self.column.checked_add_signed(offset)?;
+        self.states.get(column)
+    }
+
+    pub fn render(&mut self, f: &mut Frame, area: Rect) {
+        let main_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Title bar
+                Constraint::Min(8),    // Columns
+                Constraint::Length(3), // Status bar
+            ])
+            .split(area);
+
+        self.render_title_bar(f, main_chunks[0]);
+        self.render_columns(f, main_chunks[1]);
+        self.render_status_bar(f, main_chunks[2]);
+    }
+
+    fn render_title_bar(&self, f: &mut Frame, area: Rect) {
+        let title = Paragraph::new(format!(
+            "📋 Linear Board - {} issues in {} states",
+            self.issues.len(),
+            self.states.len()
+        ))
+        .style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Blue)),
+        );
+        f.render_widget(title, area);
+    }
+
+    fn render_columns(&mut self, f: &mut Frame, area: Rect) {
+        if self.states.is_empty() {
+            let empty_msg = Paragraph::new("No workflow states loaded")
+                .style(Style::default().fg(Color::Gray))
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title(" Board ")
+                        .border_style(Style::default().fg(Color::Gray)),
+                );
+            f.render_widget(empty_msg, area);
+            return;
+        }
+
+        // Show as many columns as fit, scrolled so the selected one is visible.
+        let visible = ((area.width / MIN_COLUMN_WIDTH) as usize).clamp(1, self.states.len());
+        let first = self
+            .column
+            .saturating_sub(visible - 1)
+            .min(self.states.len() - visible);
+
+        let chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Ratio(1, visible as u32); visible])
+            .split(area);
+
+        for (chunk, column) in chunks.iter().zip(first..first + visible) {
+            self.render_column(f, *chunk, column);
+        }
+    }
+
+    fn render_column(&mut self, f: &mut Frame, area: Rect, column: usize) {
+        let state = &self.states[column];
+        let is_current = column == self.column;
+        let state_color = state.color.parse::<Color>().unwrap_or(Color::Gray);
+        let width = area.width.saturating_sub(4) as usize;
+
+        let items: Vec<ListItem> = self
+            .column_issues(column)
+            .iter()
+            .map(|issue| {
+                let priority_color = match issue.priority.unwrap_or(0) {
+                    1 => Color::Red,
+                    2 => Color::Yellow,
+                    3 => Color::Green,
+                    4 => Color::Blue,
+                    _ => Color::Gray,
+                };
+                let assignee = issue
+                    .assignee
+                    .as_ref()
+                    .map(|a| a.display_name.as_str())
+                    .unwrap_or("Unassigned");
+
+                ListItem::new(vec![
+                    Line::from(vec![
+                        Span::styled("● ", Style::default().fg(priority_color)),
+                        Span::styled(
+                            issue.identifier.clone(),
+                            Style::default()
+                                .fg(Color::Cyan)
+                                .add_modifier(Modifier::BOLD),
+                        ),
+                    ]),
+                    Line::from(Span::styled(
+                        truncate_text(&issue.title, width),
+                        Style::default().fg(Color::White),
+                    )),
+                    Line::from(Span::styled(
+                        truncate_text(assignee, width),
+                        Style::default().fg(Color::Gray),
+                    )),
+                    Line::from(""),
+                ])
+            })
+            .collect();
+
+        let border_style = if is_current {
+            Style::default()
+                .fg(state_color)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        };
+
+        let highlight_style = if is_current {
+            Style::default().bg(Color::DarkGray)
+        } else {
+            Style::default()
+        };
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!(
+                        " {} ({}) ",
+                        state.name,
+                        self.column_issues(column).len()
+                    ))
+                    .title_style(Style::default().fg(state_color))
+                    .border_style(border_style),
+            )
+            .highlight_style(highlight_style);
+
+        f.render_stateful_widget(list, area, &mut self.columns[column]);
+    }
+
+    fn render_status_bar(&self, f: &mut Frame, area: Rect) {
+        let status_text = if let Some(issue) = self.selected_issue() {
+            format!(
+                "Selected: {} | h/l: Column | j/k: Card | H/L: Move card to previous/next state",
+                issue.identifier
+            )
+        } else {
+            "h/l: Column | j/k: Card | H/L: Move card".to_string()
+        };
+
+        let status = Paragraph::new(status_text)
+            .style(Style::default().fg(Color::White))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(" Status ")
+                    .border_style(Style::default().fg(Color::Gray)),
+            );
+        f.render_widget(status, area);
+    }
+}
+
+fn truncate_text(text: &str, max_width: usize) -> String {
+    if text.chars().count() <= max_width {
+        text.to_string()
+    } else if max_width <= 3 {
+        text.chars().take(max_width).collect()
+    } else {
+        let truncated: String = text.chars().take(max_width - 3).collect();
+        format!("{}...", truncated)
+    }
+}
@@ -0,0 +1,399 @@
+// SPDX-FileCopyrightText: Copyright (C) Nicolas Lamirault <nicolas.lamirault@gmail.com>
+// SPDX-License-Identifier: Apache-2.0
+
+use chrono::{DateTime, Duration, Utc};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    symbols,
+    text::{Line, Span},
+    widgets::{
+        Axis, Block, Borders, Chart, Dataset, GraphType, List, ListItem, ListState, Paragraph,
+    },
+    Frame,
+};
+
+use crate::client::{Cycle, Issue};
+use crate::ui::components::issues::fit;
+
+const NAME_WIDTH: u16 = 20;
+const BAR_WIDTH: usize = 10;
+
+/// What the chart of the cycle shows.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChartMode {
+    /// Remaining issues against the ideal line down to zero.
+    Burndown,
+    /// Completed issues up to the scope.
+    Burnup,
+}
+
+/// Daily points of a cycle chart, x is the number of days since the cycle started.
+pub struct CycleSeries {
+    pub days: f64,
+    pub scope: f64,
+    pub ideal: Vec<(f64, f64)>,
+    pub scope_line: Vec<(f64, f64)>,
+    pub remaining: Vec<(f64, f64)>,
+    pub completed: Vec<(f64, f64)>,
+}
+
+impl CycleSeries {
+    /// Build the series from the completion time of the issues, canceled issues are out of scope.
+    pub fn new(cycle: &Cycle, issues: &[Issue], now: DateTime<Utc>) -> Self {
+        let days = ((cycle.ends_at - cycle.starts_at).num_hours() as f64 / 24.0).max(1.0);
+        let scope = issues
+            .iter()
+            .filter(|i| i.state.state_type != "canceled")
+            .count() as f64;
+
+        let completed_by = |at: DateTime<Utc>| {
+            issues
+                .iter()
+                .filter(|i| i.state.state_type == "completed")
+                .filter(|i| i.completed_at.is_some_and(|done| done <= at))
+                .count() as f64
+        };
+
+        // One point per day until now (or the end of the cycle), then one at the current time
+        let until = now.min(cycle.ends_at);
+        let mut times: Vec<(f64, DateTime<Utc>)> = Vec::new();
+        if until >= cycle.starts_at {
+            let mut day = 0;
+            while cycle.starts_at + Duration::days(day) < until {
+                times.push((day as f64, cycle.starts_at + Duration::days(day)));
+                day += 1;
+            }
+            let elapsed = (until - cycle.starts_at).num_minutes() as f64 / (24.0 * 60.0);
+            times.push((elapsed, until));
+        }
+
+        let completed: Vec<(f64, f64)> =
+            times.iter().map(|(x, t)| (*x, completed_by(*t))).collect();
+        let remaining = completed
+            .iter()
+            .map(|(x, done)| (*x, scope - done))
+            .collect();
+
+        Self {
+            days,
+            scope,
+            ideal: vec![(0.0, scope), (days, 0.0)],
+            scope_line: vec![(0.0, scope), (days, scope)],
+            remaining,
+            completed,
+        }
+    }
+}
+
+pub struct CyclesComponent {
+    pub cycles: Vec<Cycle>,
+    pub state: ListState,
+    /// Cycle shown in the chart and its issues.
+    pub chart: Option<(Cycle, Vec<Issue>)>,
+    pub mode: ChartMode,
+}
+
+impl Default for CyclesComponent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CyclesComponent {
+    pub fn new() -> Self {
+        Self {
+            cycles: Vec::new(),
+            state: ListState::default(),
+            chart: None,
+            mode: ChartMode::Burndown,
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        if self.cycles.is_empty() {
+            return;
+        }
+        let i = match self.state.selected() {
+            Some(i) if i + 1 < self.cycles.len() => i + 1,
+            _ => 0,
+        };
+        self.state.select(Some(i));
+    }
+
+    pub fn select_previous(&mut self) {
+        if self.cycles.is_empty() {
+            return;
+        }
+        let i = match self.state.selected() {
+            Some(0) | None => self.cycles.len() - 1,
+            Some(i) => i - 1,
+        };
+        self.state.select(Some(i));
+    }
+
+    pub fn selected_cycle(&self) -> Option<&Cycle> {
+        self.state.selected().and_then(|i| self.cycles.get(i))
+    }
+
+    pub fn active_cycle(&self) -> Option<&Cycle> {
+        self.cycles.iter().find(|c| c.is_active)
+    }
+
+    /// Replace the cycles, selecting the current one.
+    pub fn update_cycles(&mut self, cycles: Vec<Cycle>) {
+        self.cycles = cycles;
+        let selected = self
+            .cycles
+            .iter()
+            .position(|c| c.is_active)
+            .or_else(|| self.cycles.len().checked_sub(1));
+        self.state.select(selected);
+        self.chart = None;
+    }
+
+    pub fn show_chart(&mut self, cycle: Cycle, issues: Vec<Issue>) {
+        self.chart = Some((cycle, issues));
+    }
+
+    pub fn toggle_mode(&mut self) {
+        self.mode = match self.mode {
+            ChartMode::Burndown => ChartMode::Burnup,
+            ChartMode::Burnup => ChartMode::Burndown,
+        };
+    }
+
+    pub fn render(&mut self, f: &mut Frame, area: Rect) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),      // Title bar
+                Constraint::Percentage(40), // Cycles list
+                Constraint::Min(10),        // Chart
+                Constraint::Length(3),      // Status bar
+            ])
+            .split(area);
+
+        self.render_title_bar(f, chunks[0]);
+        self.render_cycles_list(f, chunks[1]);
+        self.render_chart(f, chunks[2]);
+        self.render_status_bar(f, chunks[3]);
+    }
+
+    fn render_title_bar(&self, f: &mut Frame, area: Rect) {
+        let text = match self.active_cycle() {
+            Some(cycle) => format!(
+                "🔄 Linear Cycles - {} ends {}",
+                cycle.display_name(),
+                cycle.ends_at.format("%b %d")
+            ),
+            None => "🔄 Linear Cycles - No active cycle".to_string(),
+        };
+        let title = Paragraph::new(text)
+            .style(
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Blue)),
+            );
+        f.render_widget(title, area);
+    }
+
+    fn render_cycles_list(&mut self, f: &mut Frame, area: Rect) {
+        if self.cycles.is_empty() {
+            let empty_msg = Paragraph::new("No cycles, cycles may be disabled for this team")
+                .style(Style::default().fg(Color::Gray))
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title(" Cycles ")
+                        .border_style(Style::default().fg(Color::Gray)),
+                );
+            f.render_widget(empty_msg, area);
+            return;
+        }
+
+        let items: Vec<ListItem> = self
+            .cycles
+            .iter()
+            .map(|cycle| {
+                let (marker, color) = if cycle.is_active {
+                    ("▶ ", Color::Yellow)
+                } else if cycle.is_future {
+                    ("○ ", Color::Blue)
+                } else {
+                    ("✓ ", Color::Green)
+                };
+                let (scope, completed) = (cycle.scope(), cycle.completed());
+                // The counts come from separate histories, completed can exceed the scope
+                let ratio = if scope == 0 {
+                    0.0
+                } else {
+                    (completed as f64 / scope as f64).clamp(0.0, 1.0)
+                };
+                let filled = (ratio * BAR_WIDTH as f64).round() as usize;
+
+                ListItem::new(Line::from(vec![
+                    Span::styled(marker, Style::default().fg(color)),
+                    Span::styled(
+                        fit(&cycle.display_name(), NAME_WIDTH, Alignment::Left),
+                        Style::default().fg(Color::White),
+                    ),
+                    Span::raw(" │ "),
+                    Span::styled(
+                        fit(cycle.timing(), 8, Alignment::Left),
+                        Style::default().fg(color),
+                    ),
+                    Span::raw(" │ "),
+                    Span::styled(
+                        format!(
+                            "{} → {}",
+                            cycle.starts_at.format("%b %d"),
+                            cycle.ends_at.format("%b %d")
+                        ),
+                        Style::default().fg(Color::Gray),
+                    ),
+                    Span::raw(" │ "),
+                    Span::styled(
+                        format!("{:>3}/{:<3}", completed, scope),
+                        Style::default().fg(Color::White),
+                    ),
+                    Span::raw(" "),
+                    Span::styled("█".repeat(filled), Style::default().fg(Color::Green)),
+                    Span::styled(
+                        "░".repeat(BAR_WIDTH - filled),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                    Span::styled(
+                        format!(" {:>3.0}%", ratio * 100.0),
+                        Style::default().fg(Color::Gray),
+                    ),
+                ]))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!(" Cycles ({}) ", self.cycles.len()))
+                    .border_style(Style::default().fg(Color::Gray)),
+            )
+            .highlight_style(Style::default().bg(Color::DarkGray));
+
+        f.render_stateful_widget(list, area, &mut self.state);
+    }
+
+    fn render_chart(&self, f: &mut Frame, area: Rect) {
+        let Some((cycle, issues)) = &self.chart else {
+            let empty_msg = Paragraph::new("No cycle to chart, press Enter on a cycle")
+                .style(Style::default().fg(Color::Gray))
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title(" Chart ")
+                        .border_style(Style::default().fg(Color::Gray)),
+                );
+            f.render_widget(empty_msg, area);
+            return;
+        };
+
+        let series = CycleSeries::new(cycle, issues, Utc::now());
+        let datasets = match self.mode {
+            ChartMode::Burndown => vec![
+                Dataset::default()
+                    .name("Ideal")
+                    .marker(symbols::Marker::Braille)
+                    .graph_type(GraphType::Line)
+                    .style(Style::default().fg(Color::DarkGray))
+                    .data(&series.ideal),
+                Dataset::default()
+                    .name("Remaining")
+                    .marker(symbols::Marker::Braille)
+                    .graph_type(GraphType::Line)
+                    .style(Style::default().fg(Color::Cyan))
+                    .data(&series.remaining),
+            ],
+            ChartMode::Burnup => vec![
+                Dataset::default()
+                    .name("Scope")
+                    .marker(symbols::Marker::Braille)
+                    .graph_type(GraphType::Line)
+                    .style(Style::default().fg(Color::DarkGray))
+                    .data(&series.scope_line),
+                Dataset::default()
+                    .name("Completed")
+                    .marker(symbols::Marker::Braille)
+                    .graph_type(GraphType::Line)
+                    .style(Style::default().fg(Color::Green))
+                    .data(&series.completed),
+            ],
+        };
+
+        let middle = cycle.starts_at + (cycle.ends_at - cycle.starts_at) / 2;
+        let x_labels: Vec<Line> = [cycle.starts_at, middle, cycle.ends_at]
+            .iter()
+            .map(|date| Line::from(date.format("%b %d").to_string()))
+            .collect();
+        let y_max = series.scope.max(1.0);
+        let y_labels: Vec<Line> = [0.0, y_max / 2.0, y_max]
+            .iter()
+            .map(|count| Line::from(format!("{:.0}", count)))
+            .collect();
+
+        let (title, other) = match self.mode {
+            ChartMode::Burndown => ("Burndown", "burnup"),
+            ChartMode::Burnup => ("Burnup", "burndown"),
+        };
+        let chart = Chart::new(datasets)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!(
+                        " {} - {} ({} issues, b: {}) ",
+                        title,
+                        cycle.display_name(),
+                        series.scope,
+                        other
+                    ))
+                    .border_style(Style::default().fg(Color::Gray)),
+            )
+            .x_axis(
+                Axis::default()
+                    .style(Style::default().fg(Color::Gray))
+                    .bounds([0.0, series.days])
+                    .labels(x_labels),
+            )
+            .y_axis(
+                Axis::default()
+                    .style(Style::default().fg(Color::Gray))
+                    .bounds([0.0, y_max])
+                    .labels(y_labels),
+            );
+        f.render_widget(chart, area);
+    }
+
+    fn render_status_bar(&self, f: &mut Frame, area: Rect) {
+        let status_text = match self.selected_cycle() {
+            Some(cycle) => format!(
+                "Selected: {} - {} | Enter: chart | b: burndown/burnup | Press ? for help",
+                cycle.display_name(),
+                cycle.timing()
+            ),
+            None => "No cycle selected | Press ? for help".to_string(),
+        };
+        let status = Paragraph::new(status_text)
+            .style(Style::default().fg(Color::White))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(" Status ")
+                    .border_style(Style::default().fg(Color::Gray)),
+            );
+        f.render_widget(status, area);
+    }
+}
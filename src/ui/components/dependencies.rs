@@ -0,0 +1,321 @@
+// SPDX-FileCopyrightText: Copyright (C) Nicolas Lamirault <nicolas.lamirault@gmail.com>
+// SPDX-License-Identifier: Apache-2.0
+
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use crate::client::{Issue, RelationKind};
+
+/// An issue of the dependency graph, from the project or blocking/blocked by one of its issues.
+struct Node {
+    identifier: String,
+    title: String,
+    state: String,
+    resolved: bool,
+}
+
+/// The "blocks" relations between a set of issues.
+pub struct DependencyGraph {
+    nodes: HashMap<String, Node>,
+    /// Ids of the issues blocked by each issue.
+    blocks: BTreeMap<String, BTreeSet<String>>,
+    /// Ids of the issues blocking each issue.
+    blocked_by: BTreeMap<String, BTreeSet<String>>,
+    /// Issues of the set without any blocking relation.
+    independent: usize,
+}
+
+impl DependencyGraph {
+    pub fn new(issues: &[Issue]) -> Self {
+        let mut graph = Self {
+            nodes: HashMap::new(),
+            blocks: BTreeMap::new(),
+            blocked_by: BTreeMap::new(),
+            independent: 0,
+        };
+
+        for issue in issues {
+            graph.nodes.insert(
+                issue.id.clone(),
+                Node {
+                    identifier: issue.identifier.clone(),
+                    title: issue.title.clone(),
+                    state: issue.state.name.clone(),
+                    resolved: matches!(issue.state.state_type.as_str(), "completed" | "canceled"),
+                },
+            );
+        }
+        for issue in issues {
+            for link in issue.links() {
+                let (blocker, blocked) = match link.kind {
+                    RelationKind::Blocks => (&issue.id, &link.issue.id),
+                    RelationKind::BlockedBy => (&link.issue.id, &issue.id),
+                    _ => continue,
+                };
+                graph
+                    .nodes
+                    .entry(link.issue.id.clone())
+                    .or_insert_with(|| Node {
+                        identifier: link.issue.identifier.clone(),
+                        title: link.issue.title.clone(),
+                        state: link.issue.state.name.clone(),
+                        resolved: link.issue.is_resolved(),
+                    });
+                graph
+                    .blocks
+                    .entry(blocker.clone())
+                    .or_default()
+                    .insert(blocked.clone());
+                graph
+                    .blocked_by
+                    .entry(blocked.clone())
+                    .or_default()
+                    .insert(blocker.clone());
+            }
+        }
+        graph.independent = issues
+            .iter()
+            .filter(|i| !graph.blocks.contains_key(&i.id) && !graph.blocked_by.contains_key(&i.id))
+            .count();
+        graph
+    }
+
+    fn is_resolved(&self, id: &str) -> bool {
+        self.nodes.get(id).is_some_and(|n| n.resolved)
+    }
+
+    /// An unresolved issue waiting on an unresolved blocker.
+    fn is_blocked(&self, id: &str) -> bool {
+        !self.is_resolved(id)
+            && self
+                .blocked_by
+                .get(id)
+                .is_some_and(|blockers| blockers.iter().any(|b| !self.is_resolved(b)))
+    }
+
+    fn blocked(&self, id: &str) -> impl Iterator<Item = &String> {
+        self.blocks.get(id).into_iter().flatten()
+    }
+
+    /// Issues in identifier order, so the graph is drawn the same way on every render.
+    fn sorted(&self, ids: impl Iterator<Item = String>) -> Vec<String> {
+        let mut ids: Vec<String> = ids.collect();
+        ids.sort_by_key(|id| {
+            let identifier = self.nodes.get(id).map_or("", |n| n.identifier.as_str());
+            // ENG-9 before ENG-10
+            let (team, number) = identifier.rsplit_once('-').unwrap_or((identifier, ""));
+            (team.to_string(), number.parse::<u64>().unwrap_or(0))
+        });
+        ids
+    }
+
+    /// The longest chain of unresolved issues each blocking the next one.
+    pub fn longest_unresolved_chain(&self) -> Vec<String> {
+        fn longest(
+            graph: &DependencyGraph,
+            id: &str,
+            memo: &mut HashMap<String, Vec<String>>,
+            visiting: &mut HashSet<String>,
+        ) -> Vec<String> {
+            if let Some(chain) = memo.get(id) {
+                return chain.clone();
+            }
+            // A relation cycle, the chain stops here
+            if !visiting.insert(id.to_string()) {
+                return Vec::new();
+            }
+            let mut best = Vec::new();
+            for next in graph.sorted(graph.blocked(id).cloned()) {
+                if graph.is_resolved(&next) {
+                    continue;
+                }
+                let chain = longest(graph, &next, memo, visiting);
+                if chain.len() > best.len() {
+                    best = chain;
+                }
+            }
+            visiting.remove(id);
+            best.insert(0, id.to_string());
+            memo.insert(id.to_string(), best.clone());
+            best
+        }
+
+        let mut memo = HashMap::new();
+        let mut best = Vec::new();
+        for id in self.sorted(self.blocks.keys().cloned()) {
+            if self.is_resolved(&id) {
+                continue;
+            }
+            let chain = longest(self, &id, &mut memo, &mut HashSet::new());
+            if chain.len() > best.len() {
+                best = chain;
+            }
+        }
+        // A single issue is not a chain
+        if best.len() < 2 {
+            best.clear();
+        }
+        best
+    }
+
+    /// The graph as a tree of blockers, each issue followed by the issues it blocks.
+    pub fn lines(&self) -> Vec<Line<'static>> {
+        let chain = self.longest_unresolved_chain();
+        let blocked = self.nodes.keys().filter(|id| self.is_blocked(id)).count();
+
+        let mut lines = vec![
+            if chain.is_empty() {
+                Line::from(Span::styled(
+                    "No unresolved dependency chain",
+                    Style::default().fg(Color::Green),
+                ))
+            } else {
+                let identifiers: Vec<&str> = chain
+                    .iter()
+                    .filter_map(|id| self.nodes.get(id))
+                    .map(|n| n.identifier.as_str())
+                    .collect();
+                Line::from(vec![
+                    Span::styled(
+                        format!("★ Longest unresolved chain ({}): ", chain.len()),
+                        Style::default()
+                            .fg(Color::Magenta)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(identifiers.join(" → "), Style::default().fg(Color::White)),
+                ])
+            },
+            Line::from(vec![
+                Span::styled(
+                    format!("{} blocked", blocked),
+                    Style::default().fg(if blocked > 0 { Color::Red } else { Color::Gray }),
+                ),
+                Span::styled(
+                    format!(" | Without dependencies: {}", self.independent),
+                    Style::default().fg(Color::Gray),
+                ),
+            ]),
+            Line::from(""),
+        ];
+
+        if self.blocks.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "No blocking relations between these issues",
+                Style::default().fg(Color::Gray),
+            )));
+            return lines;
+        }
+
+        let on_chain: HashSet<&str> = chain.iter().map(String::as_str).collect();
+        let mut drawn = HashSet::new();
+        // Issues nothing blocks first, then what is left in relation cycles
+        let ids = self.sorted(self.blocks.keys().cloned());
+        let roots = ids.iter().filter(|id| !self.blocked_by.contains_key(*id));
+        for id in roots.chain(ids.iter()) {
+            if !drawn.contains(id.as_str()) {
+                self.draw(id, "", None, &on_chain, &mut drawn, &mut lines);
+            }
+        }
+        lines
+    }
+
+    /// Draw an issue and, the first time it is drawn, the issues it blocks below it.
+    /// `edge` is the connector from the blocker and whether the blocker is unresolved.
+    fn draw(
+        &self,
+        id: &str,
+        prefix: &str,
+        edge: Option<(&str, bool)>,
+        on_chain: &HashSet<&str>,
+        drawn: &mut HashSet<String>,
+        lines: &mut Vec<Line<'static>>,
+    ) {
+        let Some(node) = self.nodes.get(id) else {
+            return;
+        };
+        let blocked = self.is_blocked(id);
+        let again = drawn.contains(id);
+
+        let mut spans = vec![Span::styled(
+            prefix.to_string(),
+            Style::default().fg(Color::DarkGray),
+        )];
+        if let Some((connector, active)) = edge {
+            let color = if active && !node.resolved {
+                Color::Red
+            } else {
+                Color::DarkGray
+            };
+            spans.push(Span::styled(
+                connector.to_string(),
+                Style::default().fg(color),
+            ));
+        }
+        if on_chain.contains(id) {
+            spans.push(Span::styled("★ ", Style::default().fg(Color::Magenta)));
+        }
+        let identifier_color = if node.resolved {
+            Color::Green
+        } else if blocked {
+            Color::Red
+        } else {
+            Color::Cyan
+        };
+        spans.push(Span::styled(
+            format!("{} ", node.identifier),
+            Style::default()
+                .fg(identifier_color)
+                .add_modifier(Modifier::BOLD),
+        ));
+        if again {
+            spans.push(Span::styled(
+                "↑ see above",
+                Style::default().fg(Color::DarkGray),
+            ));
+            lines.push(Line::from(spans));
+            return;
+        }
+        spans.push(Span::styled(
+            node.title.clone(),
+            Style::default().fg(if node.resolved {
+                Color::Gray
+            } else {
+                Color::White
+            }),
+        ));
+        spans.push(Span::styled(
+            format!(" [{}]", node.state),
+            Style::default().fg(Color::Gray),
+        ));
+        if blocked {
+            spans.push(Span::styled(" ⛔ blocked", Style::default().fg(Color::Red)));
+        }
+        lines.push(Line::from(spans));
+        drawn.insert(id.to_string());
+
+        let children = self.sorted(self.blocked(id).cloned());
+        let child_prefix = match edge {
+            Some(("└─▶ ", _)) => format!("{}    ", prefix),
+            Some(_) => format!("{}│   ", prefix),
+            None => prefix.to_string(),
+        };
+        for (n, child) in children.iter().enumerate() {
+            let connector = if n + 1 == children.len() {
+                "└─▶ "
+            } else {
+                "├─▶ "
+            };
+            self.draw(
+                child,
+                &child_prefix,
+                Some((connector, !node.resolved)),
+                on_chain,
+                drawn,
+                lines,
+            );
+        }
+    }
+}
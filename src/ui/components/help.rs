@@ -50,13 +50,105 @@ impl HelpComponent {
             Line::from(vec![
                 Span::raw("  "),
                 Span::styled("d", Style::default().fg(Color::Cyan)),
-                Span::raw("             Toggle issue details view (in issues view)"),
+                Span::raw("             Show/hide the details pane (in issues view)"),
+            ]),
+            Line::from(vec![
+                Span::raw("  "),
+                Span::styled("z", Style::default().fg(Color::Cyan)),
+                Span::raw("             Zoom list/details to full screen (in issues view)"),
+            ]),
+            Line::from(vec![
+                Span::raw("  "),
+                Span::styled("PgUp/PgDn", Style::default().fg(Color::Cyan)),
+                Span::raw("     Scroll the description (j/k when details are zoomed)"),
+            ]),
+            Line::from(vec![
+                Span::raw("  "),
+                Span::styled("</>, |", Style::default().fg(Color::Cyan)),
+                Span::raw("        Resize panes, switch side-by-side/stacked (in issues view)"),
+            ]),
+            Line::from(vec![
+                Span::raw("  "),
+                Span::styled("s/S", Style::default().fg(Color::Cyan)),
+                Span::raw("           Sort by the next column, reverse the order (in issues view)"),
+            ]),
+            Line::from(vec![
+                Span::raw("  "),
+                Span::styled("/", Style::default().fg(Color::Cyan)),
+                Span::raw(
+                    "             Search issues with a query, e.g. state:started assignee:me",
+                ),
+            ]),
+            Line::from(vec![
+                Span::raw("  "),
+                Span::styled("V", Style::default().fg(Color::Cyan)),
+                Span::raw("             Save the query, sort and grouping as a view tab"),
+            ]),
+            Line::from(vec![
+                Span::raw("  "),
+                Span::styled("g", Style::default().fg(Color::Cyan)),
+                Span::raw("             Group by state/assignee/priority/project (in issues view)"),
+            ]),
+            Line::from(vec![
+                Span::raw("  "),
+                Span::styled("Space, C", Style::default().fg(Color::Cyan)),
+                Span::raw("      Collapse/expand the group, all groups (in issues view)"),
+            ]),
+            Line::from(vec![
+                Span::raw("  "),
+                Span::styled("L", Style::default().fg(Color::Cyan)),
+                Span::raw("             Add/remove labels of the selected issue (in issues view)"),
+            ]),
+            Line::from(vec![
+                Span::raw("  "),
+                Span::styled("F", Style::default().fg(Color::Cyan)),
+                Span::raw("             Filter the issues by labels (in issues view)"),
+            ]),
+            Line::from(vec![
+                Span::raw("  "),
+                Span::styled("t, p, A", Style::default().fg(Color::Cyan)),
+                Span::raw("       Fold sub-issues, go to the parent, create a sub-issue"),
+            ]),
+            Line::from(vec![
+                Span::raw("  "),
+                Span::styled("[/], J, B, X", Style::default().fg(Color::Cyan)),
+                Span::raw("  Select a relation, go to its issue, add/remove a relation"),
+            ]),
+            Line::from(vec![
+                Span::raw("  "),
+                Span::styled("{/}, o, y, U", Style::default().fg(Color::Cyan)),
+                Span::raw("  Select an attachment, open it, copy its URL, attach a URL/file"),
+            ]),
+            Line::from(vec![
+                Span::raw("  "),
+                Span::styled("H, c", Style::default().fg(Color::Cyan)),
+                Span::raw("          History tab of the details, show/hide its comments"),
+            ]),
+            Line::from(vec![
+                Span::raw("  "),
+                Span::styled("-/+", Style::default().fg(Color::Cyan)),
+                Span::raw("           Narrow/widen the sorted column (in issues view)"),
+            ]),
+            Line::from(vec![
+                Span::raw("  "),
+                Span::styled("e", Style::default().fg(Color::Cyan)),
+                Span::raw("             Edit title and description in $EDITOR (in issues view)"),
             ]),
             Line::from(vec![
                 Span::raw("  "),
                 Span::styled("n", Style::default().fg(Color::Cyan)),
                 Span::raw("             Create new issue (in issues view)"),
             ]),
+            Line::from(vec![
+                Span::raw("  "),
+                Span::styled("E", Style::default().fg(Color::Cyan)),
+                Span::raw("             Export visible issues/projects to .md, .csv or .json, pick columns"),
+            ]),
+            Line::from(vec![
+                Span::raw("  "),
+                Span::styled("R", Style::default().fg(Color::Cyan)),
+                Span::raw("             Standup report of the last day"),
+            ]),
             Line::from(""),
             Line::from(vec![Span::styled(
                 "Views:",
@@ -70,12 +162,50 @@ impl HelpComponent {
             Line::from(vec![
                 Span::raw("  "),
                 Span::styled("2", Style::default().fg(Color::Cyan)),
-                Span::raw("             Projects view"),
+                Span::raw("             Projects view (Enter: project details and issues, G: dependency graph, Esc: back)"),
             ]),
             Line::from(vec![
                 Span::raw("  "),
                 Span::styled("3", Style::default().fg(Color::Cyan)),
-                Span::raw("             Teams view"),
+                Span::raw("             Teams view (d: team details, Esc: back)"),
+            ]),
+            Line::from(vec![
+                Span::raw("  "),
+                Span::styled("4", Style::default().fg(Color::Cyan)),
+                Span::raw(
+                    "             Board view (h/l: column, H/L: move card to previous/next state)",
+                ),
+            ]),
+            Line::from(vec![
+                Span::raw("  "),
+                Span::styled("5", Style::default().fg(Color::Cyan)),
+                Span::raw(
+                    "             My Issues in every team (h/l: assigned/created/subscribed)",
+                ),
+            ]),
+            Line::from(vec![
+                Span::raw("  "),
+                Span::styled("6", Style::default().fg(Color::Cyan)),
+                Span::raw(
+                    "             Inbox (Enter: open issue, Esc: back, m: read/unread, s: snooze, a: archive)",
+                ),
+            ]),
+            Line::from(vec![
+                Span::raw("  "),
+                Span::styled("7", Style::default().fg(Color::Cyan)),
+                Span::raw("             Cycles (Enter: chart the cycle, b: burndown/burnup)"),
+            ]),
+            Line::from(vec![
+                Span::raw("  "),
+                Span::styled("8", Style::default().fg(Color::Cyan)),
+                Span::raw(
+                    "             Workload (Enter: the member's issues, Esc: back, m: issues/estimate)",
+                ),
+            ]),
+            Line::from(vec![
+                Span::raw("  "),
+                Span::styled("Alt+1..9", Style::default().fg(Color::Cyan)),
+                Span::raw("      Saved views, then the workspace's Linear views (◆)"),
             ]),
             Line::from(""),
             Line::from(vec![Span::styled(
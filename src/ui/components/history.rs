@@ -0,0 +1,217 @@
+// SPDX-FileCopyrightText: Copyright (C) Nicolas Lamirault <nicolas.lamirault@gmail.com>
+// SPDX-License-Identifier: Apache-2.0
+
+use chrono::{DateTime, Utc};
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+};
+
+use crate::client::{Comment, IssueActivity, IssueHistory, User};
+use crate::ui::markdown;
+use crate::utils::{format_duration_since, priority_name};
+
+/// An entry of the timeline, in chronological order.
+enum Entry<'a> {
+    Change(&'a IssueHistory),
+    Comment(&'a Comment),
+}
+
+impl Entry<'_> {
+    fn created_at(&self) -> DateTime<Utc> {
+        match self {
+            Entry::Change(change) => change.created_at,
+            Entry::Comment(comment) => comment.created_at,
+        }
+    }
+}
+
+/// The history of an issue from its creation, oldest first, with its comments when `comments`.
+pub fn timeline(activity: &IssueActivity, comments: bool) -> Vec<Line<'static>> {
+    let issue = &activity.issue;
+    let mut lines = vec![entry_header(
+        "✚",
+        Color::Green,
+        Some(&issue.creator),
+        "created the issue",
+        &issue.created_at,
+    )];
+
+    let mut entries: Vec<Entry> = activity
+        .history
+        .iter()
+        .filter(|h| !changes(h).is_empty())
+        .map(Entry::Change)
+        .collect();
+    if comments {
+        entries.extend(activity.comments.iter().map(Entry::Comment));
+    }
+    entries.sort_by_key(|e| e.created_at());
+
+    for entry in entries {
+        lines.push(Line::from(""));
+        match entry {
+            Entry::Change(change) => {
+                lines.push(entry_header(
+                    "●",
+                    Color::Yellow,
+                    change.actor.as_ref(),
+                    "changed",
+                    &change.created_at,
+                ));
+                for (field, value) in changes(change) {
+                    lines.push(Line::from(vec![
+                        Span::raw("    "),
+                        Span::styled(format!("{}: ", field), Style::default().fg(Color::Gray)),
+                        Span::styled(value, Style::default().fg(Color::White)),
+                    ]));
+                }
+            }
+            Entry::Comment(comment) => {
+                lines.push(entry_header(
+                    "💬",
+                    Color::Cyan,
+                    comment.user.as_ref(),
+                    "commented",
+                    &comment.created_at,
+                ));
+                for line in markdown::render(&comment.body) {
+                    let mut spans = vec![Span::raw("    ")];
+                    spans.extend(line.spans);
+                    lines.push(Line::from(spans));
+                }
+            }
+        }
+    }
+    lines
+}
+
+fn entry_header(
+    symbol: &str,
+    color: Color,
+    actor: Option<&User>,
+    action: &str,
+    at: &DateTime<Utc>,
+) -> Line<'static> {
+    let actor = actor
+        .map(|a| a.display_name.clone())
+        .unwrap_or_else(|| "Linear".to_string());
+    Line::from(vec![
+        Span::styled(format!("{} ", symbol), Style::default().fg(color)),
+        Span::styled(
+            actor,
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(format!(" {} ", action), Style::default().fg(Color::Gray)),
+        Span::styled(
+            format!("· {}", format_duration_since(at)),
+            Style::default().fg(Color::DarkGray),
+        ),
+    ])
+}
+
+/// Changed fields of a history entry, with their old and new values.
+fn changes(change: &IssueHistory) -> Vec<(&'static str, String)> {
+    fn transition(from: Option<String>, to: Option<String>, none: &str) -> String {
+        format!(
+            "{} → {}",
+            from.unwrap_or_else(|| none.to_string()),
+            to.unwrap_or_else(|| none.to_string())
+        )
+    }
+
+    let mut changes = Vec::new();
+    if change.from_state.is_some() || change.to_state.is_some() {
+        changes.push((
+            "State",
+            transition(
+                change.from_state.as_ref().map(|s| s.name.clone()),
+                change.to_state.as_ref().map(|s| s.name.clone()),
+                "None",
+            ),
+        ));
+    }
+    if change.from_priority.is_some() || change.to_priority.is_some() {
+        let name = |p: Option<f64>| p.map(|p| priority_name(p as i32).to_string());
+        changes.push((
+            "Priority",
+            transition(name(change.from_priority), name(change.to_priority), "None"),
+        ));
+    }
+    if change.from_assignee.is_some() || change.to_assignee.is_some() {
+        changes.push((
+            "Assignee",
+            transition(
+                change
+                    .from_assignee
+                    .as_ref()
+                    .map(|u| u.display_name.clone()),
+                change.to_assignee.as_ref().map(|u| u.display_name.clone()),
+                "Unassigned",
+            ),
+        ));
+    }
+    if let (Some(from), Some(to)) = (&change.from_title, &change.to_title) {
+        changes.push(("Title", format!("{} → {}", from, to)));
+    }
+    if change.from_estimate.is_some() || change.to_estimate.is_some() {
+        changes.push((
+            "Estimate",
+            transition(
+                change.from_estimate.map(|e| e.to_string()),
+                change.to_estimate.map(|e| e.to_string()),
+                "None",
+            ),
+        ));
+    }
+    if change.from_due_date.is_some() || change.to_due_date.is_some() {
+        changes.push((
+            "Due date",
+            transition(
+                change.from_due_date.map(|d| d.to_string()),
+                change.to_due_date.map(|d| d.to_string()),
+                "None",
+            ),
+        ));
+    }
+    if change.from_project.is_some() || change.to_project.is_some() {
+        changes.push((
+            "Project",
+            transition(
+                change.from_project.as_ref().map(|p| p.name.clone()),
+                change.to_project.as_ref().map(|p| p.name.clone()),
+                "None",
+            ),
+        ));
+    }
+    if change.from_cycle.is_some() || change.to_cycle.is_some() {
+        changes.push((
+            "Cycle",
+            transition(
+                change.from_cycle.as_ref().map(|c| c.display_name()),
+                change.to_cycle.as_ref().map(|c| c.display_name()),
+                "None",
+            ),
+        ));
+    }
+    let added = change
+        .added_labels
+        .iter()
+        .flatten()
+        .map(|l| format!("+{}", l.name));
+    let removed = change
+        .removed_labels
+        .iter()
+        .flatten()
+        .map(|l| format!("-{}", l.name));
+    let labels: Vec<String> = added.chain(removed).collect();
+    if !labels.is_empty() {
+        changes.push(("Labels", labels.join(" ")));
+    }
+    if change.updated_description == Some(true) {
+        changes.push(("Description", "updated".to_string()));
+    }
+    changes
+}
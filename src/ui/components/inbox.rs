@@ -0,0 +1,293 @@
+// SPDX-FileCopyrightText: Copyright (C) Nicolas Lamirault <nicolas.lamirault@gmail.com>
+// SPDX-License-Identifier: Apache-2.0
+
+use chrono::Utc;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::client::{Issue, Notification};
+use crate::config::Config;
+use crate::ui::components::issues::fit;
+use crate::ui::components::{IssuesComponent, Zoom};
+use crate::utils::format_duration_since;
+
+const TYPE_WIDTH: u16 = 20;
+const IDENTIFIER_WIDTH: u16 = 10;
+const ACTOR_WIDTH: u16 = 18;
+const AGE_WIDTH: u16 = 8;
+
+/// The viewer's notifications, with the referenced issue opened full screen on Enter.
+pub struct InboxComponent {
+    pub notifications: Vec<Notification>,
+    pub state: ListState,
+    /// Details of the issue of the opened notification.
+    pub issue: IssuesComponent,
+    pub open: bool,
+}
+
+impl InboxComponent {
+    pub fn with_config(config: &Config) -> Self {
+        let mut issue = IssuesComponent::with_config(config);
+        issue.zoom = Zoom::Details;
+        Self {
+            notifications: Vec::new(),
+            state: ListState::default(),
+            issue,
+            open: false,
+        }
+    }
+
+    pub fn unread_count(&self) -> usize {
+        self.notifications.iter().filter(|n| n.is_unread()).count()
+    }
+
+    pub fn select_next(&mut self) {
+        if self.notifications.is_empty() {
+            return;
+        }
+        let i = match self.state.selected() {
+            Some(i) if i + 1 < self.notifications.len() => i + 1,
+            _ => 0,
+        };
+        self.state.select(Some(i));
+    }
+
+    pub fn select_previous(&mut self) {
+        if self.notifications.is_empty() {
+            return;
+        }
+        let i = match self.state.selected() {
+            Some(0) | None => self.notifications.len() - 1,
+            Some(i) => i - 1,
+        };
+        self.state.select(Some(i));
+    }
+
+    pub fn selected_notification(&self) -> Option<&Notification> {
+        self.state
+            .selected()
+            .and_then(|i| self.notifications.get(i))
+    }
+
+    pub fn selected_notification_mut(&mut self) -> Option<&mut Notification> {
+        self.state
+            .selected()
+            .and_then(|i| self.notifications.get_mut(i))
+    }
+
+    /// Snoozed notifications are left out, they come back on refresh once the snooze is over.
+    pub fn update_notifications(&mut self, notifications: Vec<Notification>) {
+        let now = Utc::now();
+        self.notifications = notifications
+            .into_iter()
+            .filter(|n| !n.is_snoozed(now))
+            .collect();
+        self.clamp_selection();
+    }
+
+    /// Remove a notification after it was archived or snoozed.
+    pub fn remove(&mut self, id: &str) {
+        self.notifications.retain(|n| n.id != id);
+        self.clamp_selection();
+    }
+
+    fn clamp_selection(&mut self) {
+        let selected = match self.state.selected() {
+            _ if self.notifications.is_empty() => None,
+            Some(i) => Some(i.min(self.notifications.len() - 1)),
+            None => Some(0),
+        };
+        self.state.select(selected);
+    }
+
+    /// Show the issue of the selected notification, returns false when it has none.
+    pub fn open_selected(&mut self) -> bool {
+        let Some(issue) = self.selected_notification().and_then(|n| n.issue.clone()) else {
+            return false;
+        };
+        self.issue.show_query(None, vec![issue]);
+        self.open = true;
+        true
+    }
+
+    pub fn close(&mut self) {
+        self.open = false;
+    }
+
+    /// Replace an issue after it was updated, in the notifications and the opened details.
+    pub fn replace_issue(&mut self, issue: Issue) {
+        for notification in &mut self.notifications {
+            if notification
+                .issue
+                .as_ref()
+                .is_some_and(|i| i.id == issue.id)
+            {
+                notification.issue = Some(issue.clone());
+            }
+        }
+        self.issue.replace_issue(issue);
+    }
+
+    pub fn render(&mut self, f: &mut Frame, area: Rect) {
+        if self.open {
+            self.issue.render(f, area);
+            return;
+        }
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Title bar
+                Constraint::Min(5),    // Notifications list
+                Constraint::Length(6), // Preview of the selected notification
+                Constraint::Length(3), // Status bar
+            ])
+            .split(area);
+
+        self.render_title_bar(f, chunks[0]);
+        self.render_notifications_list(f, chunks[1]);
+        self.render_preview(f, chunks[2]);
+        self.render_status_bar(f, chunks[3]);
+    }
+
+    fn render_title_bar(&self, f: &mut Frame, area: Rect) {
+        let title = Paragraph::new(format!("📥 Inbox - {} unread", self.unread_count()))
+            .style(
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Blue)),
+            );
+        f.render_widget(title, area);
+    }
+
+    fn render_notifications_list(&mut self, f: &mut Frame, area: Rect) {
+        if self.notifications.is_empty() {
+            let empty_msg = Paragraph::new("Inbox zero, no notifications")
+                .style(Style::default().fg(Color::Gray))
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title(" Notifications ")
+                        .border_style(Style::default().fg(Color::Gray)),
+                );
+            f.render_widget(empty_msg, area);
+            return;
+        }
+
+        // Borders, unread marker and the separators between the columns
+        let fixed = 2 + 2 + TYPE_WIDTH + IDENTIFIER_WIDTH + ACTOR_WIDTH + AGE_WIDTH + 4;
+        let title_width = area.width.saturating_sub(fixed).max(10);
+
+        let items: Vec<ListItem> = self
+            .notifications
+            .iter()
+            .map(|notification| {
+                let unread = notification.is_unread();
+                let marker = if unread { "● " } else { "  " };
+                let text_style = if unread {
+                    Style::default()
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Color::Gray)
+                };
+                let (identifier, title) = notification
+                    .issue
+                    .as_ref()
+                    .map(|i| (i.identifier.as_str(), i.title.as_str()))
+                    .unwrap_or(("", ""));
+                let actor = notification
+                    .actor
+                    .as_ref()
+                    .map(|a| a.display_name.as_str())
+                    .unwrap_or("Linear");
+
+                ListItem::new(Line::from(vec![
+                    Span::styled(marker, Style::default().fg(Color::Cyan)),
+                    Span::styled(
+                        fit(&notification.describe(), TYPE_WIDTH, Alignment::Left),
+                        Style::default().fg(Color::Yellow),
+                    ),
+                    Span::raw(" "),
+                    Span::styled(
+                        fit(identifier, IDENTIFIER_WIDTH, Alignment::Left),
+                        Style::default().fg(Color::Cyan),
+                    ),
+                    Span::raw(" "),
+                    Span::styled(fit(title, title_width, Alignment::Left), text_style),
+                    Span::raw(" "),
+                    Span::styled(
+                        fit(actor, ACTOR_WIDTH, Alignment::Left),
+                        Style::default().fg(Color::Gray),
+                    ),
+                    Span::raw(" "),
+                    Span::styled(
+                        fit(
+                            &format_duration_since(&notification.created_at),
+                            AGE_WIDTH,
+                            Alignment::Right,
+                        ),
+                        Style::default().fg(Color::Gray),
+                    ),
+                ]))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!(" Notifications ({}) ", self.notifications.len()))
+                    .border_style(Style::default().fg(Color::Gray)),
+            )
+            .highlight_style(Style::default().bg(Color::DarkGray));
+
+        f.render_stateful_widget(list, area, &mut self.state);
+    }
+
+    fn render_preview(&self, f: &mut Frame, area: Rect) {
+        let text = match self.selected_notification() {
+            Some(notification) => match (&notification.comment, &notification.issue) {
+                (Some(comment), _) => comment.body.clone(),
+                (None, Some(issue)) => format!("{} - {}", issue.state.name, issue.title),
+                (None, None) => notification.describe(),
+            },
+            None => String::new(),
+        };
+
+        let preview = Paragraph::new(text)
+            .style(Style::default().fg(Color::White))
+            .wrap(Wrap { trim: true })
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(" Preview ")
+                    .border_style(Style::default().fg(Color::Gray)),
+            );
+        f.render_widget(preview, area);
+    }
+
+    fn render_status_bar(&self, f: &mut Frame, area: Rect) {
+        let status = Paragraph::new(
+            "Enter: open issue | m: read/unread | s: snooze | a: archive | Press ? for help",
+        )
+        .style(Style::default().fg(Color::White))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" Status ")
+                .border_style(Style::default().fg(Color::Gray)),
+        );
+        f.render_widget(status, area);
+    }
+}
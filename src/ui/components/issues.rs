@@ -2,36 +2,619 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use ratatui::{
-    layout::{Constraint, Direction, Layout},
+    layout::{Alignment, Constraint, Direction, Layout},
     style::{Color, Modifier, Style},
     text::{Line, Span, Text},
-    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
     Frame,
 };
+use std::cell::{Cell, Ref, RefCell};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use unicode_width::UnicodeWidthChar;
 
-use crate::client::Issue;
+use crate::client::{completion, Attachment, Issue, IssueActivity, IssueLink, RelationKind};
+use crate::config::{Config, SplitDirection};
+use crate::query::Query;
+use crate::ui::components::{label_chips, timeline};
+use crate::ui::markdown;
+use crate::utils::{format_duration_since, Column, GroupBy, IssueColumn, IssueGroup, SortOrder};
+
+const MIN_COLUMN_WIDTH: u16 = 3;
+const MAX_COLUMN_WIDTH: u16 = 60;
+const MIN_TITLE_WIDTH: u16 = 10;
+const SEPARATOR: &str = " │ ";
+const SEPARATOR_WIDTH: u16 = 3;
+
+/// Pane shown full screen instead of the split layout.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Zoom {
+    None,
+    List,
+    Details,
+}
+
+/// Content shown under the header of the issue details.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DetailsTab {
+    Description,
+    /// Timeline of the changes to the issue.
+    History,
+}
+
+/// A line of the issues table: a group header or the index of an issue, at its depth in the
+/// sub-issues tree.
+#[derive(Clone)]
+pub enum Row {
+    Group {
+        id: String,
+        name: String,
+        count: usize,
+        collapsed: bool,
+    },
+    Issue {
+        index: usize,
+        depth: usize,
+        /// Completed and total sub-issues shown below the issue, None without any.
+        sub_issues: Option<(usize, usize)>,
+    },
+}
+
+impl Row {
+    /// Identifies the row across changes to the filter, sort or grouping.
+    fn key(&self, issues: &[Issue]) -> String {
+        match self {
+            Row::Group { id, .. } => format!("group:{}", id),
+            Row::Issue { index, .. } => issues[*index].id.clone(),
+        }
+    }
+}
 
 pub struct IssuesComponent {
-    pub issues: Vec<Issue>,
+    issues: Vec<Issue>,
+    /// Indexes of the issues matching the filters, in display order. Cleared when the issues,
+    /// the filters or the sort change.
+    visible: RefCell<Option<Vec<usize>>>,
+    /// Lines of the table, cleared with `visible` and when groups or sub-issues are folded.
+    rows: RefCell<Option<Vec<Row>>>,
     pub state: ListState,
-    pub filter: String,
+    filter: String,
+    /// Names of the labels to show the issues of, all issues when empty.
+    label_filter: Vec<String>,
+    /// Query the issues were searched with, the team's issues when unset.
+    pub query: Option<Query>,
+    /// Name of the saved view shown in the title bar.
+    pub name: Option<String>,
     pub show_details: bool,
+    pub split: SplitDirection,
+    pub list_ratio: u16,
+    pub zoom: Zoom,
+    pub description_scroll: u16,
+    /// Relation of the selected issue highlighted in the details.
+    relation_cursor: usize,
+    /// Attachment of the selected issue highlighted in the details.
+    attachment_cursor: usize,
+    pub details_tab: DetailsTab,
+    /// Id of the selected issue and the issue with its details, loaded when the details are
+    /// shown. None when they could not be loaded.
+    details: Option<(String, Option<Issue>)>,
+    /// History of the selected issue, loaded when the history tab is shown.
+    history: Option<IssueActivity>,
+    /// Show the comments in the history timeline.
+    pub history_comments: bool,
+    /// Size of the description as last rendered: total lines and visible lines.
+    description_size: Cell<(u16, u16)>,
+    pub columns: Vec<IssueColumn>,
+    pub widths: BTreeMap<IssueColumn, u16>,
+    /// Sort column, the API order when unset.
+    sort: Option<IssueColumn>,
+    order: SortOrder,
+    pub group_by: GroupBy,
+    /// Ids of the collapsed groups.
+    collapsed: HashSet<String>,
+    /// Ids of the issues with their sub-issues hidden.
+    folded: HashSet<String>,
+}
+
+impl Default for IssuesComponent {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl IssuesComponent {
     pub fn new() -> Self {
         Self {
             issues: Vec::new(),
+            visible: RefCell::new(None),
+            rows: RefCell::new(None),
             state: ListState::default(),
             filter: String::new(),
+            label_filter: Vec::new(),
+            query: None,
+            name: None,
             show_details: false,
+            split: SplitDirection::Horizontal,
+            list_ratio: 60,
+            zoom: Zoom::None,
+            description_scroll: 0,
+            relation_cursor: 0,
+            attachment_cursor: 0,
+            details_tab: DetailsTab::Description,
+            details: None,
+            history: None,
+            history_comments: true,
+            description_size: Cell::new((0, 0)),
+            columns: IssueColumn::defaults(),
+            widths: BTreeMap::new(),
+            sort: None,
+            order: SortOrder::Ascending,
+            group_by: GroupBy::None,
+            collapsed: HashSet::new(),
+            folded: HashSet::new(),
+        }
+    }
+
+    pub fn with_config(config: &Config) -> Self {
+        let layout = &config.layout;
+        let columns = if config.issues.columns.is_empty() {
+            IssueColumn::defaults()
+        } else {
+            config.issues.columns.clone()
+        };
+        Self {
+            show_details: layout.show_details,
+            split: layout.split,
+            list_ratio: layout.list_ratio.clamp(20, 80),
+            widths: columns
+                .iter()
+                .map(|c| (*c, config.issues.width(*c)))
+                .collect(),
+            columns,
+            sort: config.issues.sort,
+            order: config.issues.order,
+            group_by: config.issues.group_by,
+            ..Self::new()
+        }
+    }
+
+    pub fn issues(&self) -> &[Issue] {
+        &self.issues
+    }
+
+    pub fn label_filter(&self) -> &[String] {
+        &self.label_filter
+    }
+
+    pub fn sort(&self) -> Option<IssueColumn> {
+        self.sort
+    }
+
+    pub fn order(&self) -> SortOrder {
+        self.order
+    }
+
+    pub fn set_sort(&mut self, sort: Option<IssueColumn>, order: SortOrder) {
+        self.keep_selection(|issues| {
+            issues.sort = sort;
+            issues.order = order;
+        });
+    }
+
+    /// Issues matching the current filter, in display order.
+    pub fn visible_issues(&self) -> Vec<&Issue> {
+        let mut visible = self.visible.borrow_mut();
+        visible
+            .get_or_insert_with(|| self.filter_and_sort())
+            .iter()
+            .map(|&index| &self.issues[index])
+            .collect()
+    }
+
+    fn filter_and_sort(&self) -> Vec<usize> {
+        let filter = self.filter.to_lowercase();
+        let mut indexes: Vec<usize> = self
+            .issues
+            .iter()
+            .enumerate()
+            .filter(|(_, i)| {
+                filter.is_empty()
+                    || i.identifier.to_lowercase().contains(&filter)
+                    || i.title.to_lowercase().contains(&filter)
+            })
+            .filter(|(_, i)| {
+                self.label_filter.is_empty()
+                    || i.labels.iter().any(|l| {
+                        self.label_filter
+                            .iter()
+                            .any(|name| name.eq_ignore_ascii_case(&l.name))
+                    })
+            })
+            .map(|(index, _)| index)
+            .collect();
+
+        if let Some(column) = self.sort {
+            indexes.sort_by(|&a, &b| {
+                self.order
+                    .apply(column.compare(&self.issues[a], &self.issues[b]))
+            });
+        }
+        indexes
+    }
+
+    /// Lines of the table: the visible issues, under their group header when grouped.
+    /// The issues of collapsed groups are hidden.
+    pub fn rows(&self) -> Ref<'_, [Row]> {
+        if self.rows.borrow().is_none() {
+            let rows = self.build_rows();
+            *self.rows.borrow_mut() = Some(rows);
+        }
+        Ref::map(self.rows.borrow(), |rows| {
+            rows.as_deref().unwrap_or_default()
+        })
+    }
+
+    fn build_rows(&self) -> Vec<Row> {
+        let visible: Vec<usize> = self
+            .visible
+            .borrow_mut()
+            .get_or_insert_with(|| self.filter_and_sort())
+            .clone();
+        if self.group_by == GroupBy::None {
+            return self.tree(&visible);
+        }
+
+        // Issues keep the sort order within their group
+        let mut groups: Vec<(IssueGroup, Vec<usize>)> = Vec::new();
+        let mut positions: HashMap<String, usize> = HashMap::new();
+        for index in visible {
+            let group = self.group_by.group(&self.issues[index]);
+            match positions.get(&group.id) {
+                Some(&position) => groups[position].1.push(index),
+                None => {
+                    positions.insert(group.id.clone(), groups.len());
+                    groups.push((group, vec![index]));
+                }
+            }
+        }
+        groups.sort_by(|(a, _), (b, _)| a.rank.cmp(&b.rank));
+
+        let mut rows = Vec::new();
+        for (group, issues) in groups {
+            let collapsed = self.collapsed.contains(&group.id);
+            rows.push(Row::Group {
+                id: group.id,
+                name: group.name,
+                count: issues.len(),
+                collapsed,
+            });
+            if !collapsed {
+                rows.extend(self.tree(&issues));
+            }
+        }
+        rows
+    }
+
+    /// Rows of the issues with their sub-issues indented below them, in the order of the issues.
+    /// Sub-issues whose parent is not among the issues are shown at the top level.
+    fn tree(&self, indexes: &[usize]) -> Vec<Row> {
+        let mut children: HashMap<&str, Vec<usize>> = HashMap::new();
+        let ids: HashSet<&str> = indexes
+            .iter()
+            .map(|&i| self.issues[i].id.as_str())
+            .collect();
+        let mut roots = Vec::new();
+        for &index in indexes {
+            match &self.issues[index].parent {
+                Some(parent) if ids.contains(parent.id.as_str()) => {
+                    children.entry(parent.id.as_str()).or_default().push(index)
+                }
+                _ => roots.push(index),
+            }
+        }
+
+        let mut rows = Vec::new();
+        let mut stack: Vec<(usize, usize)> = roots.into_iter().rev().map(|i| (i, 0)).collect();
+        let mut seen = HashSet::new();
+        while let Some((index, depth)) = stack.pop() {
+            let issue = &self.issues[index];
+            if !seen.insert(issue.id.as_str()) {
+                continue;
+            }
+            let sub_issues = children.get(issue.id.as_str());
+            rows.push(Row::Issue {
+                index,
+                depth,
+                sub_issues: sub_issues
+                    .and_then(|c| completion(c.iter().map(|&c| &self.issues[c].state))),
+            });
+            if self.folded.contains(&issue.id) {
+                continue;
+            }
+            if let Some(sub_issues) = sub_issues {
+                stack.extend(sub_issues.iter().rev().map(|&c| (c, depth + 1)));
+            }
+        }
+        rows
+    }
+
+    /// Forget the visible issues and the rows, to compute them again on the next render.
+    fn invalidate(&self) {
+        self.visible.take();
+        self.rows.take();
+    }
+
+    /// Hide or show the sub-issues of the selected issue.
+    pub fn toggle_sub_issues(&mut self) {
+        let id = match self.selected_row() {
+            Some(Row::Issue {
+                index,
+                sub_issues: Some(_),
+                ..
+            }) => self.issues[index].id.clone(),
+            _ => return,
+        };
+        if !self.folded.remove(&id) {
+            self.folded.insert(id);
+        }
+        self.rows.take();
+    }
+
+    /// Select an issue, showing it when it is hidden under a folded parent or a collapsed group.
+    /// Returns false when the issue is not loaded or hidden by the filter.
+    pub fn select_issue(&mut self, id: &str) -> bool {
+        let mut parent = self
+            .issues
+            .iter()
+            .find(|i| i.id == id)
+            .and_then(|i| i.parent.clone());
+        let mut depth = 0;
+        while let Some(p) = parent.take().filter(|_| depth < self.issues.len()) {
+            self.folded.remove(&p.id);
+            parent = self
+                .issues
+                .iter()
+                .find(|i| i.id == p.id)
+                .and_then(|i| i.parent.clone());
+            depth += 1;
+        }
+        if let Some(issue) = self.issues.iter().find(|i| i.id == id) {
+            self.collapsed.remove(&self.group_by.group(issue).id);
+        }
+        self.rows.take();
+
+        let row = self.rows().iter().position(|r| r.key(&self.issues) == id);
+        if row.is_some() {
+            self.state.select(row);
+            self.description_scroll = 0;
+            self.relation_cursor = 0;
+            self.attachment_cursor = 0;
+        }
+        row.is_some()
+    }
+
+    /// Highlight the next (positive) or previous (negative) relation of the selected issue.
+    pub fn move_relation_cursor(&mut self, delta: isize) {
+        let count = self.details().map_or(0, |i| i.links().len());
+        if count > 0 {
+            let cursor = (self.relation_cursor % count) as isize + delta;
+            self.relation_cursor = cursor.rem_euclid(count as isize) as usize;
+        }
+    }
+
+    /// Relation of the selected issue highlighted in the details.
+    pub fn selected_link(&self) -> Option<IssueLink<'_>> {
+        let links = self.details()?.links();
+        let cursor = self.relation_cursor % links.len().max(1);
+        links.into_iter().nth(cursor)
+    }
+
+    /// Highlight the next (positive) or previous (negative) attachment of the selected issue.
+    pub fn move_attachment_cursor(&mut self, delta: isize) {
+        let count = self.details().map_or(0, |i| i.attachments.len());
+        if count > 0 {
+            let cursor = (self.attachment_cursor % count) as isize + delta;
+            self.attachment_cursor = cursor.rem_euclid(count as isize) as usize;
+        }
+    }
+
+    /// Attachment of the selected issue highlighted in the details.
+    pub fn selected_attachment(&self) -> Option<&Attachment> {
+        let attachments = &self.details()?.attachments;
+        attachments.get(self.attachment_cursor % attachments.len().max(1))
+    }
+
+    pub fn open_selected_attachment(&self) -> anyhow::Result<()> {
+        match self.selected_attachment() {
+            Some(attachment) => self.open_url(&attachment.url),
+            None => Err(anyhow::anyhow!("The issue has no attachments")),
+        }
+    }
+
+    /// Switch the details between the description and the history timeline.
+    pub fn toggle_history(&mut self) {
+        self.details_tab = match self.details_tab {
+            DetailsTab::Description => DetailsTab::History,
+            DetailsTab::History => DetailsTab::Description,
+        };
+        self.description_scroll = 0;
+    }
+
+    pub fn toggle_history_comments(&mut self) {
+        self.history_comments = !self.history_comments;
+    }
+
+    /// Id of the selected issue when the history tab shows it but its history is not loaded.
+    pub fn history_needed(&self) -> Option<String> {
+        let details_visible = self.show_details || self.zoom == Zoom::Details;
+        if self.details_tab != DetailsTab::History || !details_visible {
+            return None;
+        }
+        let issue = self.selected_issue()?;
+        match &self.history {
+            Some(history) if history.issue.id == issue.id => None,
+            _ => Some(issue.id.clone()),
+        }
+    }
+
+    /// Id of the selected issue when the details show it but its details are not loaded.
+    pub fn details_needed(&self) -> Option<String> {
+        if !self.show_details && self.zoom != Zoom::Details {
+            return None;
+        }
+        let issue = self.selected_issue()?;
+        match &self.details {
+            Some((id, _)) if *id == issue.id => None,
+            _ => Some(issue.id.clone()),
+        }
+    }
+
+    /// Keep the details of an issue, None when they could not be loaded.
+    pub fn set_details(&mut self, id: String, issue: Option<Issue>) {
+        self.details = Some((id, issue));
+    }
+
+    /// The selected issue with its details, when they are loaded.
+    pub fn details(&self) -> Option<&Issue> {
+        let selected = self.selected_issue()?;
+        match &self.details {
+            Some((id, issue)) if *id == selected.id => issue.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn set_history(&mut self, history: IssueActivity) {
+        self.history = Some(history);
+    }
+
+    /// Forget the loaded history so it is fetched again, after a refresh or a change to the issue.
+    pub fn clear_history(&mut self) {
+        self.history = None;
+    }
+
+    /// Add an issue loaded outside of the list, like the parent of a sub-issue or a new sub-issue.
+    pub fn add_issue(&mut self, issue: Issue) {
+        match self.issues.iter_mut().find(|i| i.id == issue.id) {
+            Some(existing) => *existing = issue,
+            None => self.issues.push(issue),
+        }
+        self.invalidate();
+    }
+
+    /// Group the issues by the next field, after the last one go back to a flat list.
+    pub fn cycle_group_by(&mut self) {
+        self.keep_selection(|issues| {
+            issues.group_by = issues.group_by.next();
+            issues.collapsed.clear();
+        });
+    }
+
+    /// Collapse or expand the group of the selected row.
+    pub fn toggle_group(&mut self) {
+        let id = match self.selected_row() {
+            Some(Row::Group { id, .. }) => id,
+            Some(Row::Issue { index, .. }) if self.group_by != GroupBy::None => {
+                self.group_by.group(&self.issues[index]).id
+            }
+            _ => return,
+        };
+
+        // Select the header, the issue may be hidden now
+        let key = format!("group:{}", id);
+        if !self.collapsed.remove(&id) {
+            self.collapsed.insert(id);
+        }
+        self.rows.take();
+        let row = self.rows().iter().position(|r| r.key(&self.issues) == key);
+        self.state.select(row);
+    }
+
+    /// Collapse all groups, or expand them all when they are all collapsed.
+    pub fn toggle_all_groups(&mut self) {
+        if self.group_by == GroupBy::None {
+            return;
+        }
+
+        let ids: HashSet<String> = self
+            .visible_issues()
+            .into_iter()
+            .map(|i| self.group_by.group(i).id)
+            .collect();
+        self.keep_selection(|issues| {
+            if ids.is_subset(&issues.collapsed) {
+                issues.collapsed.clear();
+            } else {
+                issues.collapsed = ids;
+            }
+        });
+    }
+
+    /// Sort by the next displayed column, after the last one go back to the API order.
+    pub fn cycle_sort(&mut self) {
+        let next = match self
+            .sort
+            .and_then(|s| self.columns.iter().position(|c| *c == s))
+        {
+            Some(i) => self.columns.get(i + 1).copied(),
+            None if self.sort.is_none() => self.columns.first().copied(),
+            None => None,
+        };
+        self.keep_selection(|issues| issues.sort = next);
+    }
+
+    pub fn toggle_sort_order(&mut self) {
+        self.keep_selection(|issues| issues.order = issues.order.reverse());
+    }
+
+    /// Change the width of the sorted column, returning its new width.
+    /// The title takes the remaining space and cannot be resized.
+    pub fn resize_column(&mut self, delta: i16) -> Option<(IssueColumn, u16)> {
+        let column = self.sort.filter(|c| *c != IssueColumn::Title)?;
+        let width = self.column_width(column).saturating_add_signed(delta);
+        let width = width.clamp(MIN_COLUMN_WIDTH, MAX_COLUMN_WIDTH);
+        self.widths.insert(column, width);
+        Some((column, width))
+    }
+
+    fn column_width(&self, column: IssueColumn) -> u16 {
+        self.widths
+            .get(&column)
+            .copied()
+            .unwrap_or_else(|| column.default_width())
+    }
+
+    /// Only show the issues with one of these labels, all issues when empty.
+    pub fn set_label_filter(&mut self, names: Vec<String>) {
+        self.keep_selection(|c| c.label_filter = names);
+        if self.state.selected().is_none() {
+            self.state.select(Some(0));
+        }
+    }
+
+    /// Apply a change to the display order, keeping the same row selected.
+    /// When an issue is hidden in a collapsed group, its group header is selected instead.
+    fn keep_selection(&mut self, change: impl FnOnce(&mut Self)) {
+        let selected = self.selected_row().map(|r| r.key(&self.issues));
+        let group = self
+            .selected_issue()
+            .map(|i| format!("group:{}", self.group_by.group(i).id));
+        change(self);
+        self.invalidate();
+        if let Some(key) = selected {
+            let row = {
+                let rows = self.rows();
+                rows.iter()
+                    .position(|r| r.key(&self.issues) == key)
+                    .or_else(|| rows.iter().position(|r| Some(r.key(&self.issues)) == group))
+            };
+            self.state.select(row.or(Some(0)));
         }
     }
 
     pub fn select_next(&mut self) {
+        let len = self.rows().len();
         let i = match self.state.selected() {
             Some(i) => {
-                if i >= self.issues.len().saturating_sub(1) {
+                if i >= len.saturating_sub(1) {
                     0
                 } else {
                     i + 1
@@ -39,32 +622,44 @@ impl IssuesComponent {
             }
             None => 0,
         };
-        if !self.issues.is_empty() {
+        if len > 0 {
             self.state.select(Some(i));
+            self.description_scroll = 0;
+            self.relation_cursor = 0;
+            self.attachment_cursor = 0;
         }
     }
 
     pub fn select_previous(&mut self) {
+        let len = self.rows().len();
         let i = match self.state.selected() {
             Some(i) => {
                 if i == 0 {
-                    self.issues.len().saturating_sub(1)
+                    len.saturating_sub(1)
                 } else {
                     i.saturating_sub(1)
                 }
             }
             None => 0,
         };
-        if !self.issues.is_empty() {
+        if len > 0 {
             self.state.select(Some(i));
+            self.description_scroll = 0;
+            self.relation_cursor = 0;
+            self.attachment_cursor = 0;
         }
     }
 
+    fn selected_row(&self) -> Option<Row> {
+        let index = self.state.selected()?;
+        self.rows().get(index).cloned()
+    }
+
     pub fn selected_issue(&self) -> Option<&Issue> {
-        if let Some(index) = self.state.selected() {
-            self.issues.get(index)
-        } else {
-            None
+        let index = self.state.selected()?;
+        match self.rows().get(index)? {
+            Row::Issue { index, .. } => Some(&self.issues[*index]),
+            Row::Group { .. } => None,
         }
     }
 
@@ -78,6 +673,43 @@ impl IssuesComponent {
 
     pub fn toggle_details(&mut self) {
         self.show_details = !self.show_details;
+        self.zoom = Zoom::None;
+    }
+
+    /// Scroll the description of the selected issue by `lines` (negative to scroll up).
+    pub fn scroll_description(&mut self, lines: i32) {
+        let (total, visible) = self.description_size.get();
+        let max = total.saturating_sub(visible);
+        let scroll = (self.description_scroll as i32 + lines).clamp(0, max as i32);
+        self.description_scroll = scroll as u16;
+    }
+
+    /// Number of description lines visible at once, used to scroll by pages.
+    pub fn description_page(&self) -> i32 {
+        (self.description_size.get().1 as i32 - 1).max(1)
+    }
+
+    /// Cycle between the split layout, the list full screen and the details full screen.
+    pub fn cycle_zoom(&mut self) {
+        self.zoom = match self.zoom {
+            Zoom::None => Zoom::List,
+            Zoom::List => Zoom::Details,
+            Zoom::Details => Zoom::None,
+        };
+    }
+
+    pub fn toggle_split(&mut self) {
+        self.split = match self.split {
+            SplitDirection::Horizontal => SplitDirection::Vertical,
+            SplitDirection::Vertical => SplitDirection::Horizontal,
+        };
+    }
+
+    /// Grow (positive) or shrink (negative) the list pane, in percent of the screen, and
+    /// return the new share.
+    pub fn resize_list(&mut self, delta: i16) -> u16 {
+        self.list_ratio = self.list_ratio.saturating_add_signed(delta).clamp(20, 80);
+        self.list_ratio
     }
 
     fn open_url(&self, url: &str) -> anyhow::Result<()> {
@@ -98,55 +730,132 @@ impl IssuesComponent {
         #[cfg(target_os = "windows")]
         {
             std::process::Command::new("cmd")
-                .args(&["/c", "start", url])
+                .args(["/c", "start", url])
                 .spawn()
                 .map_err(|e| anyhow::anyhow!("Failed to open URL: {}", e))?;
         }
         Ok(())
     }
 
+    /// Replace an issue after it was updated.
+    pub fn replace_issue(&mut self, issue: Issue) {
+        if self
+            .history
+            .as_ref()
+            .is_some_and(|h| h.issue.id == issue.id)
+        {
+            self.history = None;
+        }
+        if self.details.as_ref().is_some_and(|(id, _)| *id == issue.id) {
+            self.details = None;
+        }
+        if let Some(existing) = self.issues.iter_mut().find(|i| i.id == issue.id) {
+            *existing = issue;
+            self.invalidate();
+        }
+    }
+
+    /// Show the results of a query, or the team's issues when the query is unset.
+    pub fn show_query(&mut self, query: Option<Query>, issues: Vec<Issue>) {
+        self.query = query;
+        self.issues = issues;
+        self.details = None;
+        self.invalidate();
+        self.state.select(if self.issues.is_empty() {
+            None
+        } else {
+            Some(0)
+        });
+        self.description_scroll = 0;
+        self.relation_cursor = 0;
+        self.attachment_cursor = 0;
+    }
+
     pub fn update_issues(&mut self, issues: Vec<Issue>) {
         self.issues = issues;
+        self.details = None;
+        self.invalidate();
         if !self.issues.is_empty() && self.state.selected().is_none() {
             self.state.select(Some(0));
         }
     }
 
     pub fn render(&mut self, f: &mut Frame, area: ratatui::layout::Rect) {
-        if self.show_details {
+        if self.zoom == Zoom::Details {
+            self.render_issue_details(f, area);
+            return;
+        }
+
+        // Create 4-section layout similar to g1c dashboard
+        let main_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Title bar
+                Constraint::Length(6), // Overview panel
+                Constraint::Min(8),    // Issues list and details
+                Constraint::Length(3), // Status bar
+            ])
+            .split(area);
+
+        // 1. Title Bar
+        self.render_title_bar(f, main_chunks[0]);
+
+        // 2. Overview Panel
+        self.render_overview_panel(f, main_chunks[1]);
+
+        // 3. Issues List, next to the details of the selected issue
+        self.render_body(f, main_chunks[2]);
+
+        // 4. Status Bar
+        self.render_status_bar(f, main_chunks[3]);
+    }
+
+    /// Render the list and details only, to embed the issues in another screen.
+    pub fn render_embedded(&mut self, f: &mut Frame, area: ratatui::layout::Rect) {
+        if self.zoom == Zoom::Details {
             self.render_issue_details(f, area);
         } else {
-            // Create 4-section layout similar to g1c dashboard
-            let main_chunks = Layout::default()
-                .direction(Direction::Vertical)
+            self.render_body(f, area);
+        }
+    }
+
+    fn render_body(&mut self, f: &mut Frame, area: ratatui::layout::Rect) {
+        if self.show_details && self.zoom == Zoom::None {
+            let direction = match self.split {
+                SplitDirection::Horizontal => Direction::Horizontal,
+                SplitDirection::Vertical => Direction::Vertical,
+            };
+            let panes = Layout::default()
+                .direction(direction)
                 .constraints([
-                    Constraint::Length(3), // Title bar
-                    Constraint::Length(6), // Overview panel
-                    Constraint::Min(8),    // Issues list
-                    Constraint::Length(3), // Status bar
+                    Constraint::Percentage(self.list_ratio),
+                    Constraint::Percentage(100 - self.list_ratio),
                 ])
                 .split(area);
 
-            // 1. Title Bar
-            self.render_title_bar(f, main_chunks[0]);
-
-            // 2. Overview Panel
-            self.render_overview_panel(f, main_chunks[1]);
-
-            // 3. Issues List
-            self.render_issues_list(f, main_chunks[2]);
-
-            // 4. Status Bar
-            self.render_status_bar(f, main_chunks[3]);
+            self.render_issues_list(f, panes[0]);
+            self.render_details_pane(f, panes[1]);
+        } else {
+            self.render_issues_list(f, area);
         }
     }
 
     fn render_title_bar(&self, f: &mut Frame, area: ratatui::layout::Rect) {
-        let title_text = if !self.filter.is_empty() {
+        let mut title_text = if let Some(name) = &self.name {
+            match self.query.as_ref().filter(|q| !q.is_empty()) {
+                Some(query) => format!("🎯 {} - Query: {}", name, query.text),
+                None => format!("🎯 {}", name),
+            }
+        } else if let Some(query) = &self.query {
+            format!("🎯 Linear Issues - Query: {}", query.text)
+        } else if !self.filter.is_empty() {
             format!("🎯 Linear Issues - Filter: '{}'", self.filter)
         } else {
             "🎯 Linear Issues".to_string()
         };
+        if !self.label_filter.is_empty() {
+            title_text.push_str(&format!(" - Labels: {}", self.label_filter.join(", ")));
+        }
 
         let title = Paragraph::new(title_text)
             .style(
@@ -257,7 +966,8 @@ impl IssuesComponent {
     }
 
     fn render_issues_list(&mut self, f: &mut Frame, area: ratatui::layout::Rect) {
-        if self.issues.is_empty() {
+        let visible = self.visible_issues();
+        if visible.is_empty() {
             let empty_msg = Paragraph::new("No issues found")
                 .style(Style::default().fg(Color::Gray))
                 .block(
@@ -276,109 +986,84 @@ impl IssuesComponent {
             .constraints([Constraint::Length(3), Constraint::Min(5)])
             .split(area);
 
-        // Calculate dynamic column widths based on available space
-        let inner_width = area.width.saturating_sub(2); // Account for borders
-        let id_width = 10; // Increased for better alignment
-        let priority_width = 4; // For emoji symbols with padding
-        let state_width = 14; // Increased for longer state names
-        let assignee_width = 30; // Increased for longer names
-        let title_width = inner_width
-            .saturating_sub(id_width + priority_width + state_width + assignee_width + 12); // Account for 5 separators
-
-        // Render column headers
-        self.render_issues_header(
-            f,
-            chunks[0],
-            id_width,
-            priority_width,
-            title_width,
-            state_width,
-            assignee_width,
-        );
+        // The title takes the space left by the other columns
+        let inner_width = area.width.saturating_sub(4); // Borders and selection indicator
+        let fixed: u16 = self
+            .columns
+            .iter()
+            .filter(|c| **c != IssueColumn::Title)
+            .map(|c| self.column_width(*c))
+            .sum::<u16>()
+            + SEPARATOR_WIDTH * self.columns.len().saturating_sub(1) as u16;
+        let layout: Vec<(IssueColumn, u16)> = self
+            .columns
+            .iter()
+            .map(|c| match c {
+                IssueColumn::Title => (*c, inner_width.saturating_sub(fixed).max(MIN_TITLE_WIDTH)),
+                _ => (*c, self.column_width(*c)),
+            })
+            .collect();
+
+        self.render_issues_header(f, chunks[0], &layout);
 
         let items: Vec<ListItem> = self
-            .issues
+            .rows()
             .iter()
             .enumerate()
-            .map(|(i, issue)| {
+            .map(|(i, row)| {
                 let is_selected = Some(i) == self.state.selected();
                 let selection_indicator = if is_selected { "➤ " } else { "  " };
 
-                let priority_color = match issue.priority.unwrap_or(0) {
-                    4 => Color::Red,    // Urgent
-                    3 => Color::Yellow, // High
-                    2 => Color::Green,  // Medium
-                    1 => Color::Blue,   // Low
-                    _ => Color::White,
-                };
-
-                let priority_symbol = match issue.priority.unwrap_or(0) {
-                    4 => "🔴",
-                    3 => "🟠",
-                    2 => "🟢",
-                    1 => "🔵",
-                    _ => "⚪",
-                };
-
-                let state_color = match issue.state.state_type.as_str() {
-                    "completed" => Color::Green,
-                    "started" => Color::Yellow,
-                    "unstarted" => Color::Gray,
-                    "canceled" => Color::Red,
-                    _ => Color::White,
+                let (issue, depth, sub_issues) = match row {
+                    Row::Issue {
+                        index,
+                        depth,
+                        sub_issues,
+                    } => (&self.issues[*index], *depth, *sub_issues),
+                    Row::Group {
+                        name,
+                        count,
+                        collapsed,
+                        ..
+                    } => {
+                        let marker = if *collapsed { "▸" } else { "▾" };
+                        return ListItem::new(Line::from(vec![
+                            Span::styled(selection_indicator, Style::default().fg(Color::Yellow)),
+                            Span::styled(
+                                format!("{} {} ", marker, name),
+                                Style::default()
+                                    .fg(Color::Magenta)
+                                    .add_modifier(Modifier::BOLD),
+                            ),
+                            Span::styled(format!("({})", count), Style::default().fg(Color::Gray)),
+                        ]));
+                    }
                 };
 
-                let assignee = issue
-                    .assignee
-                    .as_ref()
-                    .map(|a| a.display_name.as_str())
-                    .unwrap_or("Unassigned");
-
-                let truncated_title = self.truncate_text(&issue.title, title_width as usize);
-                let truncated_assignee = self.truncate_text(assignee, assignee_width as usize);
-
-                let line = Line::from(vec![
-                    Span::styled(selection_indicator, Style::default().fg(Color::Yellow)),
-                    Span::styled(
-                        format!(
-                            "{:>width$} ",
-                            issue.identifier,
-                            width = (id_width - 1) as usize
-                        ),
-                        Style::default()
-                            .fg(Color::Cyan)
-                            .add_modifier(Modifier::BOLD),
-                    ),
-                    Span::raw("│ "),
-                    Span::styled(
-                        format!(
-                            "{:^width$} ",
-                            priority_symbol,
-                            width = (priority_width - 1) as usize
-                        ),
-                        Style::default().fg(priority_color),
-                    ),
-                    Span::raw("│ "),
-                    Span::styled(
-                        format!("{:<width$} ", truncated_title, width = title_width as usize),
-                        Style::default().fg(Color::White),
-                    ),
-                    Span::raw("│ "),
-                    Span::styled(
-                        format!(
-                            "{:>width$} ",
-                            issue.state.name,
-                            width = (state_width - 1) as usize
-                        ),
-                        Style::default()
-                            .fg(state_color)
-                            .add_modifier(Modifier::BOLD),
-                    ),
-                    Span::raw("│ "),
-                    Span::styled(truncated_assignee, Style::default().fg(Color::Gray)),
-                ]);
+                let mut spans = vec![Span::styled(
+                    selection_indicator,
+                    Style::default().fg(Color::Yellow),
+                )];
+                for (n, (column, width)) in layout.iter().enumerate() {
+                    if n > 0 {
+                        spans.push(Span::raw(SEPARATOR));
+                    }
+                    if *column == IssueColumn::Labels {
+                        spans.extend(label_chips(&issue.labels, *width));
+                        continue;
+                    }
+                    if *column == IssueColumn::Title {
+                        spans.extend(self.title_cell(issue, depth, sub_issues, *width));
+                        continue;
+                    }
+                    let (text, style) = issue_cell(issue, *column);
+                    spans.push(Span::styled(
+                        fit(&text, *width, column_alignment(*column)),
+                        style,
+                    ));
+                }
 
-                ListItem::new(line)
+                ListItem::new(Line::from(spans))
             })
             .collect();
 
@@ -386,7 +1071,14 @@ impl IssuesComponent {
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .title(format!(" Issues ({}) ", self.issues.len()))
+                    .title(match self.group_by {
+                        GroupBy::None => format!(" Issues ({}) ", visible.len()),
+                        group_by => format!(
+                            " Issues ({}) by {} ",
+                            visible.len(),
+                            group_by.title().to_lowercase()
+                        ),
+                    })
                     .border_style(Style::default().fg(Color::Gray)),
             )
             .highlight_style(Style::default().bg(Color::DarkGray));
@@ -394,53 +1086,86 @@ impl IssuesComponent {
         f.render_stateful_widget(list, chunks[1], &mut self.state);
     }
 
+    /// Title indented at the issue's depth in the tree, with a ▸/▾ toggle and the completed
+    /// sub-issues when it has some.
+    fn title_cell(
+        &self,
+        issue: &Issue,
+        depth: usize,
+        sub_issues: Option<(usize, usize)>,
+        width: u16,
+    ) -> Vec<Span<'static>> {
+        let (text, style) = issue_cell(issue, IssueColumn::Title);
+        let marker = match sub_issues {
+            None if depth > 0 => "  ",
+            None => "",
+            Some(_) if self.folded.contains(&issue.id) => "▸ ",
+            Some(_) => "▾ ",
+        };
+        let prefix = format!("{}{}", "  ".repeat(depth), marker);
+        let completion = match sub_issues {
+            Some((done, total)) => format!(" {}/{}", done, total),
+            None => String::new(),
+        };
+        let completion_color = match sub_issues {
+            Some((done, total)) if done == total => Color::Green,
+            _ => Color::Gray,
+        };
+
+        // The completion is cut before the title when the column is narrow
+        let prefix = fit(
+            &prefix,
+            (prefix.chars().count() as u16).min(width),
+            Alignment::Left,
+        );
+        let rest = width.saturating_sub(prefix.chars().count() as u16);
+        let completion = if (completion.len() as u16) + MIN_COLUMN_WIDTH <= rest {
+            completion
+        } else {
+            String::new()
+        };
+        let title_width = rest - completion.len() as u16;
+        vec![
+            Span::styled(prefix, Style::default().fg(Color::Magenta)),
+            Span::styled(fit(&text, title_width, Alignment::Left), style),
+            Span::styled(completion, Style::default().fg(completion_color)),
+        ]
+    }
+
     fn render_issues_header(
         &self,
         f: &mut Frame,
         area: ratatui::layout::Rect,
-        id_width: u16,
-        priority_width: u16,
-        title_width: u16,
-        state_width: u16,
-        assignee_width: u16,
+        layout: &[(IssueColumn, u16)],
     ) {
-        let header = Line::from(vec![
-            Span::raw("  "), // Space for selection indicator
-            Span::styled(
-                format!("{:>width$}", "ID", width = (id_width - 1) as usize),
-                Style::default()
-                    .add_modifier(Modifier::BOLD)
-                    .fg(Color::Blue),
-            ),
-            Span::raw(" │ "),
-            Span::styled(
-                format!("{:^width$}", "P", width = (priority_width - 1) as usize),
-                Style::default()
-                    .add_modifier(Modifier::BOLD)
-                    .fg(Color::Blue),
-            ),
-            Span::raw(" │ "),
-            Span::styled(
-                format!("{:<width$}", "TITLE", width = title_width as usize),
-                Style::default()
-                    .add_modifier(Modifier::BOLD)
-                    .fg(Color::Blue),
-            ),
-            Span::raw(" │ "),
-            Span::styled(
-                format!("{:>width$}", "STATE", width = (state_width - 1) as usize),
-                Style::default()
-                    .add_modifier(Modifier::BOLD)
-                    .fg(Color::Blue),
-            ),
-            Span::raw(" │ "),
-            Span::styled(
-                format!("{:<width$}", "ASSIGNEE", width = assignee_width as usize),
-                Style::default()
-                    .add_modifier(Modifier::BOLD)
-                    .fg(Color::Blue),
-            ),
-        ]);
+        let style = Style::default()
+            .add_modifier(Modifier::BOLD)
+            .fg(Color::Blue);
+        let mut spans = vec![Span::raw("  ")]; // Space for selection indicator
+        for (n, (column, width)) in layout.iter().enumerate() {
+            if n > 0 {
+                spans.push(Span::raw(SEPARATOR));
+            }
+            let title = match column {
+                IssueColumn::Priority => "P".to_string(),
+                _ => column.title().to_uppercase(),
+            };
+            let title = match (self.sort, self.order) {
+                (Some(sort), SortOrder::Ascending) if sort == *column => format!("{} ▲", title),
+                (Some(sort), SortOrder::Descending) if sort == *column => format!("{} ▼", title),
+                _ => title,
+            };
+            let style = if self.sort == Some(*column) {
+                style.fg(Color::Yellow)
+            } else {
+                style
+            };
+            spans.push(Span::styled(
+                fit(&title, *width, column_alignment(*column)),
+                style,
+            ));
+        }
+        let header = Line::from(spans);
 
         let header_paragraph = Paragraph::new(header).block(
             Block::default()
@@ -454,9 +1179,26 @@ impl IssuesComponent {
         let status_text = if let Some(issue) = self.selected_issue() {
             let creator = &issue.creator.display_name;
             let team = &issue.team.key;
+            let mut hierarchy = String::new();
+            if let Some(Row::Issue {
+                sub_issues: Some((done, total)),
+                ..
+            }) = self.selected_row()
+            {
+                hierarchy.push_str(&format!(" | Sub-issues: {}/{} (t: fold)", done, total));
+            }
+            if let Some(parent) = &issue.parent {
+                hierarchy.push_str(&format!(" | Parent: {} (p)", parent.identifier));
+            }
+            format!(
+                "Selected: {} - {} | Team: {} | Creator: {}{} | Press ? for help",
+                issue.identifier, issue.title, team, creator, hierarchy
+            )
+        } else if let Some(Row::Group { name, count, .. }) = self.selected_row() {
+            let plural = if count == 1 { "" } else { "s" };
             format!(
-                "Selected: {} - {} | Team: {} | Creator: {} | Press ? for help",
-                issue.identifier, issue.title, team, creator
+                "Group: {} ({} issue{}) | Space: Collapse/expand | C: All groups | Press ? for help",
+                name, count, plural
             )
         } else {
             "No issue selected | Press ? for help".to_string()
@@ -476,23 +1218,26 @@ impl IssuesComponent {
     }
 
     fn truncate_text(&self, text: &str, max_width: usize) -> String {
-        if text.len() <= max_width {
+        if text.chars().count() <= max_width {
             text.to_string()
         } else if max_width > 3 {
-            format!("{}...", &text[..max_width.saturating_sub(3)])
+            let truncated: String = text.chars().take(max_width - 3).collect();
+            format!("{}...", truncated)
         } else {
             text.chars().take(max_width).collect()
         }
     }
 
     fn render_issue_details(&self, f: &mut Frame, area: ratatui::layout::Rect) {
-        if let Some(issue) = self.selected_issue() {
+        if let Some(issue) = self.details().or(self.selected_issue()) {
             // Create 3-section layout for details view
             let main_chunks = Layout::default()
                 .direction(Direction::Vertical)
                 .constraints([
-                    Constraint::Length(5), // Header with issue info
-                    Constraint::Min(8),    // Description/comments area
+                    Constraint::Length(7),                         // Header with issue info
+                    Constraint::Length(relations_height(issue)),   // Relations
+                    Constraint::Length(attachments_height(issue)), // Attachments
+                    Constraint::Min(8),                            // Description/comments area
                     Constraint::Length(3), // Status bar with navigation info
                 ])
                 .split(area);
@@ -500,11 +1245,44 @@ impl IssuesComponent {
             // 1. Issue Header
             self.render_issue_header(f, main_chunks[0], issue);
 
-            // 2. Description and Comments
-            self.render_issue_description(f, main_chunks[1], issue);
+            // 2. Relations and attachments
+            self.render_relations(f, main_chunks[1], issue);
+            self.render_attachments(f, main_chunks[2], issue);
+
+            // 3. Description and Comments
+            self.render_issue_description(f, main_chunks[3], issue);
+
+            // 4. Navigation Status Bar
+            self.render_details_status_bar(f, main_chunks[4]);
+        } else {
+            let no_selection = Paragraph::new("No issue selected")
+                .style(Style::default().fg(Color::Gray))
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title(" Issue Details ")
+                        .border_style(Style::default().fg(Color::Gray)),
+                );
+            f.render_widget(no_selection, area);
+        }
+    }
+
+    fn render_details_pane(&self, f: &mut Frame, area: ratatui::layout::Rect) {
+        if let Some(issue) = self.details().or(self.selected_issue()) {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Length(7),                         // Header with issue info
+                    Constraint::Length(relations_height(issue)),   // Relations
+                    Constraint::Length(attachments_height(issue)), // Attachments
+                    Constraint::Min(3),                            // Description
+                ])
+                .split(area);
 
-            // 3. Navigation Status Bar
-            self.render_details_status_bar(f, main_chunks[2]);
+            self.render_issue_header(f, chunks[0], issue);
+            self.render_relations(f, chunks[1], issue);
+            self.render_attachments(f, chunks[2], issue);
+            self.render_issue_description(f, chunks[3], issue);
         } else {
             let no_selection = Paragraph::new("No issue selected")
                 .style(Style::default().fg(Color::Gray))
@@ -581,6 +1359,18 @@ impl IssuesComponent {
                     Style::default().fg(Color::White),
                 ),
             ]),
+            Line::from(if issue.labels.is_empty() {
+                vec![
+                    Span::styled("Labels: ", Style::default().fg(Color::Gray)),
+                    Span::styled("None", Style::default().fg(Color::DarkGray)),
+                ]
+            } else {
+                let mut spans = vec![Span::styled("Labels: ", Style::default().fg(Color::Gray))];
+                let width = area.width.saturating_sub(2 + 8);
+                spans.extend(label_chips(&issue.labels, width));
+                spans
+            }),
+            Line::from(self.hierarchy_line(issue)),
         ]);
 
         let header = Paragraph::new(header_text).block(
@@ -592,69 +1382,240 @@ impl IssuesComponent {
         f.render_widget(header, area);
     }
 
-    fn render_issue_description(
-        &self,
-        f: &mut Frame,
-        area: ratatui::layout::Rect,
-        issue: &Issue,
-    ) {
-        let description = issue
-            .description
-            .as_deref()
-            .unwrap_or("No description available");
-
-        // Split the description into lines that fit the available width
-        let inner_width = area.width.saturating_sub(4) as usize; // Account for borders and padding
-        let wrapped_lines: Vec<Line> = description
-            .split('\n')
-            .flat_map(|line| {
-                if line.len() <= inner_width {
-                    vec![Line::from(line.to_string())]
+    /// Parent and completed sub-issues of an issue, for the details header.
+    fn hierarchy_line<'a>(&self, issue: &'a Issue) -> Vec<Span<'a>> {
+        let mut spans = vec![Span::styled("Parent: ", Style::default().fg(Color::Gray))];
+        match &issue.parent {
+            Some(parent) => {
+                spans.push(Span::styled(
+                    format!("{} ", parent.identifier),
+                    Style::default().fg(Color::Cyan),
+                ));
+                spans.push(Span::styled(
+                    &parent.title,
+                    Style::default().fg(Color::White),
+                ));
+            }
+            None => spans.push(Span::styled("None", Style::default().fg(Color::DarkGray))),
+        }
+        spans.push(Span::raw("  |  "));
+        spans.push(Span::styled(
+            "Sub-issues: ",
+            Style::default().fg(Color::Gray),
+        ));
+        match issue.sub_issue_completion() {
+            _ if self.details().is_none() => {
+                spans.push(Span::styled("...", Style::default().fg(Color::DarkGray)))
+            }
+            Some((done, total)) => spans.push(Span::styled(
+                format!("{}/{} done", done, total),
+                Style::default().fg(if done == total {
+                    Color::Green
                 } else {
-                    // Simple word wrapping
-                    let mut wrapped = Vec::new();
-                    let words: Vec<&str> = line.split_whitespace().collect();
-                    let mut current_line = String::new();
-
-                    for word in words {
-                        if current_line.len() + word.len() + 1 <= inner_width {
-                            if !current_line.is_empty() {
-                                current_line.push(' ');
-                            }
-                            current_line.push_str(word);
-                        } else {
-                            if !current_line.is_empty() {
-                                wrapped.push(Line::from(current_line.clone()));
-                                current_line.clear();
-                            }
-                            current_line.push_str(word);
-                        }
-                    }
-                    if !current_line.is_empty() {
-                        wrapped.push(Line::from(current_line));
-                    }
-                    wrapped
-                }
+                    Color::White
+                }),
+            )),
+            None => spans.push(Span::styled("None", Style::default().fg(Color::DarkGray))),
+        }
+        spans
+    }
+
+    /// Relations of the issue, the highlighted one is opened with `J`.
+    fn render_relations(&self, f: &mut Frame, area: ratatui::layout::Rect, issue: &Issue) {
+        let links = issue.links();
+        if links.is_empty() {
+            return;
+        }
+        let cursor = self.relation_cursor % links.len();
+
+        let items: Vec<ListItem> = links
+            .iter()
+            .map(|link| {
+                let color = match link.kind {
+                    RelationKind::BlockedBy if !link.issue.is_resolved() => Color::Red,
+                    RelationKind::Blocks if !link.issue.is_resolved() => Color::Yellow,
+                    RelationKind::DuplicateOf | RelationKind::DuplicatedBy => Color::Magenta,
+                    _ => Color::Gray,
+                };
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!("{:<14}", link.kind.title()),
+                        Style::default().fg(color),
+                    ),
+                    Span::styled(
+                        format!("{} ", link.issue.identifier),
+                        Style::default().fg(Color::Cyan),
+                    ),
+                    Span::styled(&link.issue.title, Style::default().fg(Color::White)),
+                    Span::styled(
+                        format!(" [{}]", link.issue.state.name),
+                        Style::default().fg(Color::Gray),
+                    ),
+                ]))
             })
             .collect();
 
-        let description_text = Text::from(wrapped_lines);
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!(" Relations ({}) ", links.len()))
+                    .title_bottom(
+                        Line::from(" [/]: select | J: go to | B: add | X: remove ")
+                            .alignment(Alignment::Right),
+                    )
+                    .border_style(Style::default().fg(Color::Gray)),
+            )
+            .highlight_symbol("➤ ")
+            .highlight_style(Style::default().add_modifier(Modifier::BOLD));
+        let mut state = ListState::default().with_selected(Some(cursor));
+        f.render_stateful_widget(list, area, &mut state);
+    }
 
-        let description_widget = Paragraph::new(description_text)
+    /// Attachments of the issue: pull requests, links and uploaded files.
+    fn render_attachments(&self, f: &mut Frame, area: ratatui::layout::Rect, issue: &Issue) {
+        if issue.attachments.is_empty() {
+            return;
+        }
+        let cursor = self.attachment_cursor % issue.attachments.len();
+
+        let items: Vec<ListItem> = issue
+            .attachments
+            .iter()
+            .map(|attachment| {
+                let mut spans = vec![
+                    Span::styled(
+                        format!("{:<8}", attachment.source()),
+                        Style::default().fg(Color::Yellow),
+                    ),
+                    Span::styled(&attachment.title, Style::default().fg(Color::White)),
+                ];
+                if let Some(subtitle) = attachment.subtitle.as_deref().filter(|s| !s.is_empty()) {
+                    spans.push(Span::styled(
+                        format!(" - {}", subtitle),
+                        Style::default().fg(Color::Gray),
+                    ));
+                }
+                spans.push(Span::styled(
+                    format!("  {}", attachment.url),
+                    Style::default().fg(Color::DarkGray),
+                ));
+                ListItem::new(Line::from(spans))
+            })
+            .collect();
+
+        let list = List::new(items)
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .title(" Description ")
+                    .title(format!(" Attachments ({}) ", issue.attachments.len()))
+                    .title_bottom(
+                        Line::from(" {/}: select | o: open | y: copy URL | U: attach ")
+                            .alignment(Alignment::Right),
+                    )
                     .border_style(Style::default().fg(Color::Gray)),
             )
-            .wrap(ratatui::widgets::Wrap { trim: true });
+            .highlight_symbol("➤ ")
+            .highlight_style(Style::default().add_modifier(Modifier::BOLD));
+        let mut state = ListState::default().with_selected(Some(cursor));
+        f.render_stateful_widget(list, area, &mut state);
+    }
+
+    fn render_issue_description(&self, f: &mut Frame, area: ratatui::layout::Rect, issue: &Issue) {
+        let lines = match (self.details_tab, &self.history) {
+            (DetailsTab::History, Some(history)) if history.issue.id == issue.id => {
+                timeline(history, self.history_comments)
+            }
+            (DetailsTab::History, _) => vec![Line::from(Span::styled(
+                "Loading the history...",
+                Style::default().fg(Color::Gray),
+            ))],
+            (DetailsTab::Description, _) => match &self.details {
+                Some((id, Some(details))) if *id == issue.id => match details
+                    .description
+                    .as_deref()
+                    .filter(|d| !d.trim().is_empty())
+                {
+                    Some(description) => markdown::render(description),
+                    None => vec![Line::from(Span::styled(
+                        "No description available",
+                        Style::default().fg(Color::Gray),
+                    ))],
+                },
+                Some((id, None)) if *id == issue.id => vec![Line::from(Span::styled(
+                    "Could not load the details",
+                    Style::default().fg(Color::Red),
+                ))],
+                _ => vec![Line::from(Span::styled(
+                    "Loading the details...",
+                    Style::default().fg(Color::Gray),
+                ))],
+            },
+        };
+        let tab = |name: &'static str, tab: DetailsTab| {
+            if self.details_tab == tab {
+                Span::styled(
+                    name,
+                    Style::default()
+                        .fg(Color::Cyan)
+                        .add_modifier(Modifier::BOLD),
+                )
+            } else {
+                Span::styled(name, Style::default().fg(Color::DarkGray))
+            }
+        };
+        let mut title = vec![
+            Span::raw(" "),
+            tab("Description", DetailsTab::Description),
+            Span::raw(" | "),
+            tab("History", DetailsTab::History),
+            Span::raw(" "),
+        ];
+        if self.details_tab == DetailsTab::History {
+            title.push(Span::styled(
+                if self.history_comments {
+                    "(H: description, c: hide comments) "
+                } else {
+                    "(H: description, c: show comments) "
+                },
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+
+        let description = Paragraph::new(Text::from(lines)).wrap(Wrap { trim: false });
+
+        let inner_width = area.width.saturating_sub(2);
+        let visible = area.height.saturating_sub(2);
+        let total = description.line_count(inner_width) as u16;
+        self.description_size.set((total, visible));
+
+        let scroll = self.description_scroll.min(total.saturating_sub(visible));
+        let position = if total <= visible {
+            "All".to_string()
+        } else {
+            format!(
+                "{}-{}/{} {}%",
+                scroll + 1,
+                (scroll + visible).min(total),
+                total,
+                (scroll + visible).min(total) as u32 * 100 / total as u32
+            )
+        };
+
+        let description_widget = description.scroll((scroll, 0)).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(Line::from(title))
+                .title_bottom(Line::from(format!(" {} ", position)).alignment(Alignment::Right))
+                .border_style(Style::default().fg(Color::Gray)),
+        );
 
         f.render_widget(description_widget, area);
     }
 
     fn render_details_status_bar(&self, f: &mut Frame, area: ratatui::layout::Rect) {
         let status_text =
-            "Press 'd' to return to list view | Press 'v' to open in browser | Press ? for help";
+            "j/k, PgUp/PgDn: Scroll | H: History | Press 'z' to return to the list | Press 'v' to open in browser | Press ? for help";
 
         let status = Paragraph::new(status_text)
             .style(Style::default().fg(Color::White))
@@ -667,3 +1628,125 @@ impl IssuesComponent {
         f.render_widget(status, area);
     }
 }
+
+/// Text and style of an issue's cell in the issues table.
+fn issue_cell(issue: &Issue, column: IssueColumn) -> (String, Style) {
+    match column {
+        IssueColumn::Identifier => (
+            issue.identifier.clone(),
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        ),
+        IssueColumn::Priority => {
+            let (symbol, color) = match issue.priority.unwrap_or(0) {
+                1 => ("🔴", Color::Red),
+                2 => ("🟠", Color::Yellow),
+                3 => ("🟢", Color::Green),
+                4 => ("🔵", Color::Blue),
+                _ => ("⚪", Color::White),
+            };
+            (symbol.to_string(), Style::default().fg(color))
+        }
+        IssueColumn::Title => (issue.title.clone(), Style::default().fg(Color::White)),
+        IssueColumn::State => {
+            let color = match issue.state.state_type.as_str() {
+                "completed" => Color::Green,
+                "started" => Color::Yellow,
+                "unstarted" => Color::Gray,
+                "canceled" => Color::Red,
+                _ => Color::White,
+            };
+            (
+                issue.state.name.clone(),
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            )
+        }
+        IssueColumn::Assignee => (
+            issue
+                .assignee
+                .as_ref()
+                .map(|a| a.display_name.clone())
+                .unwrap_or_else(|| "Unassigned".to_string()),
+            Style::default().fg(Color::Gray),
+        ),
+        IssueColumn::Created => (
+            format_duration_since(&issue.created_at),
+            Style::default().fg(Color::Gray),
+        ),
+        IssueColumn::Updated => (
+            format_duration_since(&issue.updated_at),
+            Style::default().fg(Color::Gray),
+        ),
+        IssueColumn::DueDate => {
+            let overdue = issue
+                .due_date
+                .is_some_and(|d| d < chrono::Local::now().date_naive())
+                && !matches!(issue.state.state_type.as_str(), "completed" | "canceled");
+            let color = if overdue { Color::Red } else { Color::Gray };
+            (column.value(issue), Style::default().fg(color))
+        }
+        _ => (column.value(issue), Style::default().fg(Color::Gray)),
+    }
+}
+
+/// Height of the relations block of the details, none without relations.
+fn relations_height(issue: &Issue) -> u16 {
+    match issue.links().len() {
+        0 => 0,
+        count => count.min(6) as u16 + 2,
+    }
+}
+
+/// Height of the attachments block of the details, none without attachments.
+fn attachments_height(issue: &Issue) -> u16 {
+    match issue.attachments.len() {
+        0 => 0,
+        count => count.min(4) as u16 + 2,
+    }
+}
+
+fn column_alignment(column: IssueColumn) -> Alignment {
+    match column {
+        IssueColumn::Identifier | IssueColumn::State | IssueColumn::Estimate => Alignment::Right,
+        IssueColumn::Priority => Alignment::Center,
+        _ => Alignment::Left,
+    }
+}
+
+/// Truncate or pad text to exactly `width` terminal cells.
+pub(crate) fn fit(text: &str, width: u16, alignment: Alignment) -> String {
+    let width = width as usize;
+    let mut fitted = String::new();
+    let mut used = 0;
+    let total: usize = text.chars().map(|c| c.width().unwrap_or(0)).sum();
+    let limit = if total > width {
+        width.saturating_sub(1)
+    } else {
+        width
+    };
+    for c in text.chars() {
+        let w = c.width().unwrap_or(0);
+        if used + w > limit {
+            break;
+        }
+        fitted.push(c);
+        used += w;
+    }
+    if total > width && width > 0 {
+        fitted.push('…');
+        used += 1;
+    }
+
+    let padding = width.saturating_sub(used);
+    match alignment {
+        Alignment::Left => format!("{}{}", fitted, " ".repeat(padding)),
+        Alignment::Right => format!("{}{}", " ".repeat(padding), fitted),
+        Alignment::Center => format!(
+            "{}{}{}",
+            " ".repeat(padding / 2),
+            fitted,
+            " ".repeat(padding - padding / 2)
+        ),
+    }
+}
@@ -0,0 +1,208 @@
+// SPDX-FileCopyrightText: Copyright (C) Nicolas Lamirault <nicolas.lamirault@gmail.com>
+// SPDX-License-Identifier: Apache-2.0
+
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+use std::collections::HashSet;
+use unicode_width::UnicodeWidthStr;
+
+use crate::client::IssueLabel;
+use crate::ui::components::prompt::centered_rect;
+
+/// Style of a label chip: the label's color as background, with readable text on it.
+pub fn label_style(label: &IssueLabel) -> Style {
+    let color = label.color.parse::<Color>().unwrap_or(Color::Magenta);
+    let text = match color {
+        // Perceived brightness, dark text on light colors
+        Color::Rgb(r, g, b) if (299 * r as u32 + 587 * g as u32 + 114 * b as u32) / 1000 > 140 => {
+            Color::Black
+        }
+        _ => Color::White,
+    };
+    Style::default().bg(color).fg(text)
+}
+
+/// Labels as colored chips, padded or cut to exactly `width` cells. Labels that do not fit are
+/// counted as "+N".
+pub fn label_chips(labels: &[IssueLabel], width: u16) -> Vec<Span<'static>> {
+    let width = width as usize;
+    let mut spans = Vec::new();
+    let mut used = 0;
+
+    for (n, label) in labels.iter().enumerate() {
+        let chip = format!(" {} ", label.name);
+        let separator = usize::from(n > 0);
+        let rest = labels.len() - n - 1;
+        // Keep room for the "+N" of the labels after this one
+        let reserve = if rest > 0 {
+            format!(" +{}", rest).len()
+        } else {
+            0
+        };
+        if used + separator + chip.width() + reserve > width {
+            let more = format!("+{}", labels.len() - n);
+            if used + separator + more.len() <= width {
+                spans.push(Span::raw(" ".repeat(separator)));
+                spans.push(Span::styled(more.clone(), Style::default().fg(Color::Gray)));
+                used += separator + more.len();
+            }
+            break;
+        }
+        spans.push(Span::raw(" ".repeat(separator)));
+        spans.push(Span::styled(chip.clone(), label_style(label)));
+        used += separator + chip.width();
+    }
+
+    spans.push(Span::raw(" ".repeat(width.saturating_sub(used))));
+    spans
+}
+
+pub enum LabelPickerAction {
+    None,
+    /// Ids of the checked labels.
+    Submit(HashSet<String>),
+    Cancel,
+}
+
+/// Checklist of labels shown as a popup, narrowed down by typing.
+pub struct LabelPickerComponent {
+    pub title: String,
+    labels: Vec<IssueLabel>,
+    checked: HashSet<String>,
+    filter: String,
+    state: ListState,
+}
+
+impl LabelPickerComponent {
+    pub fn new(title: impl Into<String>, mut labels: Vec<IssueLabel>, checked: &[String]) -> Self {
+        labels.sort_by_key(|l| l.name.to_lowercase());
+        let mut state = ListState::default();
+        if !labels.is_empty() {
+            state.select(Some(0));
+        }
+        Self {
+            title: title.into(),
+            labels,
+            checked: checked.iter().cloned().collect(),
+            filter: String::new(),
+            state,
+        }
+    }
+
+    pub fn labels(&self) -> &[IssueLabel] {
+        &self.labels
+    }
+
+    fn visible(&self) -> Vec<&IssueLabel> {
+        let filter = self.filter.to_lowercase();
+        self.labels
+            .iter()
+            .filter(|l| l.name.to_lowercase().contains(&filter))
+            .collect()
+    }
+
+    fn move_selection(&mut self, offset: isize) {
+        let len = self.visible().len();
+        if len == 0 {
+            self.state.select(None);
+            return;
+        }
+        let current = self.state.selected().unwrap_or(0) as isize;
+        self.state
+            .select(Some((current + offset).rem_euclid(len as isize) as usize));
+    }
+
+    pub fn handle_key(&mut self, key_code: KeyCode) -> LabelPickerAction {
+        match key_code {
+            KeyCode::Enter => return LabelPickerAction::Submit(self.checked.clone()),
+            KeyCode::Esc => return LabelPickerAction::Cancel,
+            KeyCode::Down => self.move_selection(1),
+            KeyCode::Up => self.move_selection(-1),
+            KeyCode::Char(' ') => {
+                let id = self
+                    .state
+                    .selected()
+                    .and_then(|i| self.visible().get(i).map(|l| l.id.clone()));
+                if let Some(id) = id {
+                    if !self.checked.remove(&id) {
+                        self.checked.insert(id);
+                    }
+                }
+            }
+            KeyCode::Backspace => {
+                self.filter.pop();
+                self.state.select(Some(0));
+                self.move_selection(0);
+            }
+            KeyCode::Char(c) => {
+                self.filter.push(c);
+                self.state.select(Some(0));
+                self.move_selection(0);
+            }
+            _ => {}
+        }
+        LabelPickerAction::None
+    }
+
+    pub fn render(&mut self, f: &mut Frame, area: Rect) {
+        let height = (self.labels.len() as u16 + 6).min(area.height.saturating_sub(4).max(8));
+        let popup = centered_rect(50, height, area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" {} ", self.title))
+            .title_style(
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .border_style(Style::default().fg(Color::Blue));
+        let inner = block.inner(popup);
+        f.render_widget(Clear, popup);
+        f.render_widget(block, popup);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1),
+                Constraint::Min(1),
+                Constraint::Length(1),
+            ])
+            .split(inner);
+
+        let filter = Paragraph::new(Line::from(vec![
+            Span::styled("Filter: ", Style::default().fg(Color::Gray)),
+            Span::styled(&self.filter, Style::default().fg(Color::White)),
+            Span::styled("█", Style::default().fg(Color::Yellow)),
+        ]));
+        f.render_widget(filter, chunks[0]);
+
+        let items: Vec<ListItem> = self
+            .visible()
+            .iter()
+            .map(|label| {
+                let mark = if self.checked.contains(&label.id) {
+                    "[x] "
+                } else {
+                    "[ ] "
+                };
+                ListItem::new(Line::from(vec![
+                    Span::styled(mark, Style::default().fg(Color::Yellow)),
+                    Span::styled(format!(" {} ", label.name), label_style(label)),
+                ]))
+            })
+            .collect();
+        let list = List::new(items).highlight_style(Style::default().bg(Color::DarkGray));
+        f.render_stateful_widget(list, chunks[1], &mut self.state);
+
+        let help = Paragraph::new("Space: check | Enter: apply | Esc: cancel")
+            .style(Style::default().fg(Color::Gray));
+        f.render_widget(help, chunks[2]);
+    }
+}
@@ -1,12 +1,32 @@
 // SPDX-FileCopyrightText: Copyright (C) Nicolas Lamirault <nicolas.lamirault@gmail.com>
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod board;
+pub mod cycles;
+pub mod dependencies;
 pub mod help;
+pub mod history;
+pub mod inbox;
 pub mod issues;
+pub mod labels;
+pub mod my_issues;
 pub mod projects;
+pub mod prompt;
+pub mod report;
 pub mod teams;
+pub mod workload;
 
+pub use board::*;
+pub use cycles::*;
+pub use dependencies::*;
 pub use help::*;
+pub use history::*;
+pub use inbox::*;
 pub use issues::*;
+pub use labels::*;
+pub use my_issues::*;
 pub use projects::*;
+pub use prompt::*;
+pub use report::*;
 pub use teams::*;
+pub use workload::*;
@@ -0,0 +1,157 @@
+// SPDX-FileCopyrightText: Copyright (C) Nicolas Lamirault <nicolas.lamirault@gmail.com>
+// SPDX-License-Identifier: Apache-2.0
+
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, Tabs},
+    Frame,
+};
+use serde_json::{json, Value};
+
+use crate::client::{Issue, User};
+use crate::config::Config;
+use crate::ui::components::IssuesComponent;
+use crate::utils::IssueColumn;
+
+/// How the viewer is related to the issues of a sub-tab.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Relation {
+    Assigned,
+    Created,
+    Subscribed,
+}
+
+impl Relation {
+    pub const ALL: [Relation; 3] = [Relation::Assigned, Relation::Created, Relation::Subscribed];
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            Relation::Assigned => "Assigned",
+            Relation::Created => "Created",
+            Relation::Subscribed => "Subscribed",
+        }
+    }
+
+    /// The `IssueFilter` of the viewer's open issues with this relation, in every team.
+    pub fn filter(&self, viewer_id: &str) -> Value {
+        let viewer = json!({ "id": { "eq": viewer_id } });
+        let relation = match self {
+            Relation::Assigned => json!({ "assignee": viewer }),
+            Relation::Created => json!({ "creator": viewer }),
+            Relation::Subscribed => json!({ "subscribers": { "some": viewer } }),
+        };
+        json!({
+            "and": [
+                relation,
+                { "state": { "type": { "nin": ["completed", "canceled"] } } }
+            ]
+        })
+    }
+}
+
+/// The viewer's issues across all teams, with a sub-tab per relation.
+pub struct MyIssuesComponent {
+    pub viewer: Option<User>,
+    pub relation: Relation,
+    tables: Vec<IssuesComponent>,
+}
+
+impl MyIssuesComponent {
+    pub fn with_config(config: &Config) -> Self {
+        let tables = Relation::ALL
+            .iter()
+            .map(|relation| {
+                let mut table = IssuesComponent::with_config(config);
+                table.name = Some(format!("My Issues - {}", relation.title()));
+                // Issues come from every team, show which one
+                if !table.columns.contains(&IssueColumn::Team) {
+                    let at = table
+                        .columns
+                        .iter()
+                        .position(|c| *c == IssueColumn::Identifier)
+                        .map_or(0, |i| i + 1);
+                    table.columns.insert(at, IssueColumn::Team);
+                }
+                table
+            })
+            .collect();
+
+        Self {
+            viewer: None,
+            relation: Relation::Assigned,
+            tables,
+        }
+    }
+
+    fn index(relation: Relation) -> usize {
+        Relation::ALL
+            .iter()
+            .position(|r| *r == relation)
+            .unwrap_or(0)
+    }
+
+    pub fn next_relation(&mut self) {
+        let index = (Self::index(self.relation) + 1) % Relation::ALL.len();
+        self.relation = Relation::ALL[index];
+    }
+
+    pub fn previous_relation(&mut self) {
+        let len = Relation::ALL.len();
+        let index = (Self::index(self.relation) + len - 1) % len;
+        self.relation = Relation::ALL[index];
+    }
+
+    pub fn update_issues(&mut self, relation: Relation, issues: Vec<Issue>) {
+        self.tables[Self::index(relation)].update_issues(issues);
+    }
+
+    /// The issues table of the current sub-tab.
+    pub fn current(&self) -> &IssuesComponent {
+        &self.tables[Self::index(self.relation)]
+    }
+
+    pub fn current_mut(&mut self) -> &mut IssuesComponent {
+        &mut self.tables[Self::index(self.relation)]
+    }
+
+    pub fn tables_mut(&mut self) -> impl Iterator<Item = &mut IssuesComponent> {
+        self.tables.iter_mut()
+    }
+
+    pub fn render(&mut self, f: &mut Frame, area: Rect) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(0)])
+            .split(area);
+
+        let titles: Vec<String> = Relation::ALL
+            .iter()
+            .zip(&self.tables)
+            .map(|(relation, table)| format!("{} ({})", relation.title(), table.issues().len()))
+            .collect();
+        let viewer = self
+            .viewer
+            .as_ref()
+            .map(|v| format!(" {} - h/l: switch ", v.display_name))
+            .unwrap_or_else(|| " h/l: switch ".to_string());
+
+        let tabs = Tabs::new(titles)
+            .select(Self::index(self.relation))
+            .style(Style::default().fg(Color::White))
+            .highlight_style(
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(viewer)
+                    .border_style(Style::default().fg(Color::Blue)),
+            );
+        f.render_widget(tabs, chunks[0]);
+
+        self.current_mut().render(f, chunks[1]);
+    }
+}
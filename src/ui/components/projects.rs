@@ -5,15 +5,34 @@ use ratatui::{
     layout::{Constraint, Direction, Layout},
     style::{Color, Modifier, Style},
     text::{Line, Span, Text},
-    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
     Frame,
 };
 
-use crate::client::Project;
+use crate::client::{Issue, Project, ProjectDetails};
+use crate::config::Config;
+use crate::ui::components::{DependencyGraph, IssuesComponent};
+use crate::ui::markdown;
+use crate::utils::format_duration_since;
+
+const PROGRESS_WIDTH: usize = 20;
 
 pub struct ProjectsComponent {
     pub projects: Vec<Project>,
     pub state: ListState,
+    /// Project opened with Enter, shown instead of the list.
+    pub detail: Option<ProjectDetails>,
+    /// Issues of the opened project.
+    pub issues: IssuesComponent,
+    /// Show the dependency graph of the project's issues instead of their list.
+    pub graph: bool,
+    pub graph_scroll: u16,
+}
+
+impl Default for ProjectsComponent {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl ProjectsComponent {
@@ -21,9 +40,50 @@ impl ProjectsComponent {
         Self {
             projects: Vec::new(),
             state: ListState::default(),
+            detail: None,
+            issues: IssuesComponent::new(),
+            graph: false,
+            graph_scroll: 0,
+        }
+    }
+
+    pub fn with_config(config: &Config) -> Self {
+        let mut issues = IssuesComponent::with_config(config);
+        // The details pane would leave little room under the project overview
+        issues.show_details = false;
+        Self {
+            issues,
+            ..Self::new()
         }
     }
 
+    /// Show the detail screen of a project, with its issues.
+    pub fn open_project(&mut self, mut project: ProjectDetails) {
+        self.issues.name = Some(project.project.name.clone());
+        self.issues
+            .show_query(None, std::mem::take(&mut project.issues));
+        self.detail = Some(project);
+    }
+
+    pub fn close_project(&mut self) {
+        self.detail = None;
+        self.graph = false;
+    }
+
+    pub fn toggle_graph(&mut self) {
+        self.graph = !self.graph;
+        self.graph_scroll = 0;
+    }
+
+    pub fn scroll_graph(&mut self, lines: i32) {
+        self.graph_scroll = (self.graph_scroll as i32 + lines).max(0) as u16;
+    }
+
+    /// Replace an issue after it was updated, in the issues of the opened project.
+    pub fn replace_issue(&mut self, issue: Issue) {
+        self.issues.replace_issue(issue);
+    }
+
     pub fn select_next(&mut self) {
         let i = match self.state.selected() {
             Some(i) => {
@@ -72,6 +132,11 @@ impl ProjectsComponent {
     }
 
     pub fn render(&mut self, f: &mut Frame, area: ratatui::layout::Rect) {
+        if self.detail.is_some() {
+            self.render_project_details(f, area);
+            return;
+        }
+
         // Create 4-section layout similar to g1c dashboard
         let main_chunks = Layout::default()
             .direction(Direction::Vertical)
@@ -345,6 +410,258 @@ impl ProjectsComponent {
         f.render_widget(status, area);
     }
 
+    fn render_project_details(&mut self, f: &mut Frame, area: ratatui::layout::Rect) {
+        let Some(project) = &self.detail else {
+            return;
+        };
+
+        let main_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Title bar
+                Constraint::Length(8), // Overview and description
+                Constraint::Length(7), // Milestones and latest update
+                Constraint::Min(6),    // Issues
+                Constraint::Length(3), // Status bar
+            ])
+            .split(area);
+        let columns = |area| {
+            Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(45), Constraint::Percentage(55)])
+                .split(area)
+        };
+        let (top, middle) = (columns(main_chunks[1]), columns(main_chunks[2]));
+
+        let title = Paragraph::new(format!("🚀 {}", project.project.name))
+            .style(
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Blue)),
+            );
+        f.render_widget(title, main_chunks[0]);
+
+        f.render_widget(Self::overview(project), top[0]);
+
+        let description = match project
+            .project
+            .description
+            .as_deref()
+            .filter(|d| !d.trim().is_empty())
+        {
+            Some(description) => Text::from(markdown::render(description)),
+            None => Text::styled("No description", Style::default().fg(Color::Gray)),
+        };
+        let description = Paragraph::new(description)
+            .wrap(Wrap { trim: false })
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(" Description ")
+                    .border_style(Style::default().fg(Color::Gray)),
+            );
+        f.render_widget(description, top[1]);
+
+        f.render_widget(Self::milestones(project, self.issues.issues()), middle[0]);
+        f.render_widget(Self::latest_update(project), middle[1]);
+
+        let status_text = if self.graph {
+            let lines = DependencyGraph::new(self.issues.issues()).lines();
+            let visible = main_chunks[3].height.saturating_sub(2);
+            self.graph_scroll = self
+                .graph_scroll
+                .min((lines.len() as u16).saturating_sub(visible));
+            let graph = Paragraph::new(lines).scroll((self.graph_scroll, 0)).block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(" Dependencies ")
+                    .border_style(Style::default().fg(Color::Gray)),
+            );
+            f.render_widget(graph, main_chunks[3]);
+            "j/k: Scroll | G: Issues | Esc: Back | Press ? for help"
+        } else {
+            self.issues.render_embedded(f, main_chunks[3]);
+            "j/k: Navigate issues | Enter: Issue details | G: Dependency graph | Esc: Back | Press ? for help"
+        };
+
+        let status = Paragraph::new(status_text)
+            .style(Style::default().fg(Color::White))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(" Status ")
+                    .border_style(Style::default().fg(Color::Gray)),
+            );
+        f.render_widget(status, main_chunks[4]);
+    }
+
+    fn overview(project: &ProjectDetails) -> Paragraph<'_> {
+        let status_color = match project.project.status.status_type.as_str() {
+            "completed" => Color::Green,
+            "started" => Color::Yellow,
+            "planned" => Color::Blue,
+            "canceled" => Color::Red,
+            _ => Color::Gray,
+        };
+        let lead = project
+            .project
+            .lead
+            .as_ref()
+            .map(|l| l.display_name.as_str())
+            .unwrap_or("No lead");
+        let members = if project.members.is_empty() {
+            "None".to_string()
+        } else {
+            project
+                .members
+                .iter()
+                .map(|m| m.display_name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let date = |date: Option<chrono::NaiveDate>| {
+            date.map(|d| d.format("%Y-%m-%d").to_string())
+                .unwrap_or_else(|| "-".to_string())
+        };
+        let progress = project.progress.clamp(0.0, 1.0);
+        let filled = (progress * PROGRESS_WIDTH as f64).round() as usize;
+
+        let text = Text::from(vec![
+            Line::from(vec![
+                Span::styled("Status: ", Style::default().fg(Color::Gray)),
+                Span::styled(
+                    &project.project.status.name,
+                    Style::default().fg(status_color),
+                ),
+            ]),
+            Line::from(vec![
+                Span::styled("Lead: ", Style::default().fg(Color::Gray)),
+                Span::styled(lead, Style::default().fg(Color::White)),
+            ]),
+            Line::from(vec![
+                Span::styled("Members: ", Style::default().fg(Color::Gray)),
+                Span::styled(members, Style::default().fg(Color::White)),
+            ]),
+            Line::from(vec![
+                Span::styled("Dates: ", Style::default().fg(Color::Gray)),
+                Span::styled(
+                    format!(
+                        "{} → {}",
+                        date(project.start_date),
+                        date(project.target_date)
+                    ),
+                    Style::default().fg(Color::White),
+                ),
+            ]),
+            Line::from(vec![
+                Span::styled("Progress: ", Style::default().fg(Color::Gray)),
+                Span::styled("█".repeat(filled), Style::default().fg(Color::Green)),
+                Span::styled(
+                    "░".repeat(PROGRESS_WIDTH - filled),
+                    Style::default().fg(Color::DarkGray),
+                ),
+                Span::styled(
+                    format!(" {:.0}%", progress * 100.0),
+                    Style::default().fg(Color::White),
+                ),
+            ]),
+        ]);
+
+        Paragraph::new(text).wrap(Wrap { trim: true }).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" Overview ")
+                .border_style(Style::default().fg(Color::Gray)),
+        )
+    }
+
+    fn milestones<'a>(project: &'a ProjectDetails, issues: &[Issue]) -> Paragraph<'a> {
+        let lines: Vec<Line> = if project.milestones.is_empty() {
+            vec![Line::styled(
+                "No milestones",
+                Style::default().fg(Color::Gray),
+            )]
+        } else {
+            project
+                .milestones
+                .iter()
+                .map(|milestone| {
+                    let (done, total) = milestone.completion(issues);
+                    let color = if total > 0 && done == total {
+                        Color::Green
+                    } else {
+                        Color::Yellow
+                    };
+                    let target = milestone
+                        .target_date
+                        .map(|d| format!(" ({})", d.format("%b %d")))
+                        .unwrap_or_default();
+                    Line::from(vec![
+                        Span::styled(format!("◆ {}", milestone.name), Style::default().fg(color)),
+                        Span::styled(target, Style::default().fg(Color::Gray)),
+                        Span::styled(
+                            format!(" {}/{}", done, total),
+                            Style::default().fg(Color::White),
+                        ),
+                    ])
+                })
+                .collect()
+        };
+
+        Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(" Milestones ({}) ", project.milestones.len()))
+                .border_style(Style::default().fg(Color::Gray)),
+        )
+    }
+
+    fn latest_update(project: &ProjectDetails) -> Paragraph<'_> {
+        let Some(update) = project.latest_update() else {
+            return Paragraph::new(Line::styled("No updates", Style::default().fg(Color::Gray)))
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title(" Latest Update ")
+                        .border_style(Style::default().fg(Color::Gray)),
+                );
+        };
+
+        let (health, color) = match update.health.as_deref() {
+            Some("onTrack") => ("On track", Color::Green),
+            Some("atRisk") => ("At risk", Color::Yellow),
+            Some("offTrack") => ("Off track", Color::Red),
+            _ => ("No health", Color::Gray),
+        };
+        let author = update
+            .user
+            .as_ref()
+            .map(|u| u.display_name.as_str())
+            .unwrap_or("Unknown");
+
+        Paragraph::new(Text::from(markdown::render(&update.body)))
+            .wrap(Wrap { trim: false })
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(Line::from(vec![
+                        Span::raw(" Latest Update - "),
+                        Span::styled(health, Style::default().fg(color)),
+                        Span::raw(format!(
+                            " - {}, {} ",
+                            author,
+                            format_duration_since(&update.created_at)
+                        )),
+                    ]))
+                    .border_style(Style::default().fg(Color::Gray)),
+            )
+    }
+
     fn truncate_text(&self, text: &str, max_width: usize) -> String {
         if text.len() <= max_width {
             text.to_string()
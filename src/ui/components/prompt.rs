@@ -0,0 +1,122 @@
+// SPDX-FileCopyrightText: Copyright (C) Nicolas Lamirault <nicolas.lamirault@gmail.com>
+// SPDX-License-Identifier: Apache-2.0
+
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+pub enum PromptAction {
+    None,
+    Submit(String),
+    Cancel,
+}
+
+/// Single line text input shown as a popup over the current view.
+pub struct PromptComponent {
+    pub title: String,
+    pub input: String,
+    pub error: Option<String>,
+    /// Checks the input as it is typed, returning the error to show.
+    validator: Option<fn(&str) -> Option<String>>,
+}
+
+impl PromptComponent {
+    pub fn new(title: impl Into<String>, initial: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            input: initial.into(),
+            error: None,
+            validator: None,
+        }
+    }
+
+    pub fn with_validator(mut self, validator: fn(&str) -> Option<String>) -> Self {
+        self.validator = Some(validator);
+        self.validate();
+        self
+    }
+
+    fn validate(&mut self) {
+        self.error = self.validator.and_then(|validator| validator(&self.input));
+    }
+
+    pub fn handle_key(&mut self, key_code: KeyCode) -> PromptAction {
+        match key_code {
+            KeyCode::Enter => PromptAction::Submit(self.input.clone()),
+            KeyCode::Esc => PromptAction::Cancel,
+            KeyCode::Backspace => {
+                self.input.pop();
+                self.validate();
+                PromptAction::None
+            }
+            KeyCode::Char(c) => {
+                self.input.push(c);
+                self.validate();
+                PromptAction::None
+            }
+            _ => PromptAction::None,
+        }
+    }
+
+    pub fn render(&self, f: &mut Frame, area: Rect) {
+        let popup = centered_rect(60, 5, area);
+
+        let mut lines = vec![Line::from(vec![
+            Span::styled(&self.input, Style::default().fg(Color::White)),
+            Span::styled("█", Style::default().fg(Color::Yellow)),
+        ])];
+
+        if let Some(error) = &self.error {
+            lines.push(Line::from(Span::styled(
+                error,
+                Style::default().fg(Color::Red),
+            )));
+        } else {
+            lines.push(Line::from(Span::styled(
+                "Enter to confirm, Esc to cancel",
+                Style::default().fg(Color::Gray),
+            )));
+        }
+
+        let prompt = Paragraph::new(Text::from(lines)).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(" {} ", self.title))
+                .title_style(
+                    Style::default()
+                        .fg(Color::Cyan)
+                        .add_modifier(Modifier::BOLD),
+                )
+                .border_style(Style::default().fg(Color::Blue)),
+        );
+
+        f.render_widget(Clear, popup);
+        f.render_widget(prompt, popup);
+    }
+}
+
+/// Rectangle of the given width percentage and height, centered in `area`.
+pub fn centered_rect(percent_x: u16, height: u16, area: Rect) -> Rect {
+    let vertical = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(0),
+            Constraint::Length(height.min(area.height)),
+            Constraint::Min(0),
+        ])
+        .split(area);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(vertical[1])[1]
+}
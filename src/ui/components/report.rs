@@ -0,0 +1,103 @@
+// SPDX-FileCopyrightText: Copyright (C) Nicolas Lamirault <nicolas.lamirault@gmail.com>
+// SPDX-License-Identifier: Apache-2.0
+
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::report::{self, ReportFormat, Standup};
+
+/// Standup report shown as a popup over the current view.
+pub struct ReportComponent {
+    pub standup: Standup,
+    pub format: ReportFormat,
+    scroll: u16,
+}
+
+impl ReportComponent {
+    pub fn new(standup: Standup) -> Self {
+        Self {
+            standup,
+            format: ReportFormat::Markdown,
+            scroll: 0,
+        }
+    }
+
+    fn text(&self) -> String {
+        report::render(&self.standup, self.format)
+    }
+
+    /// Handle a key press, returning false when the popup should close.
+    pub fn handle_key(&mut self, key_code: KeyCode) -> bool {
+        match key_code {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('R') => return false,
+            KeyCode::Tab => {
+                self.format = match self.format {
+                    ReportFormat::Markdown => ReportFormat::Slack,
+                    ReportFormat::Slack => ReportFormat::Markdown,
+                };
+                self.scroll = 0;
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.scroll = self.scroll.saturating_add(1);
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.scroll = self.scroll.saturating_sub(1);
+            }
+            KeyCode::PageDown => {
+                self.scroll = self.scroll.saturating_add(10);
+            }
+            KeyCode::PageUp => {
+                self.scroll = self.scroll.saturating_sub(10);
+            }
+            _ => {}
+        }
+        true
+    }
+
+    pub fn render(&self, f: &mut Frame, area: Rect) {
+        let popup = Rect {
+            x: area.x + area.width / 10,
+            y: area.y + 2,
+            width: area.width - area.width / 5,
+            height: area.height.saturating_sub(4),
+        };
+
+        let format = match self.format {
+            ReportFormat::Markdown => "Markdown",
+            ReportFormat::Slack => "Slack",
+        };
+
+        let report = Paragraph::new(self.text())
+            .style(Style::default().fg(Color::White))
+            .wrap(Wrap { trim: false })
+            .scroll((self.scroll, 0))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!(" Standup ({}) ", format))
+                    .title_bottom(Line::from(vec![
+                        Span::styled(" Tab", Style::default().fg(Color::Cyan)),
+                        Span::raw(" Markdown/Slack  "),
+                        Span::styled("j/k", Style::default().fg(Color::Cyan)),
+                        Span::raw(" scroll  "),
+                        Span::styled("Esc", Style::default().fg(Color::Cyan)),
+                        Span::raw(" close "),
+                    ]))
+                    .title_style(
+                        Style::default()
+                            .fg(Color::Cyan)
+                            .add_modifier(Modifier::BOLD),
+                    )
+                    .border_style(Style::default().fg(Color::Blue)),
+            );
+
+        f.render_widget(Clear, popup);
+        f.render_widget(report, popup);
+    }
+}
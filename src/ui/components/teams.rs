@@ -5,15 +5,36 @@ use ratatui::{
     layout::{Constraint, Direction, Layout},
     style::{Color, Modifier, Style},
     text::{Line, Span, Text},
-    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
     Frame,
 };
+use std::collections::HashMap;
 
-use crate::client::Team;
+use crate::client::{Team, TeamDetails, TeamStats};
+use crate::ui::components::label_style;
+use crate::utils::state_type_rank;
+
+/// State types of open issues, in the order of the teams list columns.
+pub(crate) const OPEN_STATE_TYPES: [(&str, &str); 4] = [
+    ("triage", "TRIAGE"),
+    ("backlog", "BACKLOG"),
+    ("unstarted", "TODO"),
+    ("started", "STARTED"),
+];
 
 pub struct TeamsComponent {
     pub teams: Vec<Team>,
     pub state: ListState,
+    /// Member count and open issues, by team id.
+    pub stats: HashMap<String, TeamStats>,
+    /// Team opened with `d`, shown instead of the list.
+    pub detail: Option<TeamDetails>,
+}
+
+impl Default for TeamsComponent {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl TeamsComponent {
@@ -21,6 +42,8 @@ impl TeamsComponent {
         Self {
             teams: Vec::new(),
             state: ListState::default(),
+            stats: HashMap::new(),
+            detail: None,
         }
     }
 
@@ -71,7 +94,36 @@ impl TeamsComponent {
         }
     }
 
+    pub fn update_stats(&mut self, stats: HashMap<String, TeamStats>) {
+        self.stats = stats;
+    }
+
+    /// Show the detail screen of a team.
+    pub fn open_team(&mut self, mut team: TeamDetails) {
+        team.states.sort_by(|a, b| {
+            state_type_rank(&a.state_type)
+                .cmp(&state_type_rank(&b.state_type))
+                .then(
+                    a.position
+                        .unwrap_or(0.0)
+                        .total_cmp(&b.position.unwrap_or(0.0)),
+                )
+        });
+        team.members.sort_by_key(|m| m.display_name.to_lowercase());
+        team.labels.sort_by_key(|l| l.name.to_lowercase());
+        self.detail = Some(team);
+    }
+
+    pub fn close_team(&mut self) {
+        self.detail = None;
+    }
+
     pub fn render(&mut self, f: &mut Frame, area: ratatui::layout::Rect) {
+        if let Some(team) = &self.detail {
+            Self::render_team_details(f, area, team);
+            return;
+        }
+
         // Create 4-section layout similar to g1c dashboard
         let main_chunks = Layout::default()
             .direction(Direction::Vertical)
@@ -113,12 +165,21 @@ impl TeamsComponent {
 
     fn render_overview_panel(&self, f: &mut Frame, area: ratatui::layout::Rect) {
         let total_teams = self.teams.len();
-        let teams_with_descriptions = self
-            .teams
-            .iter()
-            .filter(|t| t.description.is_some() && !t.description.as_ref().unwrap().is_empty())
-            .count();
-        let teams_without_descriptions = total_teams - teams_with_descriptions;
+        let members: usize = self.stats.values().map(|s| s.members).sum();
+        let open: usize = self.stats.values().map(|s| s.open_total()).sum();
+
+        let mut by_type = vec![Span::styled("Open - ", Style::default().fg(Color::Gray))];
+        for (state_type, title) in OPEN_STATE_TYPES {
+            let count: usize = self.stats.values().map(|s| s.open(state_type)).sum();
+            by_type.push(Span::styled(
+                format!("{}: ", title.to_lowercase()),
+                Style::default().fg(Color::Yellow),
+            ));
+            by_type.push(Span::styled(
+                format!("{}  ", count),
+                Style::default().fg(Color::White),
+            ));
+        }
 
         let overview_text = Text::from(vec![
             Line::from(vec![
@@ -129,20 +190,14 @@ impl TeamsComponent {
                         .fg(Color::Green)
                         .add_modifier(Modifier::BOLD),
                 ),
+                Span::raw("  |  "),
+                Span::styled("Members: ", Style::default().fg(Color::Yellow)),
+                Span::styled(format!("{}", members), Style::default().fg(Color::Green)),
+                Span::raw("  |  "),
+                Span::styled("Open Issues: ", Style::default().fg(Color::Yellow)),
+                Span::styled(format!("{}", open), Style::default().fg(Color::Green)),
             ]),
-            Line::from(vec![
-                Span::styled("With Descriptions: ", Style::default().fg(Color::Yellow)),
-                Span::styled(
-                    format!("{}", teams_with_descriptions),
-                    Style::default().fg(Color::Green),
-                ),
-                Span::raw(" | "),
-                Span::styled("Without: ", Style::default().fg(Color::Yellow)),
-                Span::styled(
-                    format!("{}", teams_without_descriptions),
-                    Style::default().fg(Color::Red),
-                ),
-            ]),
+            Line::from(by_type),
         ]);
 
         let overview = Paragraph::new(overview_text).block(
@@ -177,11 +232,14 @@ impl TeamsComponent {
         // Calculate dynamic column widths based on available space
         let inner_width = area.width.saturating_sub(2); // Account for borders
         let key_width = 10; // Increased for better alignment
-        let name_width = 50; // Increased from 25 to 35 for longer team names
-        let desc_width = inner_width.saturating_sub(key_width + name_width + 6); // Account for separators
+        let name_width = 30;
+        let count_width = 8;
+        // Members and the open issues by state type, each after a separator
+        let counts_width = (count_width + 3) * (1 + OPEN_STATE_TYPES.len() as u16);
+        let desc_width = inner_width.saturating_sub(key_width + name_width + counts_width + 6); // Account for separators
 
         // Render column headers
-        self.render_teams_header(f, chunks[0], key_width, name_width, desc_width);
+        self.render_teams_header(f, chunks[0], key_width, name_width, count_width, desc_width);
 
         let items: Vec<ListItem> = self
             .teams
@@ -194,8 +252,29 @@ impl TeamsComponent {
                 let description = team.description.as_deref().unwrap_or("No description");
                 let truncated_name = self.truncate_text(&team.name, name_width as usize);
                 let truncated_desc = self.truncate_text(description, desc_width as usize);
-
-                let line = Line::from(vec![
+                let stats = self.stats.get(&team.id);
+                let count = |value: Option<usize>| {
+                    format!(
+                        "{:>width$} │ ",
+                        value.map_or("-".to_string(), |v| v.to_string()),
+                        width = count_width as usize
+                    )
+                };
+                let mut counts = vec![Span::styled(
+                    count(stats.map(|s| s.members)),
+                    Style::default().fg(Color::White),
+                )];
+                counts.extend(OPEN_STATE_TYPES.iter().map(|(state_type, _)| {
+                    let value = stats.map(|s| s.open(state_type));
+                    let color = if value.unwrap_or(0) == 0 {
+                        Color::DarkGray
+                    } else {
+                        Color::Yellow
+                    };
+                    Span::styled(count(value), Style::default().fg(color))
+                }));
+
+                let mut line = vec![
                     Span::styled(selection_indicator, Style::default().fg(Color::Yellow)),
                     Span::styled(
                         format!("{:>width$} ", team.key, width = (key_width - 1) as usize),
@@ -209,10 +288,14 @@ impl TeamsComponent {
                         Style::default().fg(Color::White),
                     ),
                     Span::raw("│ "),
-                    Span::styled(truncated_desc, Style::default().fg(Color::Gray)),
-                ]);
-
-                ListItem::new(line)
+                ];
+                line.extend(counts);
+                line.push(Span::styled(
+                    truncated_desc,
+                    Style::default().fg(Color::Gray),
+                ));
+
+                ListItem::new(Line::from(line))
             })
             .collect();
 
@@ -234,9 +317,13 @@ impl TeamsComponent {
         area: ratatui::layout::Rect,
         key_width: u16,
         name_width: u16,
+        count_width: u16,
         desc_width: u16,
     ) {
-        let header = Line::from(vec![
+        let style = Style::default()
+            .add_modifier(Modifier::BOLD)
+            .fg(Color::Blue);
+        let mut header = vec![
             Span::raw("  "), // Space for selection indicator
             Span::styled(
                 format!("{:>width$}", "KEY", width = (key_width - 1) as usize),
@@ -252,15 +339,21 @@ impl TeamsComponent {
                     .fg(Color::Blue),
             ),
             Span::raw(" │ "),
-            Span::styled(
-                format!("{:<width$}", "DESCRIPTION", width = desc_width as usize),
-                Style::default()
-                    .add_modifier(Modifier::BOLD)
-                    .fg(Color::Blue),
-            ),
-        ]);
+        ];
+        let titles = std::iter::once("MEMBERS").chain(OPEN_STATE_TYPES.iter().map(|(_, t)| *t));
+        for title in titles {
+            header.push(Span::styled(
+                format!("{:>width$}", title, width = count_width as usize),
+                style,
+            ));
+            header.push(Span::raw(" │ "));
+        }
+        header.push(Span::styled(
+            format!("{:<width$}", "DESCRIPTION", width = desc_width as usize),
+            style,
+        ));
 
-        let header_paragraph = Paragraph::new(header).block(
+        let header_paragraph = Paragraph::new(Line::from(header)).block(
             Block::default()
                 .borders(Borders::LEFT | Borders::RIGHT | Borders::TOP)
                 .border_style(Style::default().fg(Color::Gray)),
@@ -272,7 +365,7 @@ impl TeamsComponent {
         let status_text = if let Some(team) = self.selected_team() {
             let description = team.description.as_deref().unwrap_or("No description");
             format!(
-                "Selected: {} - {} | Press ? for help",
+                "Selected: {} - {} | Enter: Open the team's issues | d: Details | Press ? for help",
                 team.name, description
             )
         } else {
@@ -290,6 +383,184 @@ impl TeamsComponent {
         f.render_widget(status, area);
     }
 
+    fn render_team_details(f: &mut Frame, area: ratatui::layout::Rect, team: &TeamDetails) {
+        let main_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),      // Title bar
+                Constraint::Percentage(50), // Overview and workflow states
+                Constraint::Min(6),         // Members and labels
+                Constraint::Length(3),      // Status bar
+            ])
+            .split(area);
+        let columns = |area| {
+            Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .split(area)
+        };
+        let (top, bottom) = (columns(main_chunks[1]), columns(main_chunks[2]));
+        let block = |title: String| {
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .border_style(Style::default().fg(Color::Gray))
+        };
+
+        let title = Paragraph::new(format!("🏢 {} ({})", team.team.name, team.team.key))
+            .style(
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Blue)),
+            );
+        f.render_widget(title, main_chunks[0]);
+
+        // Overview: description, triage and cycles settings, active cycle
+        let enabled = |on: bool| {
+            if on {
+                Span::styled("enabled", Style::default().fg(Color::Green))
+            } else {
+                Span::styled("disabled", Style::default().fg(Color::DarkGray))
+            }
+        };
+        let mut triage = vec![
+            Span::styled("Triage: ", Style::default().fg(Color::Gray)),
+            enabled(team.triage_enabled),
+        ];
+        if team.triage_enabled && team.require_priority_to_leave_triage {
+            triage.push(Span::styled(
+                ", priority required to leave triage",
+                Style::default().fg(Color::White),
+            ));
+        }
+        let mut cycles = vec![
+            Span::styled("Cycles: ", Style::default().fg(Color::Gray)),
+            enabled(team.cycles_enabled),
+        ];
+        if team.cycles_enabled {
+            cycles.push(Span::styled(
+                format!(", {} weeks", team.cycle_duration),
+                Style::default().fg(Color::White),
+            ));
+        }
+        let active_cycle = match &team.active_cycle {
+            Some(cycle) => format!(
+                "{}, {} → {}, {}/{} done",
+                cycle.display_name(),
+                cycle.starts_at.format("%b %d"),
+                cycle.ends_at.format("%b %d"),
+                cycle.completed(),
+                cycle.scope()
+            ),
+            None => "None".to_string(),
+        };
+        let overview = Text::from(vec![
+            Line::from(Span::styled(
+                team.team
+                    .description
+                    .clone()
+                    .filter(|d| !d.is_empty())
+                    .unwrap_or_else(|| "No description".to_string()),
+                Style::default().fg(Color::White),
+            )),
+            Line::from(""),
+            Line::from(triage),
+            Line::from(cycles),
+            Line::from(vec![
+                Span::styled("Active cycle: ", Style::default().fg(Color::Gray)),
+                Span::styled(active_cycle, Style::default().fg(Color::Yellow)),
+            ]),
+        ]);
+        f.render_widget(
+            Paragraph::new(overview)
+                .wrap(Wrap { trim: true })
+                .block(block(" Overview ".to_string())),
+            top[0],
+        );
+
+        // Workflow states in their board order
+        let states: Vec<ListItem> = team
+            .states
+            .iter()
+            .map(|state| {
+                let color = state.color.parse::<Color>().unwrap_or(Color::White);
+                ListItem::new(Line::from(vec![
+                    Span::styled("● ", Style::default().fg(color)),
+                    Span::styled(
+                        format!("{:<24}", state.name),
+                        Style::default().fg(Color::White),
+                    ),
+                    Span::styled(
+                        format!("{:<10}", state.state_type),
+                        Style::default().fg(Color::Gray),
+                    ),
+                    Span::styled(
+                        state
+                            .position
+                            .map(|p| format!("#{}", p))
+                            .unwrap_or_default(),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                ]))
+            })
+            .collect();
+        f.render_widget(
+            List::new(states).block(block(format!(" Workflow States ({}) ", team.states.len()))),
+            top[1],
+        );
+
+        let members: Vec<ListItem> = team
+            .members
+            .iter()
+            .map(|member| {
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!("{:<24}", member.display_name),
+                        Style::default().fg(Color::White),
+                    ),
+                    Span::styled(
+                        member.email.clone().unwrap_or_default(),
+                        Style::default().fg(Color::Gray),
+                    ),
+                ]))
+            })
+            .collect();
+        f.render_widget(
+            List::new(members).block(block(format!(" Members ({}) ", team.members.len()))),
+            bottom[0],
+        );
+
+        let mut chips = Vec::new();
+        for label in &team.labels {
+            chips.push(Span::styled(
+                format!(" {} ", label.name),
+                label_style(label),
+            ));
+            chips.push(Span::raw(" "));
+        }
+        f.render_widget(
+            Paragraph::new(Line::from(chips))
+                .wrap(Wrap { trim: true })
+                .block(block(format!(" Labels ({}) ", team.labels.len()))),
+            bottom[1],
+        );
+
+        let status = Paragraph::new("Enter: Open the team's issues | Esc: Back | Press ? for help")
+            .style(Style::default().fg(Color::White))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(" Status ")
+                    .border_style(Style::default().fg(Color::Gray)),
+            );
+        f.render_widget(status, main_chunks[3]);
+    }
+
     fn truncate_text(&self, text: &str, max_width: usize) -> String {
         if text.len() <= max_width {
             text.to_string()
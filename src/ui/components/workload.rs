@@ -0,0 +1,580 @@
+// SPDX-FileCopyrightText: Copyright (C) Nicolas Lamirault <nicolas.lamirault@gmail.com>
+// SPDX-License-Identifier: Apache-2.0
+
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use crate::client::{Issue, TeamWorkload, User};
+use crate::config::Config;
+use crate::ui::components::{fit, IssuesComponent, OPEN_STATE_TYPES};
+
+/// Colors of the bar segments, in the order of `OPEN_STATE_TYPES`.
+const STATE_TYPE_COLORS: [Color; 4] = [Color::Magenta, Color::Gray, Color::Blue, Color::Yellow];
+
+/// Priorities in the order of the workload columns, with their column titles.
+const PRIORITIES: [(i32, &str); 5] = [(1, "URG"), (2, "HIGH"), (3, "MED"), (4, "LOW"), (0, "NONE")];
+
+/// What the workload numbers and bars are measured in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Measure {
+    Issues,
+    Estimate,
+}
+
+impl Measure {
+    fn of<'a>(self, issues: impl Iterator<Item = &'a Issue>) -> f64 {
+        match self {
+            Measure::Issues => issues.count() as f64,
+            // An empty sum is -0.0
+            Measure::Estimate => issues.filter_map(|i| i.estimate).sum::<f64>() + 0.0,
+        }
+    }
+}
+
+/// Open issues of a team member, or of nobody for the "Unassigned" bucket.
+pub struct AssigneeLoad {
+    pub assignee: Option<User>,
+    pub issues: Vec<Issue>,
+}
+
+impl AssigneeLoad {
+    pub fn name(&self) -> &str {
+        self.assignee
+            .as_ref()
+            .map_or("Unassigned", |a| a.display_name.as_str())
+    }
+
+    fn estimate(&self) -> f64 {
+        Measure::Estimate.of(self.issues.iter())
+    }
+
+    fn by_state_type(&self, measure: Measure, state_type: &str) -> f64 {
+        measure.of(self
+            .issues
+            .iter()
+            .filter(|i| i.state.state_type == state_type))
+    }
+
+    fn by_priority(&self, measure: Measure, priority: i32) -> f64 {
+        measure.of(self
+            .issues
+            .iter()
+            .filter(|i| i.priority.unwrap_or(0) == priority))
+    }
+}
+
+pub struct WorkloadComponent {
+    pub workload: Option<TeamWorkload>,
+    /// Team members with their open issues, the unassigned ones last.
+    pub rows: Vec<AssigneeLoad>,
+    pub state: ListState,
+    pub measure: Measure,
+    /// Issues of the assignee opened with Enter, shown instead of the list when `open`.
+    pub issues: IssuesComponent,
+    pub open: bool,
+    /// Whether the workload of the current team was fetched, it is fetched when the view is shown.
+    pub loaded: bool,
+}
+
+impl Default for WorkloadComponent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WorkloadComponent {
+    pub fn new() -> Self {
+        Self {
+            workload: None,
+            rows: Vec::new(),
+            state: ListState::default(),
+            measure: Measure::Issues,
+            issues: IssuesComponent::new(),
+            open: false,
+            loaded: false,
+        }
+    }
+
+    pub fn with_config(config: &Config) -> Self {
+        Self {
+            issues: IssuesComponent::with_config(config),
+            ..Self::new()
+        }
+    }
+
+    /// Forget the workload after a change of team, it is fetched again when the view is shown.
+    pub fn reset(&mut self) {
+        self.workload = None;
+        self.rows.clear();
+        self.state.select(None);
+        self.open = false;
+        self.loaded = false;
+    }
+
+    pub fn update_workload(&mut self, workload: TeamWorkload) {
+        self.workload = Some(workload);
+        self.rebuild();
+    }
+
+    /// Replace an issue after it was updated, dropping it once it is done.
+    pub fn replace_issue(&mut self, issue: Issue) {
+        if let Some(workload) = self.workload.as_mut() {
+            let Some(index) = workload.issues.iter().position(|i| i.id == issue.id) else {
+                return;
+            };
+            if matches!(issue.state.state_type.as_str(), "completed" | "canceled") {
+                workload.issues.remove(index);
+            } else {
+                workload.issues[index] = issue.clone();
+            }
+            self.rebuild();
+        }
+        self.issues.replace_issue(issue);
+    }
+
+    /// Group the open issues by assignee, keeping the selected row.
+    fn rebuild(&mut self) {
+        let Some(workload) = &self.workload else {
+            return;
+        };
+        // The assignee id of the selected row, `Some(None)` for the unassigned one
+        let selected = self
+            .selected_row()
+            .map(|r| r.assignee.as_ref().map(|a| a.id.clone()));
+
+        let mut rows: Vec<AssigneeLoad> = workload
+            .members
+            .iter()
+            .map(|member| AssigneeLoad {
+                assignee: Some(member.clone()),
+                issues: Vec::new(),
+            })
+            .collect();
+        let mut unassigned = AssigneeLoad {
+            assignee: None,
+            issues: Vec::new(),
+        };
+        for issue in &workload.issues {
+            let Some(assignee) = &issue.assignee else {
+                unassigned.issues.push(issue.clone());
+                continue;
+            };
+            // Assignees who left the team still get their row
+            match rows
+                .iter_mut()
+                .find(|r| r.assignee.as_ref().is_some_and(|a| a.id == assignee.id))
+            {
+                Some(row) => row.issues.push(issue.clone()),
+                None => rows.push(AssigneeLoad {
+                    assignee: Some(assignee.clone()),
+                    issues: vec![issue.clone()],
+                }),
+            }
+        }
+
+        let measure = self.measure;
+        rows.sort_by(|a, b| {
+            measure
+                .of(b.issues.iter())
+                .total_cmp(&measure.of(a.issues.iter()))
+                .then_with(|| a.name().to_lowercase().cmp(&b.name().to_lowercase()))
+        });
+        rows.push(unassigned);
+        self.rows = rows;
+
+        let index = self
+            .rows
+            .iter()
+            .position(|r| Some(r.assignee.as_ref().map(|a| a.id.clone())) == selected);
+        self.state.select(Some(index.unwrap_or(0)));
+
+        match index {
+            Some(index) if self.open => {
+                let issues = self.rows[index].issues.clone();
+                self.issues.update_issues(issues);
+            }
+            // Another team, the opened assignee is not in it
+            _ => self.open = false,
+        }
+    }
+
+    pub fn selected_row(&self) -> Option<&AssigneeLoad> {
+        self.state.selected().and_then(|i| self.rows.get(i))
+    }
+
+    /// Switch the numbers and bars between issue counts and estimate points.
+    pub fn toggle_measure(&mut self) {
+        self.measure = match self.measure {
+            Measure::Issues => Measure::Estimate,
+            Measure::Estimate => Measure::Issues,
+        };
+        self.rebuild();
+    }
+
+    /// Show the issues of the selected assignee.
+    pub fn open_assignee(&mut self) {
+        let Some(row) = self.selected_row() else {
+            return;
+        };
+        let name = format!("Workload: {}", row.name());
+        let issues = row.issues.clone();
+        self.issues.name = Some(name);
+        self.issues.show_query(None, issues);
+        self.open = true;
+    }
+
+    pub fn close_assignee(&mut self) {
+        self.open = false;
+    }
+
+    pub fn select_next(&mut self) {
+        let i = match self.state.selected() {
+            Some(i) => {
+                if i >= self.rows.len().saturating_sub(1) {
+                    0
+                } else {
+                    i + 1
+                }
+            }
+            None => 0,
+        };
+        if !self.rows.is_empty() {
+            self.state.select(Some(i));
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        let i = match self.state.selected() {
+            Some(i) => {
+                if i == 0 {
+                    self.rows.len().saturating_sub(1)
+                } else {
+                    i.saturating_sub(1)
+                }
+            }
+            None => 0,
+        };
+        if !self.rows.is_empty() {
+            self.state.select(Some(i));
+        }
+    }
+
+    pub fn render(&mut self, f: &mut Frame, area: ratatui::layout::Rect) {
+        if self.open {
+            self.issues.render(f, area);
+            return;
+        }
+
+        let main_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Title bar
+                Constraint::Length(5), // Overview panel
+                Constraint::Min(8),    // Assignees
+                Constraint::Length(3), // Status bar
+            ])
+            .split(area);
+
+        self.render_title_bar(f, main_chunks[0]);
+        self.render_overview_panel(f, main_chunks[1]);
+        self.render_assignees(f, main_chunks[2]);
+        self.render_status_bar(f, main_chunks[3]);
+    }
+
+    fn render_title_bar(&self, f: &mut Frame, area: ratatui::layout::Rect) {
+        let measure = match self.measure {
+            Measure::Issues => "issues",
+            Measure::Estimate => "estimate points",
+        };
+        let title = Paragraph::new(format!("⚖ Workload - Open {} per assignee", measure))
+            .style(
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Blue)),
+            );
+        f.render_widget(title, area);
+    }
+
+    fn render_overview_panel(&self, f: &mut Frame, area: ratatui::layout::Rect) {
+        let issues: Vec<&Issue> = self.rows.iter().flat_map(|r| &r.issues).collect();
+        let members = self.rows.iter().filter(|r| r.assignee.is_some()).count();
+        let estimate = Measure::Estimate.of(issues.iter().copied());
+        let unassigned = self.rows.last().filter(|r| r.assignee.is_none());
+        let busy = self
+            .rows
+            .iter()
+            .filter(|r| r.assignee.is_some() && !r.issues.is_empty())
+            .count();
+
+        let mut by_type = vec![Span::styled("States - ", Style::default().fg(Color::Gray))];
+        for ((state_type, title), color) in OPEN_STATE_TYPES.iter().zip(STATE_TYPE_COLORS) {
+            let count = issues
+                .iter()
+                .filter(|i| i.state.state_type == *state_type)
+                .count();
+            by_type.push(Span::styled("■ ", Style::default().fg(color)));
+            by_type.push(Span::styled(
+                format!("{}: {}  ", title.to_lowercase(), count),
+                Style::default().fg(Color::White),
+            ));
+        }
+
+        let mut by_priority = vec![Span::styled(
+            "Priority - ",
+            Style::default().fg(Color::Gray),
+        )];
+        for (priority, title) in PRIORITIES {
+            let count = issues
+                .iter()
+                .filter(|i| i.priority.unwrap_or(0) == priority)
+                .count();
+            by_priority.push(Span::styled(
+                format!("{}: {}  ", title.to_lowercase(), count),
+                Style::default().fg(Color::White),
+            ));
+        }
+
+        let overview_text = Text::from(vec![
+            Line::from(vec![
+                Span::styled("Open Issues: ", Style::default().fg(Color::Yellow)),
+                Span::styled(
+                    format!("{}", issues.len()),
+                    Style::default()
+                        .fg(Color::Green)
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::raw("  |  "),
+                Span::styled("Estimate: ", Style::default().fg(Color::Yellow)),
+                Span::styled(format!("{}", estimate), Style::default().fg(Color::Green)),
+                Span::raw("  |  "),
+                Span::styled("Members with work: ", Style::default().fg(Color::Yellow)),
+                Span::styled(
+                    format!("{}/{}", busy, members),
+                    Style::default().fg(Color::Green),
+                ),
+                Span::raw("  |  "),
+                Span::styled("Unassigned: ", Style::default().fg(Color::Yellow)),
+                Span::styled(
+                    format!("{}", unassigned.map_or(0, |r| r.issues.len())),
+                    Style::default().fg(Color::Red),
+                ),
+            ]),
+            Line::from(by_type),
+            Line::from(by_priority),
+        ]);
+
+        let overview = Paragraph::new(overview_text).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" Overview ")
+                .border_style(Style::default().fg(Color::Gray)),
+        );
+        f.render_widget(overview, area);
+    }
+
+    fn render_assignees(&mut self, f: &mut Frame, area: ratatui::layout::Rect) {
+        if self.workload.is_none() {
+            let message = if self.loaded {
+                "No workload loaded, select a team"
+            } else {
+                "Loading the workload..."
+            };
+            let empty_msg = Paragraph::new(message)
+                .style(Style::default().fg(Color::Gray))
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title(" Assignees ")
+                        .border_style(Style::default().fg(Color::Gray)),
+                );
+            f.render_widget(empty_msg, area);
+            return;
+        }
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(5)])
+            .split(area);
+
+        let name_width = 20;
+        let count_width = 7;
+        let priority_width = 5;
+        // Open issues and estimate, the state types then the priorities, each after a separator
+        let columns_width = (count_width + 3) * (2 + OPEN_STATE_TYPES.len() as u16)
+            + (priority_width + 3) * PRIORITIES.len() as u16;
+        let bar_width = area
+            .width
+            .saturating_sub(2 + 2 + name_width + 3 + columns_width + 1);
+
+        let header_style = Style::default()
+            .add_modifier(Modifier::BOLD)
+            .fg(Color::Blue);
+        let mut header = vec![
+            Span::raw("  "),
+            Span::styled(fit("ASSIGNEE", name_width, Alignment::Left), header_style),
+            Span::raw(" │ "),
+        ];
+        let titles = ["OPEN", "POINTS"]
+            .into_iter()
+            .chain(OPEN_STATE_TYPES.iter().map(|(_, t)| *t));
+        for title in titles {
+            header.push(Span::styled(
+                fit(title, count_width, Alignment::Right),
+                header_style,
+            ));
+            header.push(Span::raw(" │ "));
+        }
+        for (_, title) in PRIORITIES {
+            header.push(Span::styled(
+                fit(title, priority_width, Alignment::Right),
+                header_style,
+            ));
+            header.push(Span::raw(" │ "));
+        }
+        header.push(Span::styled(
+            match self.measure {
+                Measure::Issues => "ISSUES",
+                Measure::Estimate => "POINTS",
+            },
+            header_style,
+        ));
+        let header = Paragraph::new(Line::from(header)).block(
+            Block::default()
+                .borders(Borders::LEFT | Borders::RIGHT | Borders::TOP)
+                .border_style(Style::default().fg(Color::Gray)),
+        );
+        f.render_widget(header, chunks[0]);
+
+        let measure = self.measure;
+        let max = self
+            .rows
+            .iter()
+            .map(|r| measure.of(r.issues.iter()))
+            .fold(0.0, f64::max);
+        let cell = |value: f64, width: u16| {
+            let color = if value == 0.0 {
+                Color::DarkGray
+            } else {
+                Color::Yellow
+            };
+            Span::styled(
+                format!("{} │ ", fit(&value.to_string(), width, Alignment::Right)),
+                Style::default().fg(color),
+            )
+        };
+
+        let items: Vec<ListItem> = self
+            .rows
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                let is_selected = Some(i) == self.state.selected();
+                let selection_indicator = if is_selected { "➤ " } else { "  " };
+                let name_color = if row.assignee.is_some() {
+                    Color::White
+                } else {
+                    Color::Red
+                };
+
+                let mut line = vec![
+                    Span::styled(selection_indicator, Style::default().fg(Color::Yellow)),
+                    Span::styled(
+                        fit(row.name(), name_width, Alignment::Left),
+                        Style::default().fg(name_color).add_modifier(Modifier::BOLD),
+                    ),
+                    Span::raw(" │ "),
+                    Span::styled(
+                        format!(
+                            "{} │ ",
+                            fit(&row.issues.len().to_string(), count_width, Alignment::Right)
+                        ),
+                        Style::default().fg(Color::Green),
+                    ),
+                    Span::styled(
+                        format!(
+                            "{} │ ",
+                            fit(&row.estimate().to_string(), count_width, Alignment::Right)
+                        ),
+                        Style::default().fg(Color::Green),
+                    ),
+                ];
+                for (state_type, _) in OPEN_STATE_TYPES {
+                    line.push(cell(row.by_state_type(measure, state_type), count_width));
+                }
+                for (priority, _) in PRIORITIES {
+                    line.push(cell(row.by_priority(measure, priority), priority_width));
+                }
+                line.extend(Self::bar(row, measure, max, bar_width));
+
+                ListItem::new(Line::from(line))
+            })
+            .collect();
+
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!(" Assignees ({}) ", self.rows.len()))
+                    .border_style(Style::default().fg(Color::Gray)),
+            )
+            .highlight_style(Style::default().bg(Color::DarkGray));
+
+        f.render_stateful_widget(list, chunks[1], &mut self.state);
+    }
+
+    /// Bar of an assignee's workload scaled to the busiest one, a segment per state type.
+    fn bar(row: &AssigneeLoad, measure: Measure, max: f64, width: u16) -> Vec<Span<'static>> {
+        if max == 0.0 || width == 0 {
+            return Vec::new();
+        }
+        let scale = width as f64 / max;
+        let mut spans = Vec::new();
+        let mut drawn = 0.0;
+        let mut used = 0;
+        for ((state_type, _), color) in OPEN_STATE_TYPES.iter().zip(STATE_TYPE_COLORS) {
+            // Round the running total, so the segments add up to the whole bar
+            drawn += row.by_state_type(measure, state_type) * scale;
+            let end = drawn.round() as usize;
+            if end > used {
+                spans.push(Span::styled(
+                    "█".repeat(end - used),
+                    Style::default().fg(color),
+                ));
+                used = end;
+            }
+        }
+        spans
+    }
+
+    fn render_status_bar(&self, f: &mut Frame, area: ratatui::layout::Rect) {
+        let status_text = match self.selected_row() {
+            Some(row) => format!(
+                "Selected: {} - {} open, {} points | Enter: Issues | m: Issues/Estimate | Press ? for help",
+                row.name(),
+                row.issues.len(),
+                row.estimate()
+            ),
+            None => "No assignee selected | Press ? for help".to_string(),
+        };
+
+        let status = Paragraph::new(status_text)
+            .style(Style::default().fg(Color::White))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(" Status ")
+                    .border_style(Style::default().fg(Color::Gray)),
+            );
+        f.render_widget(status, area);
+    }
+}
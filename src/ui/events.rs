@@ -2,6 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crossterm::event::{self, Event, KeyEvent, KeyEventKind};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::mpsc;
 
@@ -15,12 +17,40 @@ pub enum AppEvent {
 pub struct EventHandler {
     sender: mpsc::UnboundedSender<AppEvent>,
     receiver: mpsc::UnboundedReceiver<AppEvent>,
+    /// Set while another program (e.g. an editor) owns the terminal.
+    paused: Arc<AtomicBool>,
+    /// Set by the input task once it stopped reading the terminal.
+    idle: Arc<AtomicBool>,
+}
+
+impl Default for EventHandler {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl EventHandler {
     pub fn new() -> Self {
         let (sender, receiver) = mpsc::unbounded_channel();
-        Self { sender, receiver }
+        Self {
+            sender,
+            receiver,
+            paused: Arc::new(AtomicBool::new(false)),
+            idle: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Stop reading terminal input, waiting until a pending read has finished.
+    pub async fn pause(&self) {
+        self.paused.store(true, Ordering::SeqCst);
+        while !self.idle.load(Ordering::SeqCst) {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    }
+
+    pub fn resume(&self) {
+        self.idle.store(false, Ordering::SeqCst);
+        self.paused.store(false, Ordering::SeqCst);
     }
 
     pub async fn next(&mut self) -> Option<AppEvent> {
@@ -29,21 +59,27 @@ impl EventHandler {
 
     pub fn start(&self) {
         let sender = self.sender.clone();
+        let (paused, idle) = (self.paused.clone(), self.idle.clone());
         tokio::spawn(async move {
             loop {
+                if paused.load(Ordering::SeqCst) {
+                    idle.store(true, Ordering::SeqCst);
+                    tokio::time::sleep(Duration::from_millis(50)).await;
+                    continue;
+                }
+
                 if event::poll(Duration::from_millis(250)).unwrap() {
                     match event::read().unwrap() {
-                        Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
-                            if let Err(_) = sender.send(AppEvent::Key(key_event)) {
-                                break;
-                            }
+                        Event::Key(key_event)
+                            if key_event.kind == KeyEventKind::Press
+                                && sender.send(AppEvent::Key(key_event)).is_err() =>
+                        {
+                            break;
                         }
                         _ => {}
                     }
-                } else {
-                    if let Err(_) = sender.send(AppEvent::Tick) {
-                        break;
-                    }
+                } else if sender.send(AppEvent::Tick).is_err() {
+                    break;
                 }
             }
         });
@@ -53,7 +89,7 @@ impl EventHandler {
             let mut interval = tokio::time::interval(Duration::from_secs(30));
             loop {
                 interval.tick().await;
-                if let Err(_) = sender.send(AppEvent::Refresh) {
+                if sender.send(AppEvent::Refresh).is_err() {
                     break;
                 }
             }
@@ -0,0 +1,448 @@
+// SPDX-FileCopyrightText: Copyright (C) Nicolas Lamirault <nicolas.lamirault@gmail.com>
+// SPDX-License-Identifier: Apache-2.0
+
+use pulldown_cmark::{Alignment, CodeBlockKind, Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+};
+use std::sync::OnceLock;
+use syntect::{
+    easy::HighlightLines, highlighting::ThemeSet, parsing::SyntaxSet, util::LinesWithEndings,
+};
+
+/// Syntect theme used for fenced code blocks.
+const CODE_THEME: &str = "base16-ocean.dark";
+
+/// Render Markdown (as used in Linear descriptions and comments) to styled lines.
+///
+/// Lines are not wrapped, render them in a `Paragraph` with `Wrap { trim: false }`.
+pub fn render(markdown: &str) -> Vec<Line<'static>> {
+    let options =
+        Options::ENABLE_TABLES | Options::ENABLE_TASKLISTS | Options::ENABLE_STRIKETHROUGH;
+
+    let mut renderer = Renderer::default();
+    for event in Parser::new_ext(markdown, options) {
+        renderer.event(event);
+    }
+    renderer.finish()
+}
+
+#[derive(Default)]
+struct Renderer {
+    lines: Vec<Line<'static>>,
+    spans: Vec<Span<'static>>,
+    styles: Vec<Style>,
+    /// Open lists, with the next number for ordered ones.
+    lists: Vec<Option<u64>>,
+    quote_depth: usize,
+    /// Language and content of the code block being read.
+    code: Option<(String, String)>,
+    table: Option<Table>,
+    /// Destinations of the links and images being read, with their text so far.
+    links: Vec<(String, String)>,
+}
+
+struct Table {
+    alignments: Vec<Alignment>,
+    rows: Vec<Vec<String>>,
+    has_header: bool,
+    cell: String,
+}
+
+impl Renderer {
+    fn event(&mut self, event: Event) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) => self.text(&text, self.style()),
+            Event::Code(code) => self.text(&code, Style::default().fg(Color::Yellow)),
+            Event::Html(html) | Event::InlineHtml(html) => {
+                self.text(html.trim_end(), Style::default().fg(Color::DarkGray))
+            }
+            Event::SoftBreak => self.text(" ", self.style()),
+            Event::HardBreak => self.flush(),
+            Event::Rule => {
+                self.flush();
+                self.lines.push(Line::from(Span::styled(
+                    "─".repeat(40),
+                    Style::default().fg(Color::DarkGray),
+                )));
+                self.blank_line();
+            }
+            Event::TaskListMarker(checked) => {
+                let indent = "  ".repeat(self.lists.len().saturating_sub(1));
+                self.spans.pop();
+                self.spans.push(if checked {
+                    Span::styled(format!("{}☑ ", indent), Style::default().fg(Color::Green))
+                } else {
+                    Span::styled(format!("{}☐ ", indent), Style::default().fg(Color::Yellow))
+                });
+            }
+            _ => {}
+        }
+    }
+
+    fn start(&mut self, tag: Tag) {
+        match tag {
+            Tag::Heading { level, .. } => {
+                self.flush();
+                let style = match level {
+                    HeadingLevel::H1 => Style::default()
+                        .fg(Color::Cyan)
+                        .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+                    HeadingLevel::H2 => Style::default()
+                        .fg(Color::Cyan)
+                        .add_modifier(Modifier::BOLD),
+                    _ => Style::default()
+                        .fg(Color::Blue)
+                        .add_modifier(Modifier::BOLD),
+                };
+                self.styles.push(style);
+            }
+            Tag::BlockQuote(_) => {
+                self.flush();
+                self.quote_depth += 1;
+                self.styles.push(
+                    Style::default()
+                        .fg(Color::Gray)
+                        .add_modifier(Modifier::ITALIC),
+                );
+            }
+            Tag::CodeBlock(kind) => {
+                self.flush();
+                let language = match kind {
+                    CodeBlockKind::Fenced(info) => info
+                        .split([',', ' '])
+                        .next()
+                        .unwrap_or_default()
+                        .to_string(),
+                    CodeBlockKind::Indented => String::new(),
+                };
+                self.code = Some((language, String::new()));
+            }
+            Tag::List(start) => {
+                self.flush();
+                self.lists.push(start);
+            }
+            Tag::Item => {
+                self.flush();
+                let indent = "  ".repeat(self.lists.len().saturating_sub(1));
+                let bullet = match self.lists.last_mut() {
+                    Some(Some(number)) => {
+                        *number += 1;
+                        format!("{}{}. ", indent, *number - 1)
+                    }
+                    _ => format!("{}• ", indent),
+                };
+                self.spans
+                    .push(Span::styled(bullet, Style::default().fg(Color::Yellow)));
+            }
+            Tag::Emphasis => self
+                .styles
+                .push(Style::default().add_modifier(Modifier::ITALIC)),
+            Tag::Strong => self
+                .styles
+                .push(Style::default().add_modifier(Modifier::BOLD)),
+            Tag::Strikethrough => self
+                .styles
+                .push(Style::default().add_modifier(Modifier::CROSSED_OUT)),
+            Tag::Link { dest_url, .. } => {
+                self.links.push((dest_url.to_string(), String::new()));
+                self.styles.push(
+                    Style::default()
+                        .fg(Color::Blue)
+                        .add_modifier(Modifier::UNDERLINED),
+                );
+            }
+            Tag::Image { dest_url, .. } => {
+                self.text("[image: ", Style::default().fg(Color::DarkGray));
+                self.links.push((dest_url.to_string(), String::new()));
+                self.styles.push(Style::default().fg(Color::Magenta));
+            }
+            Tag::Table(alignments) => {
+                self.flush();
+                self.table = Some(Table {
+                    alignments,
+                    rows: Vec::new(),
+                    has_header: false,
+                    cell: String::new(),
+                });
+            }
+            Tag::TableHead | Tag::TableRow => {
+                if let Some(table) = self.table.as_mut() {
+                    table.rows.push(Vec::new());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph => {
+                self.flush();
+                if self.lists.is_empty() {
+                    self.blank_line();
+                }
+            }
+            TagEnd::Heading(_) => {
+                self.styles.pop();
+                self.flush();
+                self.blank_line();
+            }
+            TagEnd::BlockQuote(_) => {
+                self.flush();
+                self.styles.pop();
+                self.quote_depth -= 1;
+                if self.quote_depth == 0 {
+                    self.blank_line();
+                }
+            }
+            TagEnd::CodeBlock => {
+                if let Some((language, code)) = self.code.take() {
+                    let prefix = self.prefix();
+                    for line in highlight(&code, &language) {
+                        let mut spans = prefix.clone();
+                        spans.push(Span::raw("  "));
+                        spans.extend(line.spans);
+                        self.lines.push(Line::from(spans));
+                    }
+                    self.blank_line();
+                }
+            }
+            TagEnd::List(_) => {
+                self.flush();
+                self.lists.pop();
+                if self.lists.is_empty() {
+                    self.blank_line();
+                }
+            }
+            TagEnd::Item => self.flush(),
+            TagEnd::Emphasis | TagEnd::Strong | TagEnd::Strikethrough => {
+                self.styles.pop();
+            }
+            TagEnd::Link | TagEnd::Image => {
+                self.styles.pop();
+                if let Some((url, text)) = self.links.pop() {
+                    if tag == TagEnd::Image {
+                        self.text("]", Style::default().fg(Color::DarkGray));
+                    }
+                    if text != url && !url.is_empty() {
+                        self.text(&format!(" ({})", url), Style::default().fg(Color::DarkGray));
+                    }
+                }
+            }
+            TagEnd::TableCell => {
+                if let Some(table) = self.table.as_mut() {
+                    let cell = std::mem::take(&mut table.cell);
+                    if let Some(row) = table.rows.last_mut() {
+                        row.push(cell.trim().to_string());
+                    }
+                }
+            }
+            TagEnd::TableHead => {
+                if let Some(table) = self.table.as_mut() {
+                    table.has_header = true;
+                }
+            }
+            TagEnd::Table => {
+                if let Some(table) = self.table.take() {
+                    self.render_table(table);
+                    self.blank_line();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn text(&mut self, text: &str, style: Style) {
+        for (_, label) in &mut self.links {
+            label.push_str(text);
+        }
+
+        if let Some((_, code)) = self.code.as_mut() {
+            code.push_str(text);
+        } else if let Some(table) = self.table.as_mut() {
+            table.cell.push_str(text);
+        } else {
+            self.spans.push(Span::styled(text.to_string(), style));
+        }
+    }
+
+    fn style(&self) -> Style {
+        self.styles
+            .iter()
+            .fold(Style::default(), |style, patch| style.patch(*patch))
+    }
+
+    /// Marks in front of every line inside block quotes.
+    fn prefix(&self) -> Vec<Span<'static>> {
+        (0..self.quote_depth)
+            .map(|_| Span::styled("│ ", Style::default().fg(Color::DarkGray)))
+            .collect()
+    }
+
+    fn flush(&mut self) {
+        if self.spans.is_empty() {
+            return;
+        }
+        let mut spans = self.prefix();
+        spans.append(&mut self.spans);
+        self.lines.push(Line::from(spans));
+    }
+
+    fn blank_line(&mut self) {
+        if self.lines.last().is_some_and(|line| line.width() > 0) {
+            self.lines.push(Line::from(""));
+        }
+    }
+
+    fn render_table(&mut self, table: Table) {
+        let columns = table.rows.iter().map(Vec::len).max().unwrap_or(0);
+        let widths: Vec<usize> = (0..columns)
+            .map(|i| {
+                table
+                    .rows
+                    .iter()
+                    .filter_map(|row| row.get(i))
+                    .map(|cell| cell.chars().count())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        let border = Style::default().fg(Color::DarkGray);
+        for (index, row) in table.rows.iter().enumerate() {
+            let is_header = table.has_header && index == 0;
+            let style = if is_header {
+                Style::default().add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+
+            let mut spans = self.prefix();
+            for (i, width) in widths.iter().enumerate() {
+                let cell = row.get(i).map(String::as_str).unwrap_or("");
+                let cell = match table.alignments.get(i) {
+                    Some(Alignment::Right) => format!("{:>width$}", cell, width = width),
+                    Some(Alignment::Center) => format!("{:^width$}", cell, width = width),
+                    _ => format!("{:<width$}", cell, width = width),
+                };
+                spans.push(Span::styled(if i == 0 { "│ " } else { " │ " }, border));
+                spans.push(Span::styled(cell, style));
+            }
+            spans.push(Span::styled(" │", border));
+            self.lines.push(Line::from(spans));
+
+            if is_header {
+                let separator: Vec<String> = widths.iter().map(|w| "─".repeat(w + 2)).collect();
+                let mut spans = self.prefix();
+                spans.push(Span::styled(format!("├{}┤", separator.join("┼")), border));
+                self.lines.push(Line::from(spans));
+            }
+        }
+    }
+
+    fn finish(mut self) -> Vec<Line<'static>> {
+        self.flush();
+        while self.lines.last().is_some_and(|line| line.width() == 0) {
+            self.lines.pop();
+        }
+        self.lines
+    }
+}
+
+/// Highlight a code block, falling back to plain text for unknown languages.
+fn highlight(code: &str, language: &str) -> Vec<Line<'static>> {
+    static SYNTAXES: OnceLock<SyntaxSet> = OnceLock::new();
+    static THEMES: OnceLock<ThemeSet> = OnceLock::new();
+
+    let syntaxes = SYNTAXES.get_or_init(SyntaxSet::load_defaults_newlines);
+    let themes = THEMES.get_or_init(ThemeSet::load_defaults);
+    let syntax = syntaxes
+        .find_syntax_by_token(language)
+        .unwrap_or_else(|| syntaxes.find_syntax_plain_text());
+    let mut highlighter = HighlightLines::new(syntax, &themes.themes[CODE_THEME]);
+
+    LinesWithEndings::from(code)
+        .map(|line| {
+            let line = line.replace('\t', "    ");
+            match highlighter.highlight_line(&line, syntaxes) {
+                Ok(ranges) => Line::from(
+                    ranges
+                        .into_iter()
+                        .map(|(style, text)| {
+                            let color = style.foreground;
+                            Span::styled(
+                                text.trim_end_matches('\n').to_string(),
+                                Style::default().fg(Color::Rgb(color.r, color.g, color.b)),
+                            )
+                        })
+                        .collect::<Vec<_>>(),
+                ),
+                Err(_) => Line::from(Span::styled(
+                    line.trim_end_matches('\n').to_string(),
+                    Style::default().fg(Color::Yellow),
+                )),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plain(markdown: &str) -> Vec<String> {
+        render(markdown)
+            .iter()
+            .map(|line| line.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn link_shows_its_destination() {
+        assert_eq!(
+            plain("see [the docs](http://example.com)"),
+            ["see the docs (http://example.com)"]
+        );
+    }
+
+    #[test]
+    fn link_with_its_destination_as_text() {
+        assert_eq!(plain("<http://example.com>"), ["http://example.com"]);
+    }
+
+    #[test]
+    fn link_with_hard_break() {
+        assert_eq!(
+            plain("x *y* [foo  \nbar](http://example.com)"),
+            ["x y foo", "bar (http://example.com)"]
+        );
+    }
+
+    #[test]
+    fn link_with_emphasis() {
+        assert_eq!(
+            plain("[*foo* **bar**](http://example.com)"),
+            ["foo bar (http://example.com)"]
+        );
+    }
+
+    #[test]
+    fn image() {
+        assert_eq!(
+            plain("![logo](http://example.com/logo.png)"),
+            ["[image: logo] (http://example.com/logo.png)"]
+        );
+    }
+
+    #[test]
+    fn image_inside_link() {
+        assert_eq!(
+            plain("[![logo](logo.png)](http://example.com)"),
+            ["[image: logo] (logo.png) (http://example.com)"]
+        );
+    }
+}
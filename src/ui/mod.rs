@@ -4,6 +4,7 @@
 pub mod app;
 pub mod components;
 pub mod events;
+pub mod markdown;
 
 pub use app::*;
 pub use components::*;
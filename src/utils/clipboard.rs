@@ -0,0 +1,66 @@
+// SPDX-FileCopyrightText: Copyright (C) Nicolas Lamirault <nicolas.lamirault@gmail.com>
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{Context, Result};
+use std::io::Write;
+use std::process::{Child, Command, Stdio};
+
+/// Commands copying their standard input to the clipboard, tried in order.
+#[cfg(target_os = "macos")]
+const COMMANDS: &[&[&str]] = &[&["pbcopy"]];
+#[cfg(target_os = "windows")]
+const COMMANDS: &[&[&str]] = &[&["clip"]];
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+const COMMANDS: &[&[&str]] = &[
+    &["wl-copy"],
+    &["xclip", "-selection", "clipboard"],
+    &["xsel", "--clipboard", "--input"],
+];
+
+/// Copy text to the system clipboard, with the first command that succeeds.
+pub fn copy(text: &str) -> Result<()> {
+    let mut failures = Vec::new();
+    for command in COMMANDS {
+        let Ok(child) = Command::new(command[0])
+            .args(&command[1..])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+        else {
+            continue;
+        };
+
+        // e.g. wl-copy is installed but there is no Wayland session
+        match run(child, text) {
+            Ok(()) => return Ok(()),
+            Err(e) => failures.push(format!("{}: {:#}", command[0], e)),
+        }
+    }
+
+    if !failures.is_empty() {
+        return Err(anyhow::anyhow!(
+            "Clipboard commands failed: {}",
+            failures.join(", ")
+        ));
+    }
+
+    let names: Vec<&str> = COMMANDS.iter().map(|c| c[0]).collect();
+    Err(anyhow::anyhow!(
+        "No clipboard command found, install one of: {}",
+        names.join(", ")
+    ))
+}
+
+fn run(mut child: Child, text: &str) -> Result<()> {
+    let written = child
+        .stdin
+        .take()
+        .context("Could not write to the clipboard command")?
+        .write_all(text.as_bytes());
+    let status = child.wait().context("Clipboard command failed")?;
+    if !status.success() {
+        return Err(anyhow::anyhow!("exited with {}", status));
+    }
+    written.context("Could not write to the clipboard command")
+}
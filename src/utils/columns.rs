@@ -0,0 +1,356 @@
+// SPDX-FileCopyrightText: Copyright (C) Nicolas Lamirault <nicolas.lamirault@gmail.com>
+// SPDX-License-Identifier: Apache-2.0
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+use crate::client::{Issue, Project};
+use crate::utils::priority_name;
+
+/// A column that can be displayed or exported for a list of items.
+pub trait Column<T> {
+    fn title(&self) -> &'static str;
+    fn value(&self, item: &T) -> String;
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, ValueEnum,
+)]
+#[serde(rename_all = "snake_case")]
+#[value(rename_all = "snake_case")]
+pub enum IssueColumn {
+    Identifier,
+    Priority,
+    Title,
+    State,
+    Assignee,
+    Creator,
+    Team,
+    Created,
+    Updated,
+    Labels,
+    Estimate,
+    DueDate,
+    Project,
+    Cycle,
+    Url,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+/// How the issues table is split into sections.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GroupBy {
+    #[default]
+    None,
+    State,
+    Assignee,
+    Priority,
+    Project,
+}
+
+impl GroupBy {
+    pub fn next(self) -> Self {
+        match self {
+            GroupBy::None => GroupBy::State,
+            GroupBy::State => GroupBy::Assignee,
+            GroupBy::Assignee => GroupBy::Priority,
+            GroupBy::Priority => GroupBy::Project,
+            GroupBy::Project => GroupBy::None,
+        }
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            GroupBy::None => "None",
+            GroupBy::State => "State",
+            GroupBy::Assignee => "Assignee",
+            GroupBy::Priority => "Priority",
+            GroupBy::Project => "Project",
+        }
+    }
+
+    /// The group an issue belongs to.
+    pub fn group(&self, issue: &Issue) -> IssueGroup {
+        let (id, name, rank) = match self {
+            GroupBy::None => (String::new(), String::new(), (0, String::new())),
+            GroupBy::State => (
+                issue.state.id.clone(),
+                issue.state.name.clone(),
+                (
+                    state_type_rank(&issue.state.state_type),
+                    issue.state.name.to_lowercase(),
+                ),
+            ),
+            GroupBy::Assignee => match &issue.assignee {
+                Some(user) => (
+                    user.id.clone(),
+                    user.display_name.clone(),
+                    (0, user.display_name.to_lowercase()),
+                ),
+                None => (String::new(), "Unassigned".to_string(), (1, String::new())),
+            },
+            GroupBy::Priority => {
+                let priority = issue.priority.unwrap_or(0);
+                let name = match priority {
+                    0 => "No priority",
+                    p => priority_name(p),
+                };
+                (
+                    priority.to_string(),
+                    name.to_string(),
+                    (priority_rank(issue) as u8, String::new()),
+                )
+            }
+            GroupBy::Project => match &issue.project {
+                Some(project) => (
+                    project.id.clone(),
+                    project.name.clone(),
+                    (0, project.name.to_lowercase()),
+                ),
+                None => (String::new(), "No project".to_string(), (1, String::new())),
+            },
+        };
+        IssueGroup { id, name, rank }
+    }
+}
+
+/// A section of the issues table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IssueGroup {
+    pub id: String,
+    pub name: String,
+    /// Orders the groups, e.g. workflow states by type.
+    pub rank: (u8, String),
+}
+
+impl SortOrder {
+    pub fn reverse(self) -> Self {
+        match self {
+            SortOrder::Ascending => SortOrder::Descending,
+            SortOrder::Descending => SortOrder::Ascending,
+        }
+    }
+
+    pub fn apply(self, ordering: Ordering) -> Ordering {
+        match self {
+            SortOrder::Ascending => ordering,
+            SortOrder::Descending => ordering.reverse(),
+        }
+    }
+}
+
+impl IssueColumn {
+    pub fn defaults() -> Vec<Self> {
+        vec![
+            IssueColumn::Identifier,
+            IssueColumn::Priority,
+            IssueColumn::Title,
+            IssueColumn::State,
+            IssueColumn::Assignee,
+        ]
+    }
+
+    /// Name of the column in the configuration and on the command line.
+    pub fn key(&self) -> String {
+        self.to_possible_value()
+            .map(|v| v.get_name().to_string())
+            .unwrap_or_default()
+    }
+
+    /// Width of the column in the issues table when not configured.
+    pub fn default_width(&self) -> u16 {
+        match self {
+            IssueColumn::Identifier => 10,
+            IssueColumn::Priority => 4,
+            IssueColumn::Title => 30,
+            IssueColumn::State => 14,
+            IssueColumn::Assignee | IssueColumn::Creator => 18,
+            IssueColumn::Team => 6,
+            IssueColumn::Created | IssueColumn::Updated | IssueColumn::DueDate => 10,
+            IssueColumn::Labels => 20,
+            IssueColumn::Estimate => 4,
+            IssueColumn::Project => 18,
+            IssueColumn::Cycle => 10,
+            IssueColumn::Url => 30,
+        }
+    }
+
+    /// Compare two issues by this column, in ascending order. Urgent issues come before
+    /// low priority ones, and issues without a value come last.
+    pub fn compare(&self, a: &Issue, b: &Issue) -> Ordering {
+        match self {
+            IssueColumn::Identifier => {
+                (&a.team.key, issue_number(a)).cmp(&(&b.team.key, issue_number(b)))
+            }
+            IssueColumn::Priority => priority_rank(a).cmp(&priority_rank(b)),
+            IssueColumn::State => state_type_rank(&a.state.state_type)
+                .cmp(&state_type_rank(&b.state.state_type))
+                .then_with(|| a.state.name.cmp(&b.state.name)),
+            IssueColumn::Created => a.created_at.cmp(&b.created_at),
+            IssueColumn::Updated => a.updated_at.cmp(&b.updated_at),
+            IssueColumn::Estimate => missing_last(a.estimate, b.estimate, |a, b| a.total_cmp(&b)),
+            IssueColumn::DueDate => missing_last(a.due_date, b.due_date, |a, b| a.cmp(&b)),
+            _ => {
+                let (a, b) = (self.value(a).to_lowercase(), self.value(b).to_lowercase());
+                missing_last(
+                    Some(a).filter(|v| !v.is_empty()),
+                    Some(b).filter(|v| !v.is_empty()),
+                    |a, b| a.cmp(&b),
+                )
+            }
+        }
+    }
+}
+
+fn issue_number(issue: &Issue) -> u64 {
+    issue
+        .identifier
+        .rsplit('-')
+        .next()
+        .and_then(|n| n.parse().ok())
+        .unwrap_or(0)
+}
+
+/// Linear uses 0 for "no priority", which sorts after low (4).
+fn priority_rank(issue: &Issue) -> i32 {
+    match issue.priority.unwrap_or(0) {
+        0 => 5,
+        p => p,
+    }
+}
+
+/// Order of Linear's workflow state types, from triage to canceled.
+pub fn state_type_rank(state_type: &str) -> u8 {
+    match state_type {
+        "triage" => 0,
+        "backlog" => 1,
+        "unstarted" => 2,
+        "started" => 3,
+        "completed" => 4,
+        "canceled" => 5,
+        _ => 6,
+    }
+}
+
+fn missing_last<T>(a: Option<T>, b: Option<T>, cmp: impl Fn(T, T) -> Ordering) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => cmp(a, b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+impl Column<Issue> for IssueColumn {
+    fn title(&self) -> &'static str {
+        match self {
+            IssueColumn::Identifier => "ID",
+            IssueColumn::Priority => "Priority",
+            IssueColumn::Title => "Title",
+            IssueColumn::State => "State",
+            IssueColumn::Assignee => "Assignee",
+            IssueColumn::Creator => "Creator",
+            IssueColumn::Team => "Team",
+            IssueColumn::Created => "Created",
+            IssueColumn::Updated => "Updated",
+            IssueColumn::Labels => "Labels",
+            IssueColumn::Estimate => "Estimate",
+            IssueColumn::DueDate => "Due",
+            IssueColumn::Project => "Project",
+            IssueColumn::Cycle => "Cycle",
+            IssueColumn::Url => "URL",
+        }
+    }
+
+    fn value(&self, issue: &Issue) -> String {
+        match self {
+            IssueColumn::Identifier => issue.identifier.clone(),
+            IssueColumn::Priority => priority_name(issue.priority.unwrap_or(0)).to_string(),
+            IssueColumn::Title => issue.title.clone(),
+            IssueColumn::State => issue.state.name.clone(),
+            IssueColumn::Assignee => issue
+                .assignee
+                .as_ref()
+                .map(|a| a.display_name.clone())
+                .unwrap_or_default(),
+            IssueColumn::Creator => issue.creator.display_name.clone(),
+            IssueColumn::Team => issue.team.key.clone(),
+            IssueColumn::Created => issue.created_at.format("%Y-%m-%d").to_string(),
+            IssueColumn::Updated => issue.updated_at.format("%Y-%m-%d").to_string(),
+            IssueColumn::Labels => issue
+                .labels
+                .iter()
+                .map(|l| l.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", "),
+            IssueColumn::Estimate => issue.estimate.map(|e| e.to_string()).unwrap_or_default(),
+            IssueColumn::DueDate => issue
+                .due_date
+                .map(|d| d.format("%Y-%m-%d").to_string())
+                .unwrap_or_default(),
+            IssueColumn::Project => issue
+                .project
+                .as_ref()
+                .map(|p| p.name.clone())
+                .unwrap_or_default(),
+            IssueColumn::Cycle => issue
+                .cycle
+                .as_ref()
+                .map(|c| c.display_name())
+                .unwrap_or_default(),
+            IssueColumn::Url => issue.url.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum ProjectColumn {
+    Name,
+    Status,
+    Lead,
+    Description,
+}
+
+impl ProjectColumn {
+    pub fn defaults() -> Vec<Self> {
+        vec![
+            ProjectColumn::Name,
+            ProjectColumn::Status,
+            ProjectColumn::Lead,
+        ]
+    }
+}
+
+impl Column<Project> for ProjectColumn {
+    fn title(&self) -> &'static str {
+        match self {
+            ProjectColumn::Name => "Name",
+            ProjectColumn::Status => "Status",
+            ProjectColumn::Lead => "Lead",
+            ProjectColumn::Description => "Description",
+        }
+    }
+
+    fn value(&self, project: &Project) -> String {
+        match self {
+            ProjectColumn::Name => project.name.clone(),
+            ProjectColumn::Status => project.status.name.clone(),
+            ProjectColumn::Lead => project
+                .lead
+                .as_ref()
+                .map(|l| l.display_name.clone())
+                .unwrap_or_default(),
+            ProjectColumn::Description => project.description.clone().unwrap_or_default(),
+        }
+    }
+}
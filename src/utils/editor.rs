@@ -0,0 +1,50 @@
+// SPDX-FileCopyrightText: Copyright (C) Nicolas Lamirault <nicolas.lamirault@gmail.com>
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{Context, Result};
+use std::path::Path;
+use std::process::Command;
+
+/// Editor command from `$VISUAL` or `$EDITOR`, falling back to `vi`.
+pub fn editor_command() -> String {
+    std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .ok()
+        .filter(|e| !e.trim().is_empty())
+        .unwrap_or_else(|| "vi".to_string())
+}
+
+/// Open a file in the user's editor and wait for it to exit.
+pub fn open_in_editor(path: &Path) -> Result<()> {
+    let editor = editor_command();
+    let mut parts = editor.split_whitespace();
+    let program = parts.next().context("Empty editor command")?;
+
+    let status = Command::new(program)
+        .args(parts)
+        .arg(path)
+        .status()
+        .with_context(|| format!("Failed to start editor '{}'", editor))?;
+
+    if !status.success() {
+        return Err(anyhow::anyhow!(
+            "Editor '{}' exited with {}",
+            editor,
+            status
+        ));
+    }
+
+    Ok(())
+}
+
+/// Edit some text in a temporary file with the given extension and return the saved content.
+pub fn edit_text(initial: &str, extension: &str) -> Result<String> {
+    let path = std::env::temp_dir().join(format!("ltui-{}.{}", uuid::Uuid::new_v4(), extension));
+    std::fs::write(&path, initial).context("Could not write temporary file")?;
+
+    let result = open_in_editor(&path)
+        .and_then(|_| std::fs::read_to_string(&path).context("Could not read temporary file"));
+
+    let _ = std::fs::remove_file(&path);
+    result
+}
@@ -1,7 +1,8 @@
 // SPDX-FileCopyrightText: Copyright (C) Nicolas Lamirault <nicolas.lamirault@gmail.com>
 // SPDX-License-Identifier: Apache-2.0
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 pub fn format_duration_since(date: &DateTime<Utc>) -> String {
     let now = Utc::now();
@@ -18,6 +19,22 @@ pub fn format_duration_since(date: &DateTime<Utc>) -> String {
     }
 }
 
+/// Parse a relative duration such as `30m`, `12h`, `1d` or `2w`. Negative amounts are refused.
+pub fn parse_duration(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    let unit = value.chars().last()?;
+    let amount: u32 = value[..value.len() - unit.len_utf8()].parse().ok()?;
+    let amount = i64::from(amount);
+
+    match unit {
+        'm' => Duration::try_minutes(amount),
+        'h' => Duration::try_hours(amount),
+        'd' => Duration::try_days(amount),
+        'w' => Duration::try_weeks(amount),
+        _ => None,
+    }
+}
+
 pub fn format_priority(priority: i32) -> &'static str {
     match priority {
         4 => "🔴 Urgent",
@@ -28,10 +45,44 @@ pub fn format_priority(priority: i32) -> &'static str {
     }
 }
 
-pub fn truncate_string(s: &str, max_len: usize) -> String {
-    if s.len() <= max_len {
-        s.to_string()
-    } else {
-        format!("{}...", &s[..max_len.saturating_sub(3)])
+pub fn priority_name(priority: i32) -> &'static str {
+    match priority {
+        1 => "Urgent",
+        2 => "High",
+        3 => "Medium",
+        4 => "Low",
+        _ => "None",
+    }
+}
+
+/// Parse a priority given as a name (e.g. "high") or as Linear's number (0-4).
+pub fn parse_priority(value: &str) -> Option<i32> {
+    match value.trim().to_lowercase().as_str() {
+        "" | "none" | "no priority" => Some(0),
+        "urgent" => Some(1),
+        "high" => Some(2),
+        "medium" | "normal" => Some(3),
+        "low" => Some(4),
+        other => other.parse().ok().filter(|p| (0..=4).contains(p)),
+    }
+}
+
+/// Shorten a string to at most `max_width` terminal columns, ending it with "..." when cut.
+pub fn truncate_string(s: &str, max_width: usize) -> String {
+    if s.width() <= max_width {
+        return s.to_string();
+    }
+
+    let limit = max_width.saturating_sub(3);
+    let mut truncated = String::new();
+    let mut used = 0;
+    for c in s.chars() {
+        let width = c.width().unwrap_or(0);
+        if used + width > limit {
+            break;
+        }
+        truncated.push(c);
+        used += width;
     }
+    format!("{}...", truncated)
 }
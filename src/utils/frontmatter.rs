@@ -0,0 +1,216 @@
+// SPDX-FileCopyrightText: Copyright (C) Nicolas Lamirault <nicolas.lamirault@gmail.com>
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{Context, Result};
+
+const DELIMITER: &str = "---";
+
+/// A Markdown document with `key: value` fields between `---` lines at the top.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Document {
+    pub fields: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Document {
+    pub fn field(&self, key: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Render the document, with optional `#` comment lines at the top of the front-matter.
+    pub fn render(&self, comments: &[&str]) -> String {
+        let mut out = format!("{}\n", DELIMITER);
+        for comment in comments {
+            out.push_str(&format!("# {}\n", comment));
+        }
+        for (key, value) in &self.fields {
+            out.push_str(&format!("{}: {}\n", key, quote(value)));
+        }
+        out.push_str(&format!("{}\n\n{}\n", DELIMITER, self.body));
+        out
+    }
+
+    /// Parse a document, ignoring comment lines in the front-matter.
+    pub fn parse(content: &str) -> Result<Self> {
+        let mut lines = content.lines();
+        if lines.next().map(str::trim_end) != Some(DELIMITER) {
+            return Err(anyhow::anyhow!(
+                "The file must start with a '{}' front-matter line",
+                DELIMITER
+            ));
+        }
+
+        let mut fields = Vec::new();
+        let mut closed = false;
+        for line in lines.by_ref() {
+            let line = line.trim_end();
+            if line == DELIMITER {
+                closed = true;
+                break;
+            }
+            if line.trim().is_empty() || line.trim_start().starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once(':')
+                .with_context(|| format!("Invalid front-matter line '{}'", line))?;
+            fields.push((key.trim().to_string(), unquote(value.trim())?));
+        }
+
+        if !closed {
+            return Err(anyhow::anyhow!(
+                "The front-matter is not closed with a '{}' line",
+                DELIMITER
+            ));
+        }
+
+        let body = lines.collect::<Vec<_>>().join("\n");
+        Ok(Self {
+            fields,
+            body: body.trim_matches('\n').to_string(),
+        })
+    }
+}
+
+/// Text with `\r\n` line endings turned into `\n`, to compare a body before and after editing.
+pub fn normalize_newlines(text: &str) -> String {
+    text.replace("\r\n", "\n")
+}
+
+/// Write a value as is, or double quoted with escapes when reading it back as is would change it.
+fn quote(value: &str) -> String {
+    let plain = value.trim() == value
+        && !value.starts_with(['"', '\'', '#'])
+        && !value.contains(|c: char| c.is_control());
+    if plain {
+        return value.to_string();
+    }
+
+    let mut quoted = String::from('"');
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// Read a value written by `quote`, or single quoted by hand.
+fn unquote(value: &str) -> Result<String> {
+    if let Some(inner) = value.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')) {
+        return Ok(inner.to_string());
+    }
+    let Some(inner) = value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .filter(|_| value.len() > 1)
+    else {
+        return Ok(value.to_string());
+    };
+
+    let mut unquoted = String::new();
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unquoted.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => unquoted.push('\n'),
+            Some('r') => unquoted.push('\r'),
+            Some('t') => unquoted.push('\t'),
+            Some(c @ ('"' | '\\')) => unquoted.push(c),
+            Some(c) => return Err(anyhow::anyhow!("Invalid escape '\\{}' in {}", c, value)),
+            None => return Err(anyhow::anyhow!("Unfinished escape in {}", value)),
+        }
+    }
+    Ok(unquoted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(title: &str, body: &str) -> Document {
+        let document = Document {
+            fields: vec![("title".to_string(), title.to_string())],
+            body: body.to_string(),
+        };
+        let parsed = Document::parse(&document.render(&["comment"])).unwrap();
+        assert_eq!(parsed.field("title"), Some(title));
+        parsed
+    }
+
+    #[test]
+    fn round_trips_plain_title() {
+        round_trip("Fix the login page", "Some *description*");
+    }
+
+    #[test]
+    fn round_trips_quoted_title() {
+        round_trip("\"Foo\"", "");
+        round_trip("'Foo'", "");
+        round_trip("\"Foo\" is broken", "");
+        round_trip("Say \"hi\"", "");
+    }
+
+    #[test]
+    fn round_trips_special_titles() {
+        round_trip("C:\\path\\to", "");
+        round_trip("\"C:\\path\"", "");
+        round_trip("  padded ", "");
+        round_trip("# not a comment", "");
+        round_trip("tab\there", "");
+        round_trip("\"", "");
+        round_trip("", "");
+    }
+
+    #[test]
+    fn round_trips_body() {
+        let parsed = round_trip("Title", "first line\n\n---\n\nafter a rule");
+        assert_eq!(parsed.body, "first line\n\n---\n\nafter a rule");
+    }
+
+    #[test]
+    fn crlf_body_matches_once_normalized() {
+        let body = "first line\r\nsecond line";
+        let parsed = round_trip("Title", body);
+        assert_eq!(normalize_newlines(&parsed.body), normalize_newlines(body));
+    }
+
+    #[test]
+    fn parses_crlf_file() {
+        let parsed =
+            Document::parse("---\r\n# comment\r\ntitle: \"Foo\"\r\n---\r\n\r\nbody\r\n").unwrap();
+        assert_eq!(parsed.field("title"), Some("Foo"));
+        assert_eq!(parsed.body, "body");
+    }
+
+    #[test]
+    fn parses_hand_quoted_values() {
+        let parsed = Document::parse("---\ntitle: 'Foo'\nother: \"a\\nb\"\n---\n").unwrap();
+        assert_eq!(parsed.field("title"), Some("Foo"));
+        assert_eq!(parsed.field("other"), Some("a\nb"));
+    }
+
+    #[test]
+    fn rejects_invalid_escape() {
+        assert!(Document::parse("---\ntitle: \"a\\qb\"\n---\n").is_err());
+    }
+
+    #[test]
+    fn rejects_unclosed_front_matter() {
+        assert!(Document::parse("---\ntitle: Foo\n").is_err());
+        assert!(Document::parse("title: Foo\n---\n").is_err());
+    }
+}
@@ -1,6 +1,11 @@
 // SPDX-FileCopyrightText: Copyright (C) Nicolas Lamirault <nicolas.lamirault@gmail.com>
 // SPDX-License-Identifier: Apache-2.0
 
+pub mod clipboard;
+pub mod columns;
+pub mod editor;
 pub mod formatters;
+pub mod frontmatter;
 
+pub use columns::*;
 pub use formatters::*;